@@ -66,7 +66,7 @@ pub fn get_account_hd_path_from_path(derivation_path: &str) -> Result<HDPath, cr
     };
 
     let hd_path = match coin_type {
-        ETH_TYPE | TRON_TYPE | BTC_TYPE | BTC_86_TYPE | LTC_TYPE | DOG_TYPE => {
+        ETH_TYPE | TRON_TYPE | BTC_TYPE | BTC_86_TYPE | LTC_TYPE | DOG_TYPE | BCH_TYPE => {
             HDPath::Other(custom_hd_path)
         }
         SOLANA_TYPE => HDPath::Solana(
@@ -122,7 +122,15 @@ mod tests {
         let chain_code = hd_path.get_chain_codes().unwrap();
         assert_eq!(
             chain_code,
-            ChainCodes(vec![ChainCode::Ethereum, ChainCode::BnbSmartChain])
+            ChainCodes(vec![
+                ChainCode::Ethereum,
+                ChainCode::BnbSmartChain,
+                ChainCode::Evm(137),
+                ChainCode::Evm(42161),
+                ChainCode::Evm(10),
+                ChainCode::Evm(8453),
+                ChainCode::Evm(43114),
+            ])
         );
         let account_id = hd_path.get_account_id().unwrap();
         assert_eq!(account_id, 2147478972);
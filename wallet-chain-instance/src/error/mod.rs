@@ -8,6 +8,8 @@ pub enum Error {
     LtcAddressTypeCantGenDerivationPath,
     #[error("This dog address type can't generate derivation path")]
     DogAddressTypeCantGenDerivationPath,
+    #[error("This bch address type can't generate derivation path")]
+    BchAddressTypeCantGenDerivationPath,
     #[error("Core error: {0}")]
     Core(#[from] wallet_core::Error),
     #[error("Utils error: {0}")]
@@ -0,0 +1,143 @@
+use std::str::FromStr as _;
+
+use bitcoin::{PrivateKey, PublicKey, hashes::Hash as _};
+use secp256k1::{Keypair, Secp256k1};
+use wallet_types::chain::{address::r#type::BchAddressType, chain, network};
+use wallet_utils::cashaddr::{self, CashAddrType};
+
+#[derive(Clone)]
+pub struct BchGenAddress {
+    pub address_type: BchAddressType,
+    pub network: network::NetworkKind,
+}
+
+impl wallet_core::address::GenAddress for BchGenAddress {
+    type Address = crate::instance::Address;
+    type Error = crate::Error;
+    fn generate(&self, pkey: &[u8]) -> Result<Self::Address, Self::Error>
+    where
+        Self: Sized,
+    {
+        let secret_key = secp256k1::SecretKey::from_slice(pkey)?;
+
+        let secp = Secp256k1::new();
+        let keypair = secp256k1::Keypair::from_secret_key(&secp, &secret_key);
+
+        Ok(crate::instance::Address::BchAddress(
+            generate_address_with_xpriv(&self.address_type, keypair, self.network)?,
+        ))
+    }
+
+    fn chain_code(&self) -> &chain::ChainCode {
+        &chain::ChainCode::BitcoinCash
+    }
+}
+
+/// 获取 cashaddr 的前缀根据网络类型
+fn get_cashaddr_prefix(network: network::NetworkKind) -> &'static str {
+    match network {
+        network::NetworkKind::Mainnet => "bitcoincash",
+        network::NetworkKind::Testnet => "bchtest",
+        network::NetworkKind::Regtest => "bchreg",
+    }
+}
+
+fn generate_xpriv(
+    seed: Vec<u8>,
+    path: &str,
+    secp: &Secp256k1<secp256k1::All>,
+) -> Result<bitcoin::bip32::Xpriv, crate::Error> {
+    let xpriv = bitcoin::bip32::Xpriv::new_master(bitcoin::Network::Bitcoin, &seed)?;
+    let path = bitcoin::bip32::DerivationPath::from_str(path)?;
+
+    Ok(xpriv.derive_priv(secp, &path)?)
+}
+
+pub(crate) fn generate_address(
+    address_type: &BchAddressType,
+    seed: &[u8],
+    derivation_path: &str,
+    network: network::NetworkKind,
+) -> Result<String, crate::Error> {
+    let secp = Secp256k1::new();
+    let xpriv = generate_xpriv(seed.to_vec(), derivation_path, &secp)?;
+    let keypair = xpriv.to_keypair(&secp);
+    generate_address_with_xpriv(address_type, keypair, network)
+}
+
+pub fn generate_address_by_seckey(
+    address_type: &BchAddressType,
+    network: network::NetworkKind,
+    seckey: String,
+) -> Result<String, crate::Error> {
+    let secp = Secp256k1::new();
+
+    let pk = PrivateKey::from_wif(&seckey).map_err(|e| crate::Error::PriKey(e.to_string()))?;
+    let keypair = Keypair::from_seckey_slice(&secp, &pk.to_bytes())
+        .map_err(|e| crate::Error::PriKey(e.to_string()))?;
+
+    generate_address_with_xpriv(address_type, keypair, network)
+}
+
+// 地址统一使用 cashaddr 格式
+pub fn generate_address_with_xpriv(
+    address_type: &BchAddressType,
+    keypair: secp256k1::Keypair,
+    network: network::NetworkKind,
+) -> Result<String, crate::Error> {
+    match address_type {
+        BchAddressType::P2pkh => {
+            let pubkey = PublicKey::new(keypair.public_key());
+            let hash = pubkey.pubkey_hash();
+            Ok(cashaddr::encode(
+                get_cashaddr_prefix(network),
+                CashAddrType::P2pkh,
+                hash.as_byte_array(),
+            )?)
+        }
+        BchAddressType::P2sh => Err(crate::Error::BchAddressTypeCantGenDerivationPath),
+    }
+}
+
+// 转换为 legacy 格式,兼容只支持 base58 地址的交易所
+pub fn to_legacy_address(
+    address: &str,
+    network: network::NetworkKind,
+) -> Result<String, crate::Error> {
+    let (pubkey_hash_prefix, script_hash_prefix) = match network {
+        network::NetworkKind::Mainnet => (0x00, 0x05),
+        network::NetworkKind::Testnet | network::NetworkKind::Regtest => (0x6f, 0xc4),
+    };
+
+    Ok(cashaddr::to_legacy(
+        get_cashaddr_prefix(network),
+        address,
+        pubkey_hash_prefix,
+        script_hash_prefix,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_cashaddr() {
+        let secp = Secp256k1::new();
+        let keypair = Keypair::from_seckey_slice(&secp, &[1; 32]).unwrap();
+
+        let address = generate_address_with_xpriv(
+            &BchAddressType::P2pkh,
+            keypair,
+            network::NetworkKind::Mainnet,
+        )
+        .unwrap();
+        assert!(address.starts_with("bitcoincash:q"));
+
+        // legacy format matches the bitcoin p2pkh address of the same key
+        let legacy = to_legacy_address(&address, network::NetworkKind::Mainnet).unwrap();
+        let pubkey = bitcoin::CompressedPublicKey(keypair.public_key());
+        let btc = bitcoin::Address::p2pkh(pubkey, bitcoin::Network::Bitcoin);
+        assert_eq!(legacy, btc.to_string());
+    }
+}
@@ -0,0 +1,157 @@
+pub(crate) mod address;
+pub use address::generate_address_by_seckey;
+
+use std::str::FromStr;
+
+use bitcoin::{
+    bip32::{DerivationPath, Xpriv},
+    key::Secp256k1,
+};
+use wallet_core::KeyPair;
+use wallet_types::chain::{address::r#type::BchAddressType, chain::ChainCode, network};
+
+pub struct BitcoinCashKeyPair {
+    bitcoin_cash_family: ChainCode,
+    pub xpriv: Xpriv,
+    pubkey: String,
+    address: String,
+    derivation: DerivationPath,
+    network: network::NetworkKind,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BitcoinCashInstance {
+    pub(crate) chain_code: ChainCode,
+    pub(crate) address_type: BchAddressType,
+    pub network: network::NetworkKind,
+}
+
+impl wallet_core::derive::GenDerivationBch for BitcoinCashInstance {
+    type Error = crate::Error;
+
+    fn generate(
+        address_type: &Option<BchAddressType>,
+        input_index: i32,
+    ) -> Result<String, crate::Error> {
+        let Some(address_type) = address_type else {
+            return Err(wallet_types::Error::BchNeedAddressType.into());
+        };
+
+        // cashaddr and legacy share the bip44 path, p2sh is only used by multisig
+        let path = if input_index < 0 {
+            let i = wallet_utils::address::i32_index_to_unhardened_u32(input_index)?;
+            let path = match address_type {
+                BchAddressType::P2pkh => wallet_types::constant::BCH_HARD_DERIVATION_PATH,
+                BchAddressType::P2sh => {
+                    return Err(crate::Error::BchAddressTypeCantGenDerivationPath);
+                }
+            };
+            crate::add_index(path, i, true)
+        } else {
+            let i = input_index as u32;
+            let path = match address_type {
+                BchAddressType::P2pkh => wallet_types::constant::BCH_DERIVATION_PATH,
+                BchAddressType::P2sh => {
+                    return Err(crate::Error::BchAddressTypeCantGenDerivationPath);
+                }
+            };
+            crate::add_index(path, i, false)
+        };
+
+        Ok(path)
+    }
+}
+
+impl wallet_core::derive::Derive for BitcoinCashInstance {
+    type Error = crate::Error;
+
+    type Item = BitcoinCashKeyPair;
+
+    fn derive_with_derivation_path(
+        &self,
+        seed: Vec<u8>,
+        derivation_path: &str,
+    ) -> Result<Self::Item, Self::Error> {
+        let address =
+            address::generate_address(&self.address_type, &seed, derivation_path, self.network)?;
+        let mut res = BitcoinCashKeyPair::generate_with_derivation(
+            seed,
+            derivation_path,
+            &self.chain_code,
+            self.network,
+        )?;
+        res.address = address;
+        Ok(res)
+    }
+}
+
+impl KeyPair for BitcoinCashKeyPair {
+    type Error = crate::Error;
+
+    fn generate_with_derivation(
+        seed: Vec<u8>,
+        derivation_path: &str,
+        chain_code: &ChainCode,
+        network: network::NetworkKind,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+    {
+        generate(seed, derivation_path, chain_code, network)
+    }
+
+    fn network(&self) -> network::NetworkKind {
+        self.network
+    }
+
+    // bitcoin cash uses the same wif version bytes as bitcoin
+    fn private_key(&self) -> Result<String, Self::Error> {
+        let prikey = bitcoin::PrivateKey::new(self.xpriv.private_key, self.network);
+        Ok(prikey.to_wif())
+    }
+
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    fn pubkey(&self) -> String {
+        self.pubkey.clone()
+    }
+
+    fn chain_code(&self) -> ChainCode {
+        self.bitcoin_cash_family
+    }
+
+    fn derivation_path(&self) -> String {
+        let path = self.derivation.to_string();
+        format!("m/{path}")
+    }
+
+    fn private_key_bytes(&self) -> Result<Vec<u8>, Self::Error> {
+        Ok(self.xpriv.private_key.secret_bytes().to_vec())
+    }
+}
+
+fn generate(
+    seed: Vec<u8>,
+    derivation_path: &str,
+    chain_code: &ChainCode,
+    network: network::NetworkKind,
+) -> Result<BitcoinCashKeyPair, crate::Error> {
+    let xpriv = Xpriv::new_master(network, &seed)?;
+
+    let path = DerivationPath::from_str(derivation_path)?;
+    let secp = Secp256k1::new();
+    let derive_key = xpriv.derive_priv(&secp, &path)?;
+
+    let keypair = derive_key.to_keypair(&secp);
+    let pubkey = keypair.public_key().to_string();
+    Ok(BitcoinCashKeyPair {
+        bitcoin_cash_family: chain_code.to_owned(),
+        xpriv: derive_key,
+        pubkey,
+        address: "".to_string(),
+        derivation: path,
+        network,
+    })
+}
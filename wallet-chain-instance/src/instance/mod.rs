@@ -1,3 +1,4 @@
+pub mod bch;
 pub mod btc;
 pub mod dog;
 pub mod eth;
@@ -9,6 +10,7 @@ pub mod trx;
 
 use std::fmt::Display;
 
+use bch::BitcoinCashInstance;
 use btc::BitcoinInstance;
 use chain::ChainCode;
 use dog::DogcoinInstance;
@@ -20,7 +22,7 @@ use ton::{TonInstance, TonKeyPair};
 use trx::TronInstance;
 use wallet_core::{
     KeyPair,
-    derive::{Derive, GenDerivation, GenDerivationDog, GenDerivationLtc},
+    derive::{Derive, GenDerivation, GenDerivationBch, GenDerivationDog, GenDerivationLtc},
 };
 
 use wallet_types::chain::{address::r#type::AddressType, chain, network};
@@ -31,6 +33,7 @@ pub enum Address {
     BtcAddress(String),
     LtcAddress(String),
     DogAddress(String),
+    BchAddress(String),
     SolAddress(solana_sdk::pubkey::Pubkey),
     TrxAddress(anychain_tron::TronAddress),
     BnbAddress(alloy::primitives::Address),
@@ -44,6 +47,7 @@ impl Display for Address {
             Address::BtcAddress(address) => write!(f, "{}", address),
             Address::LtcAddress(address) => write!(f, "{}", address),
             Address::DogAddress(address) => write!(f, "{}", address),
+            Address::BchAddress(address) => write!(f, "{}", address),
             Address::SolAddress(address) => write!(f, "{}", address),
             Address::TrxAddress(address) => write!(f, "{}", address.to_base58()),
             Address::BnbAddress(address) => write!(f, "{}", address),
@@ -61,6 +65,7 @@ pub enum ChainObject {
     Btc(crate::instance::btc::BitcoinInstance),
     Ltc(crate::instance::ltc::LitecoinInstance),
     Dog(crate::instance::dog::DogcoinInstance),
+    Bch(crate::instance::bch::BitcoinCashInstance),
     Ton(crate::instance::ton::TonInstance),
     Sui(crate::instance::sui::SuiInstance),
 }
@@ -85,6 +90,7 @@ impl ChainObject {
             ChainObject::Btc(i) => &i.chain_code,
             ChainObject::Ltc(i) => &i.chain_code,
             ChainObject::Dog(i) => &i.chain_code,
+            ChainObject::Bch(i) => &i.chain_code,
             ChainObject::Ton(i) => &i.chain_code,
             ChainObject::Sui(i) => &i.chain_code,
         }
@@ -100,6 +106,7 @@ impl ChainObject {
             ChainObject::Btc(i) => AddressType::Btc(i.address_type),
             ChainObject::Ltc(i) => AddressType::Ltc(i.address_type),
             ChainObject::Dog(i) => AddressType::Dog(i.address_type),
+            ChainObject::Bch(i) => AddressType::Bch(i.address_type),
             ChainObject::Ton(i) => AddressType::Ton(i.address_type),
         }
     }
@@ -155,6 +162,13 @@ impl ChainObject {
                 let res = Box::new(res);
                 Ok(res)
             }
+            ChainObject::Bch(i) => {
+                let derivation_path =
+                    BitcoinCashInstance::generate(&Some(i.address_type), input_index)?;
+                let res = i.derive_with_derivation_path(seed.to_vec(), &derivation_path)?;
+                let res = Box::new(res);
+                Ok(res)
+            }
 
             ChainObject::Ton(instance) => {
                 let derivation_path = TonInstance::generate(&None, input_index)?;
@@ -217,6 +231,11 @@ impl ChainObject {
                 let res = Box::new(res);
                 Ok(res)
             }
+            ChainObject::Bch(i) => {
+                let res = i.derive_with_derivation_path(seed.to_vec(), derivation_path)?;
+                let res = Box::new(res);
+                Ok(res)
+            }
             ChainObject::Ton(instance) => {
                 let res = TonKeyPair::generate_with_derivation(
                     seed.to_vec(),
@@ -247,8 +266,8 @@ impl ChainObject {
         crate::Error,
     > {
         Ok(match self {
-            ChainObject::Eth(_) => Box::new(crate::instance::eth::address::EthGenAddress::new(
-                chain::ChainCode::Ethereum,
+            ChainObject::Eth(i) => Box::new(crate::instance::eth::address::EthGenAddress::new(
+                i.chain_code,
             )),
             ChainObject::Trx(_) => Box::new(crate::instance::trx::address::TrxGenAddress {}),
             ChainObject::Sol(_) => Box::new(crate::instance::sol::address::SolGenAddress {}),
@@ -267,6 +286,10 @@ impl ChainObject {
                 address_type: i.address_type,
                 network: i.network,
             }),
+            ChainObject::Bch(i) => Box::new(crate::instance::bch::address::BchGenAddress {
+                address_type: i.address_type,
+                network: i.network,
+            }),
             ChainObject::Sui(_) => Box::new(crate::instance::sui::address::SuiGenAddress {}),
             _ => panic!("not suer used"),
         })
@@ -329,6 +352,17 @@ impl TryFrom<(&ChainCode, &AddressType, network::NetworkKind)> for ChainObject {
                     network,
                 })
             }
+            ChainCode::BitcoinCash => {
+                let AddressType::Bch(bch) = typ else {
+                    return Err(crate::Error::Types(wallet_types::Error::BchNeedAddressType));
+                };
+
+                ChainObject::Bch(crate::instance::bch::BitcoinCashInstance {
+                    chain_code: value.to_owned(),
+                    address_type: bch.to_owned(),
+                    network,
+                })
+            }
             ChainCode::Ton => {
                 let AddressType::Ton(ton) = typ else {
                     return Err(crate::Error::Types(wallet_types::Error::MissAddressType));
@@ -344,6 +378,11 @@ impl TryFrom<(&ChainCode, &AddressType, network::NetworkKind)> for ChainObject {
                 chain_code: value.to_owned(),
                 network,
             }),
+            // evm chains of the registry share the ethereum derivation and address
+            ChainCode::Evm(_) => ChainObject::Eth(crate::instance::eth::EthereumInstance {
+                chain_code: value.to_owned(),
+                network,
+            }),
         };
         Ok(res)
     }
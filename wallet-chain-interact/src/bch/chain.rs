@@ -0,0 +1,28 @@
+use crate::utxo::{NetworkParams, UtxoChain, provider::ProviderConfig};
+use alloy::primitives::map::HashMap;
+
+/// Bitcoin cash is powered by the utxo engine with the bitcoin cash network parameters,
+/// inputs are signed with SIGHASH_FORKID.
+pub struct BchChain(UtxoChain);
+
+impl BchChain {
+    pub fn new(
+        config: ProviderConfig,
+        network: wallet_types::chain::network::NetworkKind,
+        header_opt: Option<HashMap<String, String>>,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<Self> {
+        let params = NetworkParams::bitcoin_cash(network);
+        Ok(Self(UtxoChain::new(
+            params, config, network, header_opt, timeout,
+        )?))
+    }
+}
+
+impl std::ops::Deref for BchChain {
+    type Target = UtxoChain;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
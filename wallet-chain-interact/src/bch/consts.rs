@@ -0,0 +1,2 @@
+pub const BCH_DECIMAL: u8 = 8;
+pub const BCH_VALUE: f64 = 100_000_000.0;
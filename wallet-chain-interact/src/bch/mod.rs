@@ -0,0 +1,45 @@
+pub mod chain;
+pub use chain::*;
+pub mod consts;
+pub mod operations;
+use crate::utxo::{NetworkParams, address};
+pub use crate::utxo::{params, protocol, provider, utxos};
+
+// accept both cashaddr and legacy addresses of bitcoin cash
+pub fn parse_address(
+    address: &str,
+    network: wallet_types::chain::network::NetworkKind,
+) -> crate::Result<address::UtxoAddress> {
+    address::UtxoAddress::parse(NetworkParams::bitcoin_cash(network), address)
+}
+
+pub fn to_legacy_address(
+    address: &str,
+    network: wallet_types::chain::network::NetworkKind,
+) -> crate::Result<String> {
+    parse_address(address, network)?.to_legacy(NetworkParams::bitcoin_cash(network))
+}
+
+pub fn to_cash_address(
+    address: &str,
+    network: wallet_types::chain::network::NetworkKind,
+) -> crate::Result<String> {
+    parse_address(address, network)?.to_cashaddr(NetworkParams::bitcoin_cash(network))
+}
+
+pub fn wif_private_key(
+    bytes: &[u8],
+    network: wallet_types::chain::network::NetworkKind,
+) -> crate::Result<String> {
+    let inner = bitcoin::secp256k1::SecretKey::from_slice(bytes)
+        .map_err(|e| crate::Error::SignError(e.to_string()))?;
+    let key = bitcoin::PrivateKey {
+        compressed: true,
+        network: bitcoin::NetworkKind::Main,
+        inner,
+    };
+    Ok(address::private_key_to_wif(
+        NetworkParams::bitcoin_cash(network),
+        &key,
+    ))
+}
@@ -0,0 +1,171 @@
+use crate::utxo::NetworkParams;
+pub use crate::utxo::transfer::{TransferArg, TransferBuilder};
+use wallet_types::chain::{
+    self,
+    address::r#type::{BchAddressType, BtcAddressType},
+};
+
+// transfer args of bitcoin cash, from and to can be cashaddr or legacy address
+pub fn transfer_arg(
+    from: &str,
+    to: &str,
+    value: &str,
+    address_type: BchAddressType,
+    network: chain::network::NetworkKind,
+) -> crate::Result<TransferArg> {
+    let address_type = BtcAddressType::try_from(address_type.as_ref())?;
+
+    TransferArg::new(
+        NetworkParams::bitcoin_cash(network),
+        from,
+        to,
+        value,
+        address_type,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transfer_arg;
+    use crate::bch::utxos::{Utxo, UtxoList};
+    use crate::utxo::{
+        NetworkParams,
+        address::{UtxoAddress, private_key_to_wif},
+        signature::{SIGHASH_ALL_FORKID, UtxoSignature, forkid_signature_hash},
+    };
+    use bitcoin::{
+        PrivateKey,
+        script::Instruction,
+        secp256k1::{Message, Secp256k1, SecretKey, ecdsa::Signature},
+    };
+    use wallet_types::chain::{address::r#type::BchAddressType, network::NetworkKind};
+
+    #[test]
+    pub fn condition_1() {
+        // 收款地址使用 cashaddr,找零地址使用 legacy
+        let from = "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn";
+        let to = "bchreg:qpm2qsznhks23z7629mms6s4cwef74vcwv6ycwvz78";
+        let value = "0.0051";
+        let params =
+            transfer_arg(from, to, value, BchAddressType::P2pkh, NetworkKind::Regtest).unwrap();
+
+        let mut transaction_build = params.build_transaction(utxos()).unwrap();
+
+        let fee_rate = bitcoin::Amount::from_sat(1);
+        let change = params.from.script_pubkey();
+        let size = transaction_build
+            .change_and_fee(fee_rate, params.from, params.address_type, params.value)
+            .unwrap();
+
+        // the 0.005 and 0.01 utxos cover the amount
+        let transaction = &transaction_build.transaction;
+        assert_eq!(transaction.input.len(), 2);
+        assert_eq!(transaction.output.len(), 2);
+
+        let recipient =
+            bitcoin::ScriptBuf::from_hex("76a91476a04053bda0a88bda5177b86a15c3b29f55987388ac")
+                .unwrap();
+        assert_eq!(transaction.output[0].script_pubkey, recipient);
+        assert_eq!(transaction.output[0].value.to_sat(), 510_000);
+
+        assert!(change.is_p2pkh());
+        assert_eq!(transaction.output[1].script_pubkey, change);
+
+        // 2 p2pkh inputs and 2 p2pkh outputs, fee is charged on the serialized size
+        assert!((370..=376).contains(&size));
+        let fee = bitcoin::Amount::from_sat(size as u64);
+        assert_eq!(transaction_build.act_transfer_fee(), fee);
+        assert_eq!(
+            transaction.output[1].value,
+            bitcoin::Amount::from_sat(1_500_000 - 510_000) - fee
+        );
+    }
+
+    #[test]
+    pub fn sign_with_forkid() {
+        let params = NetworkParams::bitcoin_cash(NetworkKind::Regtest);
+        let secp = Secp256k1::new();
+        let key = PrivateKey {
+            compressed: true,
+            network: bitcoin::NetworkKind::Main,
+            inner: SecretKey::from_slice(&[1; 32]).unwrap(),
+        };
+        let pk = key.public_key(&secp);
+        let from = UtxoAddress::from_p2pkh(params, &pk.pubkey_hash()).to_string();
+        assert!(from.starts_with("bchreg:q"));
+
+        let arg = transfer_arg(
+            &from,
+            "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn",
+            "0.0051",
+            BchAddressType::P2pkh,
+            NetworkKind::Regtest,
+        )
+        .unwrap();
+        let mut builder = arg.build_transaction(utxos()).unwrap();
+        let used = builder.utxo.used_utxo_to_hash_map();
+
+        let wif = private_key_to_wif(params, &key);
+        let signer = UtxoSignature::new(params, &wif, used.clone()).unwrap();
+        signer.p2pkh_forkid(&mut builder.transaction).unwrap();
+
+        let script = arg.from.script_pubkey();
+        for (i, input) in builder.transaction.input.iter().enumerate() {
+            let pushes = input
+                .script_sig
+                .instructions()
+                .map(|ins| match ins.unwrap() {
+                    Instruction::PushBytes(bytes) => bytes.as_bytes().to_vec(),
+                    Instruction::Op(_) => panic!("unexpected opcode"),
+                })
+                .collect::<Vec<_>>();
+            let (sighash_type, der) = pushes[0].split_last().unwrap();
+            assert_eq!(*sighash_type as u32, SIGHASH_ALL_FORKID);
+
+            let key = format!(
+                "{}-{}",
+                input.previous_output.txid, input.previous_output.vout
+            );
+            let amount = bitcoin::Amount::from_sat(used[&key].value);
+            let sighash =
+                forkid_signature_hash(&builder.transaction, i, &script, amount, SIGHASH_ALL_FORKID)
+                    .unwrap();
+
+            let signature = Signature::from_der(der).unwrap();
+            secp.verify_ecdsa(&Message::from_digest(sighash), &signature, &pk.inner)
+                .unwrap();
+        }
+    }
+
+    pub fn utxos() -> UtxoList {
+        UtxoList(vec![
+            // 0.005
+            Utxo {
+                txid: "ed1172b141a9aac076dbc36ba1cf791a48edde46028ee5d68527d822789691ca"
+                    .to_string(),
+                vout: 1,
+                value: 500000,
+                confirmations: 10,
+                selected: false,
+            },
+            // 0.01
+            Utxo {
+                txid: "53a87b9b72759775f874ae99c1d786dc22623c1a23661052e848d12de75e875f"
+                    .to_string(),
+                vout: 2,
+                value: 1000000,
+                confirmations: 20,
+                selected: false,
+            },
+            // 0.002
+            Utxo {
+                txid: "f46a144b21aa41ba1d997784c719ab56c51c5a353b85a732ed54968b4d41c81d"
+                    .to_string(),
+                vout: 3,
+                value: 200000,
+                confirmations: 15,
+                selected: false,
+            },
+        ])
+    }
+}
@@ -1,24 +1,24 @@
 use super::operations::multisig::{BtcMultisigRaw, MultisigAccountOpt, MultisigTransactionOpt};
 use super::params::{FeeSetting, TransferResp};
-use super::provider::{Provider, ProviderConfig};
+use super::provider::ProviderConfig;
 use super::script::BtcScript;
-use super::signature::{BtcSignature, MultisigSignParams, SignatureCombiner};
-use super::{network_convert, operations, protocol};
-use crate::btc::signature::predict_transaction_size;
+use super::{network_convert, operations};
 use crate::types::{ChainPrivateKey, FetchMultisigAddressResp, MultisigSignResp, MultisigTxResp};
-use crate::{BillResourceConsume, QueryTransactionResult};
-use alloy::primitives::U256;
+use crate::utxo::signature::{
+    MultisigSignParams, SignatureCombiner, UtxoSignature, predict_transaction_size,
+};
+use crate::utxo::{NetworkParams, UtxoChain};
 use alloy::primitives::map::HashMap;
 use bitcoin::key::{Keypair, Secp256k1, rand};
 use bitcoin::taproot::TaprootBuilder;
-use bitcoin::{Address, Amount, ScriptBuf, Transaction, consensus};
+use bitcoin::{Address, ScriptBuf, Transaction, consensus};
 use wallet_types::chain::address::r#type::BtcAddressType;
 use wallet_utils::hex_func;
 
-pub struct BtcChain {
-    provider: Provider,
-    pub network: wallet_types::chain::network::NetworkKind,
-}
+/// Bitcoin is powered by the utxo engine with the bitcoin network parameters,
+/// only the multisig flow is bitcoin specific.
+pub struct BtcChain(UtxoChain);
+
 impl BtcChain {
     pub fn new(
         config: ProviderConfig,
@@ -26,159 +26,22 @@ impl BtcChain {
         header_opt: Option<HashMap<String, String>>,
         timeout: Option<std::time::Duration>,
     ) -> crate::Result<Self> {
-        let provider = Provider::new(config, header_opt, timeout)?;
-        Ok(Self { provider, network })
-    }
-
-    pub fn get_provider(&self) -> &Provider {
-        &self.provider
+        let params = NetworkParams::bitcoin(network);
+        Ok(Self(UtxoChain::new(
+            params, config, network, header_opt, timeout,
+        )?))
     }
 }
 
-impl BtcChain {
-    pub async fn balance(&self, addr: &str, _token: Option<String>) -> crate::Result<U256> {
-        let utxo = self.provider.utxos(addr, self.network).await?;
-        Ok(U256::from(utxo.balance()))
-    }
-
-    pub async fn block_num(&self) -> crate::Result<u64> {
-        let block_height = self.provider.block_heigh().await?;
-        Ok(block_height)
-    }
-
-    // 查询交易结果
-    pub async fn query_tx_res(&self, hash: &str) -> crate::Result<Option<QueryTransactionResult>> {
-        let transaction = match self
-            .provider
-            .query_transaction::<protocol::transaction::Transaction>(hash, true)
-            .await
-        {
-            Ok(res) => res,
-            Err(_) => return Ok(None),
-        };
-
-        if transaction.blockhash.is_empty() {
-            return Err(crate::Error::Other("transaction not confirm".to_string()));
-        }
+impl std::ops::Deref for BtcChain {
+    type Target = UtxoChain;
 
-        // 获取区块的高度
-        let block_header = self.provider.block_header(&transaction.blockhash).await?;
-
-        // 查询上一个交易的总输出
-        let mut total_vin = 0_f64;
-        for vin in transaction.vin.iter() {
-            let prev_tx = self
-                .provider
-                .query_transaction::<protocol::transaction::Transaction>(&vin.txid, true)
-                .await?;
-            total_vin += prev_tx.total_vout_by_sequence(vin.vout);
-        }
-        // 这次交易的总输出
-        let total_vout = transaction.total_vout();
-
-        let transaction_fee = total_vin - total_vout;
-        let status = 2;
-
-        // transaction.weight,
-        let resource_consume =
-            BillResourceConsume::one_resource(transaction.weight).to_json_str()?;
-        let res = QueryTransactionResult::new(
-            transaction.hash,
-            transaction_fee,
-            resource_consume,
-            transaction.time as u128,
-            status,
-            block_header.height as u128,
-        );
-        Ok(Some(res))
-    }
-
-    pub async fn transfer(
-        &self,
-        params: operations::transfer::TransferArg,
-        key: ChainPrivateKey,
-    ) -> crate::Result<TransferResp> {
-        let utxo = self
-            .provider
-            .utxos(&params.from.to_string(), self.network)
-            .await?;
-        let mut transaction_builder = params.build_transaction(utxo)?;
-
-        let fee_rate = params.get_fee_rate(&self.provider, self.network).await?;
-
-        let size = transaction_builder.transactin_size(fee_rate, &params)?;
-
-        let fee = fee_rate * size as u64;
-        if transaction_builder.exceeds_max_fee(fee) {
-            return Err(crate::UtxoError::ExceedsMaximum.into());
-        }
-
-        if transaction_builder.is_dust_tx(params.value, fee) {
-            return Err(crate::UtxoError::DustTx.into());
-        }
-
-        // 签名
-        let utxo = transaction_builder.utxo.used_utxo_to_hash_map();
-        let signer = BtcSignature::new(&key, utxo)?;
-        signer
-            .sign(
-                params.address_type,
-                &self.provider,
-                &mut transaction_builder.transaction,
-            )
-            .await?;
-
-        // 获取原始交易
-        let raw = transaction_builder.get_raw_transaction();
-
-        // 执行交易
-        let tx_hash = self.provider.send_raw_transaction(&raw).await?;
-
-        Ok(TransferResp::new(tx_hash, fee_rate, size))
-    }
-
-    // fee unit is btc
-    pub async fn transfer_with_fee(
-        &self,
-        params: operations::transfer::TransferArg,
-        fee: f64,
-        key: ChainPrivateKey,
-    ) -> crate::Result<TransferResp> {
-        let utxo = self
-            .provider
-            .utxos(&params.from.to_string(), self.network)
-            .await?;
-
-        let fee = bitcoin::Amount::from_float_in(fee, bitcoin::Denomination::Bitcoin)
-            .map_err(|e| crate::Error::Other(e.to_string()))?;
-
-        let mut transaction_builder = params.build_with_fee(utxo, fee)?;
-        let utxo = transaction_builder.utxo.used_utxo_to_hash_map();
-
-        let signer = BtcSignature::new(&key, utxo)?;
-        signer
-            .sign(
-                params.address_type,
-                &self.provider,
-                &mut transaction_builder.transaction,
-            )
-            .await?;
-
-        if transaction_builder.exceeds_max_fee(fee) {
-            return Err(crate::UtxoError::ExceedsMaximum.into());
-        }
-
-        if transaction_builder.is_dust_tx(params.value, fee) {
-            return Err(crate::UtxoError::DustTx.into());
-        }
-        let raw = transaction_builder.get_raw_transaction();
-
-        // 执行交易
-        let tx_hash = self.provider.send_raw_transaction(&raw).await?;
-
-        Ok(TransferResp::new(tx_hash, Amount::default(), 0))
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
+}
 
+impl BtcChain {
     // 手续费
     pub async fn estimate_fee(
         &self,
@@ -186,13 +49,13 @@ impl BtcChain {
         multisig_sign_params: Option<MultisigSignParams>,
     ) -> crate::Result<FeeSetting> {
         let utxo = self
-            .provider
+            .get_provider()
             .utxos(&params.from.to_string(), self.network)
             .await?;
         let mut transaction_builder = params.build_transaction(utxo)?;
         transaction_builder.multisig_sign_params = multisig_sign_params;
 
-        let fee_rate = params.get_fee_rate(&self.provider, self.network).await?;
+        let fee_rate = params.get_fee_rate(self.get_provider()).await?;
 
         let size = transaction_builder.transactin_size(fee_rate, &params)?;
 
@@ -211,11 +74,17 @@ impl BtcChain {
 
         let address_type = BtcAddressType::try_from(address_type)?;
 
-        let size = predict_transaction_size(tx, None, address_type, &Some(multisig_sign_params))?;
+        let size = predict_transaction_size(
+            self.params,
+            tx,
+            None,
+            address_type,
+            &Some(multisig_sign_params),
+        )?;
 
         let fee_rate = self
-            .provider
-            .fetch_fee_rate(super::consts::FEE_RATE as u32, self.network)
+            .get_provider()
+            .fetch_fee_rate(self.params.fee_blocks)
             .await?;
 
         Ok(FeeSetting { fee_rate, size })
@@ -227,10 +96,10 @@ impl BtcChain {
         multisig_sign_params: MultisigSignParams,
     ) -> crate::Result<MultisigTxResp> {
         let utxo = self
-            .provider
+            .get_provider()
             .utxos(&params.from.to_string(), self.network)
             .await?;
-        let fee_rate = params.get_fee_rate(&self.provider, self.network).await?;
+        let fee_rate = params.get_fee_rate(self.get_provider()).await?;
 
         let mut transaction_builder = params.build_transaction(utxo)?;
         transaction_builder.set_multisig_params(multisig_sign_params);
@@ -277,9 +146,14 @@ impl BtcChain {
         let script = ScriptBuf::from_hex(&params.script_hex)
             .map_err(|e| crate::Error::BtcScript(e.to_string()))?;
 
-        let signer = BtcSignature::new(&key, raw_data.used_utxo)?;
+        let signer = UtxoSignature::new(self.params, &key, raw_data.used_utxo)?;
         let sign = signer
-            .multisig_sign_v1(params.address_type, script, transaction, &self.provider)
+            .multisig_sign(
+                params.address_type,
+                script,
+                transaction,
+                self.get_provider(),
+            )
             .await?;
 
         let signature = hex_func::bincode_encode(&sign)?;
@@ -320,8 +194,8 @@ impl BtcChain {
 
         // check fee
         let fee_rate = self
-            .provider
-            .fetch_fee_rate(super::consts::FEE_RATE as u32, self.network)
+            .get_provider()
+            .fetch_fee_rate(self.params.fee_blocks)
             .await?;
         let size = transaction.vsize();
 
@@ -342,7 +216,7 @@ impl BtcChain {
 
         let hex_raw = consensus::encode::serialize_hex(&transaction);
 
-        let tx_hash = self.provider.send_raw_transaction(&hex_raw).await?;
+        let tx_hash = self.get_provider().send_raw_transaction(&hex_raw).await?;
         Ok(TransferResp::new(tx_hash, fee_rate, size))
     }
 
@@ -396,16 +270,4 @@ impl BtcChain {
         };
         Ok(resp)
     }
-
-    pub async fn decimals(&self, _token: &str) -> crate::Result<u8> {
-        Ok(super::consts::BTC_DECIMAL)
-    }
-
-    pub async fn token_symbol(&self, _token: &str) -> crate::Result<String> {
-        Ok("".to_string())
-    }
-
-    pub async fn token_name(&self, _token: &str) -> crate::Result<String> {
-        Ok("".to_string())
-    }
 }
@@ -1,7 +1,2 @@
 pub const BTC_DECIMAL: u8 = 8;
 pub const BTC_VALUE: f64 = 100_000_000.0;
-pub const FEE_RATE: u8 = 1;
-// 手续费最大费率
-pub const MAX_FEE_RATE: u64 = 200;
-// 手续费放大的值
-pub const EXPEND_FEE_RATE: u64 = 2;
@@ -4,14 +4,9 @@ use std::str::FromStr;
 pub use chain::*;
 pub mod consts;
 pub mod operations;
-pub mod params;
-pub mod protocol;
-pub mod provider;
 pub mod script;
-mod signature;
-pub use signature::MultisigSignParams;
-// mod tx_build;
-mod utxos;
+pub use crate::utxo::signature::MultisigSignParams;
+pub use crate::utxo::{params, protocol, provider, utxos};
 
 pub struct ParseBtcAddress {
     pub network: bitcoin::Network,
@@ -1,6 +0,0 @@
-mod account;
-pub use account::*;
-pub mod block;
-pub mod transaction;
-pub use block::*;
-pub mod other;
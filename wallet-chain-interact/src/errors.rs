@@ -115,6 +115,8 @@ pub enum Error {
     TonError(#[from] TonError),
     #[error("sui error {0}")]
     SuiError(#[from] crate::sui::error::SuiError),
+    #[error("sol error {0}")]
+    SolError(#[from] crate::sol::error::SolError),
 
     // flow to optimize
     #[error("hex error {0}")]
@@ -131,12 +133,20 @@ pub enum Error {
     RpcError(String),
     #[error("contract validation error {0}")]
     ContractValidationError(ContractValidationError),
+    #[error("contract revert {0}")]
+    ContractRevert(crate::eth::ContractRevert),
     #[error("parse error {0}")]
     ParseError(#[from] ParseErr),
     #[error("utxo error")]
     UtxoError(#[from] UtxoError),
     #[error("transfer error {0}")]
     TransferError(String),
+    // a multi step operation failed after some transactions were already sent
+    #[error("{source}, sent transactions {tx_ids:?}")]
+    PartiallySent {
+        source: Box<Error>,
+        tx_ids: Vec<String>,
+    },
     #[error("any chain")]
     AnyChainError(#[from] anychain_core::error::Error),
     #[error("any chain transaction")]
@@ -1,17 +1,24 @@
 use super::operations;
+use super::operations::{ens, nft};
+use super::protocol::contract::{self, IERC165, IERC721, IERC1155, IPermit2};
 use super::provider::Provider;
 use crate::types::{ChainPrivateKey, FetchMultisigAddressResp, MultisigTxResp, Transaction};
-use crate::{BillResourceConsume, QueryTransactionResult};
+use crate::{BillResourceConsume, FeeResponse, QueryTransactionResult};
+use alloy::network::TransactionBuilder as _;
 use alloy::primitives::{Address, U256};
 use alloy::rpc::types::TransactionRequest;
-use alloy::sol_types::{SolType, SolValue};
+use alloy::sol_types::{SolCall, SolError as _, SolType, SolValue};
 use wallet_types::chain::chain::ChainCode;
+use wallet_types::chain::evm::{EvmChainInfo, EvmRollup};
 use wallet_types::chain::network;
 use wallet_utils::unit;
 
+/// Chain client shared by every evm compatible chain,
+/// the chain specific behavior comes from the evm chain registry.
 pub struct EthChain {
     pub provider: Provider,
     pub chain_code: ChainCode,
+    pub chain: EvmChainInfo,
     network: network::NetworkKind,
 }
 
@@ -21,12 +28,29 @@ impl EthChain {
         network: network::NetworkKind,
         chain_code: ChainCode,
     ) -> crate::Result<Self> {
+        let chain = EvmChainInfo::resolve(chain_code, network).ok_or(crate::Error::Other(
+            format!("{} is not an evm chain", chain_code),
+        ))?;
+
         Ok(Self {
             provider,
             chain_code,
+            chain,
             network,
         })
     }
+
+    pub fn chain_id(&self) -> u64 {
+        self.chain.chain_id
+    }
+
+    pub fn native_symbol(&self) -> &'static str {
+        self.chain.native_symbol
+    }
+
+    pub fn explorer_tx_url(&self, hash: &str) -> String {
+        self.chain.tx_url(hash)
+    }
 }
 
 impl EthChain {
@@ -97,7 +121,7 @@ impl EthChain {
 
         let transaction_time = block.header.timestamp as u128;
         let transaction_fee =
-            (receipt.effective_gas_price * receipt.gas_used) as f64 / super::consts::ETH_VALUE;
+            (receipt.effective_gas_price * receipt.gas_used) as f64 / self.chain.unit();
         let status = if receipt.status() { 2 } else { 3 };
         let block_number = block.header.number as u128;
 
@@ -130,6 +154,467 @@ impl EthChain {
     }
 }
 
+// about erc20 permit and allowance
+impl EthChain {
+    // fill the nonce and domain separator of an eip-2612 permit
+    pub async fn prepare_permit(
+        &self,
+        permit: operations::erc::Erc20Permit,
+    ) -> crate::Result<operations::erc::Erc20Permit> {
+        let owner = permit.owner.to_string();
+        let token = permit.contract.to_string();
+
+        let nonce = self
+            .provider
+            .call_returns::<contract::noncesCall>(operations::erc::permit_nonce_tx(&owner, &token)?)
+            .await?
+            ._0;
+        let domain_separator = self
+            .provider
+            .call_returns::<contract::DOMAIN_SEPARATORCall>(operations::erc::domain_separator_tx(
+                &token,
+            )?)
+            .await?
+            ._0;
+
+        Ok(permit.with_domain(nonce, domain_separator))
+    }
+
+    // erc20 allowance of the spender across the token list
+    pub async fn allowances(
+        &self,
+        owner: &str,
+        spender: &str,
+        tokens: &[String],
+    ) -> crate::Result<Vec<operations::erc::TokenAllowance>> {
+        let mut res = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            let tx = operations::erc::Allowance::new(owner, token, spender)?.build_transaction()?;
+            let amount = self
+                .provider
+                .call_returns::<contract::allowanceCall>(tx)
+                .await?
+                ._0;
+
+            res.push(operations::erc::TokenAllowance {
+                token: token.clone(),
+                spender: spender.to_string(),
+                amount,
+                expiration: None,
+            });
+        }
+        Ok(res)
+    }
+
+    // permit2 allowance of the spender across the token list, also returns the nonce for the next signature
+    pub async fn permit2_allowances(
+        &self,
+        owner: &str,
+        spender: &str,
+        tokens: &[String],
+    ) -> crate::Result<Vec<(operations::erc::TokenAllowance, u64)>> {
+        let mut res = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            let tx = operations::erc::permit2_allowance_tx(owner, token, spender)?;
+            let allowance = self
+                .provider
+                .call_returns::<IPermit2::allowanceCall>(tx)
+                .await?;
+
+            let info = operations::erc::TokenAllowance {
+                token: token.clone(),
+                spender: spender.to_string(),
+                amount: U256::from(allowance.amount),
+                expiration: Some(allowance.expiration.to::<u64>()),
+            };
+            res.push((info, allowance.nonce.to::<u64>()));
+        }
+        Ok(res)
+    }
+}
+
+// about nft
+impl EthChain {
+    // erc-165 check, contracts without supportsInterface revert or return nothing and count as unsupported,
+    // network and node failures are returned
+    pub async fn supports_interface(
+        &self,
+        contract: &str,
+        interface_id: [u8; 4],
+    ) -> crate::Result<bool> {
+        let tx = nft::supports_interface_tx(contract, interface_id)?;
+        let bytes = match self.provider.call_bytes(tx).await {
+            Ok(bytes) => bytes,
+            Err(err) if super::is_revert(&err) => return Ok(false),
+            Err(err) => return Err(err),
+        };
+
+        if bytes.is_empty() {
+            return Ok(false);
+        }
+
+        let res = IERC165::supportsInterfaceCall::abi_decode_returns(&bytes, true)
+            .map_err(|e| crate::Error::HexError(e.to_string()))?;
+        Ok(res._0)
+    }
+
+    pub async fn nft_standard(&self, contract: &str) -> crate::Result<Option<nft::NftStandard>> {
+        for standard in [nft::NftStandard::Erc721, nft::NftStandard::Erc1155] {
+            if self
+                .supports_interface(contract, standard.interface_id())
+                .await?
+            {
+                return Ok(Some(standard));
+            }
+        }
+        Ok(None)
+    }
+
+    pub async fn nft_owner(&self, contract: &str, token_id: U256) -> crate::Result<String> {
+        let tx = nft::owner_of_tx(contract, token_id)?;
+        let res = self
+            .provider
+            .call_returns::<IERC721::ownerOfCall>(tx)
+            .await?;
+        Ok(res._0.to_string())
+    }
+
+    // erc721 counts the tokens of the collection, erc1155 the amount of the id
+    pub async fn nft_balance(
+        &self,
+        contract: &str,
+        owner: &str,
+        standard: nft::NftStandard,
+        token_id: U256,
+    ) -> crate::Result<U256> {
+        let res = match standard {
+            nft::NftStandard::Erc721 => {
+                let tx = nft::erc721_balance_tx(contract, owner)?;
+                self.provider
+                    .call_returns::<IERC721::balanceOfCall>(tx)
+                    .await?
+                    ._0
+            }
+            nft::NftStandard::Erc1155 => {
+                let tx = nft::erc1155_balance_tx(contract, owner, token_id)?;
+                self.provider
+                    .call_returns::<IERC1155::balanceOfCall>(tx)
+                    .await?
+                    ._0
+            }
+        };
+        Ok(res)
+    }
+
+    pub async fn nft_uri(
+        &self,
+        contract: &str,
+        standard: nft::NftStandard,
+        token_id: U256,
+    ) -> crate::Result<String> {
+        let res = match standard {
+            nft::NftStandard::Erc721 => {
+                let tx = nft::token_uri_tx(contract, token_id)?;
+                self.provider
+                    .call_returns::<IERC721::tokenURICall>(tx)
+                    .await?
+                    ._0
+            }
+            nft::NftStandard::Erc1155 => {
+                let tx = nft::uri_tx(contract, token_id)?;
+                self.provider
+                    .call_returns::<IERC1155::uriCall>(tx)
+                    .await?
+                    ._0
+            }
+        };
+        Ok(res)
+    }
+
+    // read the token uri and fetch the metadata json, ipfs uri goes through the gateway
+    pub async fn nft_metadata(
+        &self,
+        contract: &str,
+        standard: nft::NftStandard,
+        token_id: U256,
+        gateway: Option<&str>,
+    ) -> crate::Result<nft::NftMetadata> {
+        let uri = self.nft_uri(contract, standard, token_id).await?;
+        let gateway = gateway.unwrap_or(nft::DEFAULT_IPFS_GATEWAY);
+
+        match nft::resolve_uri(&uri, token_id, gateway)? {
+            nft::MetadataSource::Inline(metadata) => Ok(metadata),
+            nft::MetadataSource::Url(url) => {
+                let client = wallet_transport::client::HttpClient::new(
+                    &url,
+                    None,
+                    Some(std::time::Duration::from_secs(15)),
+                )?;
+                let metadata = wallet_transport::request_builder::ReqBuilder(
+                    client.client.get(&client.base_url),
+                )
+                .send::<nft::NftMetadata>()
+                .await?;
+                Ok(metadata)
+            }
+        }
+    }
+}
+
+// about dynamic contract call
+impl EthChain {
+    // revert errors of the node are decoded with the abi of the call
+    fn map_revert(&self, err: crate::Error, abi: &alloy::json_abi::JsonAbi) -> crate::Error {
+        match super::revert_data(&err) {
+            Some(data) => {
+                crate::Error::ContractRevert(super::ContractRevert::decode(&data, Some(abi)))
+            }
+            None if super::is_revert(&err) => {
+                crate::Error::ContractRevert(super::ContractRevert::decode(&[], Some(abi)))
+            }
+            None => err,
+        }
+    }
+
+    pub async fn call_contract(
+        &self,
+        call: &operations::ContractCall,
+    ) -> crate::Result<serde_json::Value> {
+        let tx = call.build_transaction()?;
+        let bytes = self
+            .provider
+            .call_bytes(tx)
+            .await
+            .map_err(|e| self.map_revert(e, &call.abi))?;
+
+        call.decode_output(&bytes)
+    }
+
+    pub async fn estimate_contract_gas(
+        &self,
+        call: &operations::ContractCall,
+    ) -> crate::Result<crate::params::ResourceConsume> {
+        let tx = call.build_transaction()?;
+        let gas = self
+            .provider
+            .estimate_gas(tx)
+            .await
+            .map_err(|e| self.map_revert(e, &call.abi))?;
+
+        Ok(crate::params::ResourceConsume::new(gas.to::<i64>()))
+    }
+}
+
+// about ens
+impl EthChain {
+    fn ens_registry(&self) -> crate::Result<Address> {
+        // mainnet, sepolia, holesky
+        if !matches!(self.chain_id(), 1 | 11155111 | 17000) {
+            return Err(crate::Error::NotSupportApi(format!(
+                "ens on chain {}",
+                self.chain_id()
+            )));
+        }
+        Ok(wallet_utils::address::parse_eth_address(
+            super::consts::ENS_REGISTRY,
+        )?)
+    }
+
+    // resolver of the name or of the closest parent (ensip-10), with whether it is the exact name
+    async fn ens_resolver(&self, name: &str) -> crate::Result<Option<(Address, bool)>> {
+        let registry = self.ens_registry()?;
+
+        let mut current = name;
+        loop {
+            let tx = ens::resolver_tx(registry, current);
+            let resolver = self
+                .provider
+                .call_returns::<contract::IENSRegistry::resolverCall>(tx)
+                .await?
+                ._0;
+            if resolver != Address::ZERO {
+                return Ok(Some((resolver, current == name)));
+            }
+
+            match current.split_once('.') {
+                Some((_, parent)) => current = parent,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    // eth_call that follows the OffchainLookup reverts of the resolver
+    async fn ccip_call(&self, to: Address, data: Vec<u8>) -> crate::Result<Vec<u8>> {
+        let mut data = data;
+
+        for _ in 0..ens::MAX_CCIP_LOOKUPS {
+            let tx = TransactionRequest::default()
+                .with_to(to)
+                .with_input(data.clone());
+            let err = match self.provider.call_bytes(tx).await {
+                Ok(res) => return Ok(res),
+                Err(e) => e,
+            };
+
+            let Some(lookup) = super::revert_data(&err)
+                .and_then(|d| contract::OffchainLookup::abi_decode(&d, true).ok())
+            else {
+                return Err(err);
+            };
+            if lookup.sender != to {
+                return Err(crate::Error::Other(format!(
+                    "offchain lookup sender {} is not {}",
+                    lookup.sender, to
+                )));
+            }
+
+            let response = self.ccip_fetch(&lookup).await?;
+            data = ens::callback_data(&lookup, response);
+        }
+
+        Err(crate::Error::Other("too many offchain lookups".to_string()))
+    }
+
+    // gateways are tried in order until one answers
+    async fn ccip_fetch(&self, lookup: &contract::OffchainLookup) -> crate::Result<Vec<u8>> {
+        let mut last_err = None;
+        for url in lookup.urls.iter() {
+            let request = ens::CcipRequest::new(url, lookup.sender, &lookup.callData);
+            match request.send().await {
+                Ok(data) => return Ok(data),
+                Err(e) => {
+                    tracing::warn!("ccip gateway {} failed: {}", url, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or(crate::Error::Other(
+            "offchain lookup without urls".to_string(),
+        )))
+    }
+
+    // calls the resolver with `data`, through resolve(bytes,bytes) when it is an extended resolver
+    async fn ens_resolve(&self, name: &str, data: Vec<u8>) -> crate::Result<Option<Vec<u8>>> {
+        let Some((resolver, exact)) = self.ens_resolver(name).await? else {
+            return Ok(None);
+        };
+
+        let extended = self
+            .supports_interface(&resolver.to_string(), ens::EXTENDED_RESOLVER_INTERFACE)
+            .await?;
+        if extended {
+            let call = contract::IExtendedResolver::resolveCall {
+                name: ens::dns_encode(name)?.into(),
+                data: data.into(),
+            };
+            let res = self.ccip_call(resolver, call.abi_encode()).await?;
+            let res = contract::IExtendedResolver::resolveCall::abi_decode_returns(&res, true)
+                .map_err(|e| crate::Error::HexError(e.to_string()))?;
+            Ok(Some(res._0.to_vec()))
+        } else if exact {
+            Ok(Some(self.ccip_call(resolver, data).await?))
+        } else {
+            // the parent resolver does not support wildcards
+            Ok(None)
+        }
+    }
+
+    pub async fn resolve_name(&self, name: &str) -> crate::Result<Option<Address>> {
+        let name = crate::name::normalize(name)?;
+
+        let Some(res) = self.ens_resolve(&name, ens::addr_data(&name)).await? else {
+            return Ok(None);
+        };
+        let address = contract::IENSResolver::addrCall::abi_decode_returns(&res, true)
+            .map_err(|e| crate::Error::HexError(e.to_string()))?
+            ._0;
+
+        Ok((address != Address::ZERO).then_some(address))
+    }
+
+    // primary name of the address, only returned when it resolves back to the address
+    pub async fn lookup_address(&self, addr: &str) -> crate::Result<Option<String>> {
+        let address = wallet_utils::address::parse_eth_address(addr)?;
+        let reverse = ens::reverse_name(address);
+
+        let Some(res) = self.ens_resolve(&reverse, ens::name_data(&reverse)).await? else {
+            return Ok(None);
+        };
+        let name = contract::IENSResolver::nameCall::abi_decode_returns(&res, true)
+            .map_err(|e| crate::Error::HexError(e.to_string()))?
+            ._0;
+        if name.is_empty() {
+            return Ok(None);
+        }
+
+        match self.resolve_name(&name).await? {
+            Some(forward) if forward == address => Ok(Some(name)),
+            _ => Ok(None),
+        }
+    }
+}
+
+// about l2 data fee
+impl EthChain {
+    // l1 data cost of the transaction, zero on chains that are not rollups
+    pub async fn l1_data_fee(
+        &self,
+        tx: TransactionRequest,
+        fee: &super::params::FeeSetting,
+    ) -> crate::Result<super::params::L1DataFee> {
+        let res = match self.chain.rollup {
+            None => super::params::L1DataFee::default(),
+            Some(EvmRollup::OpStack) => {
+                let tx = self
+                    .provider
+                    .set_transaction_fee(tx, fee.clone(), self.chain.fee_model)
+                    .await?;
+                let serialized = self.provider.serialize_for_fee(tx).await?;
+
+                super::params::L1DataFee {
+                    fee: self.provider.op_l1_fee(serialized).await?,
+                    gas: U256::ZERO,
+                }
+            }
+            Some(EvmRollup::Arbitrum) => {
+                let components = self.provider.arb_gas_components(&tx).await?;
+                let gas = U256::from(components.gasEstimateForL1);
+
+                super::params::L1DataFee {
+                    fee: gas * components.baseFee,
+                    gas,
+                }
+            }
+        };
+        Ok(res)
+    }
+
+    // fee with the l1 data cost merged, the gas used only counts the l2 execution
+    pub async fn estimate_fee<T>(
+        &self,
+        params: T,
+        mut fee: super::params::FeeSetting,
+    ) -> crate::Result<FeeResponse>
+    where
+        T: crate::types::Transaction<TransactionRequest>,
+    {
+        let tx = params.build_transaction()?;
+        let l1 = self.l1_data_fee(tx, &fee).await?;
+
+        let gas_price = fee.base_fee + fee.max_priority_fee_per_gas;
+        let gas_used = fee.gas_limit.saturating_sub(l1.gas);
+        // arbitrum pays the l1 cost with gas units already inside the gas limit
+        if l1.gas.is_zero() {
+            fee.l1_fee = l1.fee;
+        }
+
+        Ok(FeeResponse::new(gas_price, gas_used)
+            .l1_fee(l1.fee)
+            .set_fee(fee.transaction_fee()))
+    }
+}
+
 // about send transaction
 impl EthChain {
     pub async fn eth_call<T, R>(&self, params: T) -> crate::Result<R>
@@ -167,7 +652,7 @@ impl EthChain {
 
         let transfer_params = self
             .provider
-            .set_transaction_fee(params, fee, self.chain_code)
+            .set_transaction_fee(params, fee, self.chain.fee_model)
             .await?;
 
         self.provider
@@ -182,6 +667,7 @@ impl EthChain {
         &self,
         params: operations::MultisigAccountOpt,
     ) -> crate::Result<FetchMultisigAddressResp> {
+        let params = params.with_safe_deployment(&self.chain)?;
         let salt = params.get_nonce()?.to_string();
         let address: Address = self.eth_call(params).await?;
         Ok(FetchMultisigAddressResp::new_with_salt(
@@ -190,6 +676,222 @@ impl EthChain {
         ))
     }
 
+    pub async fn safe_owners(&self, safe: &str) -> crate::Result<(Vec<Address>, u64)> {
+        let safe_addr = wallet_utils::address::parse_eth_address(safe)?;
+
+        let tx = TransactionRequest::default()
+            .to(safe_addr)
+            .input(contract::getOwnersCall {}.abi_encode().into());
+        let owners = self
+            .provider
+            .call_returns::<contract::getOwnersCall>(tx)
+            .await?
+            ._0;
+
+        let tx = TransactionRequest::default()
+            .to(safe_addr)
+            .input(contract::getThresholdCall {}.abi_encode().into());
+        let threshold = self
+            .provider
+            .call_returns::<contract::getThresholdCall>(tx)
+            .await?
+            ._0;
+
+        Ok((owners, threshold.to::<u64>()))
+    }
+
+    pub async fn safe_owner_manager(
+        &self,
+        safe: &str,
+    ) -> crate::Result<operations::SafeOwnerManager> {
+        let (owners, threshold) = self.safe_owners(safe).await?;
+        operations::SafeOwnerManager::new(safe, owners, threshold)
+    }
+
+    // batch builder bound to the MultiSendCallOnly library of the chain
+    pub fn multi_send(&self) -> crate::Result<operations::MultiSend> {
+        let safe = self.chain.safe.ok_or(crate::Error::Other(format!(
+            "no safe deployment on {}",
+            self.chain.name
+        )))?;
+        operations::MultiSend::new(safe.multi_send)
+    }
+
+    // check every signature against the current owners, approved hashes and eip-1271,
+    // returns the sorted signatures for execTransaction sent by the executor
+    pub async fn validate_multisig_signatures(
+        &self,
+        safe: &str,
+        signatures: &operations::SafeSignatures,
+        executor: &str,
+    ) -> crate::Result<String> {
+        let (owners, threshold) = self.safe_owners(safe).await?;
+        signatures.validate(&owners, threshold)?;
+
+        let safe_addr = wallet_utils::address::parse_eth_address(safe)?;
+        let executor = wallet_utils::address::parse_eth_address(executor)?;
+        let mut tx_data = None;
+        for signature in signatures.signatures.iter() {
+            match signature {
+                operations::SafeSignature::Ecdsa { .. } => {}
+                // safe accepts msg.sender == owner without an on-chain approval
+                operations::SafeSignature::ApprovedHash { owner } if *owner == executor => {}
+                operations::SafeSignature::ApprovedHash { owner } => {
+                    let call = contract::approvedHashesCall {
+                        owner: *owner,
+                        hash: signatures.safe_tx_hash,
+                    };
+                    let tx = TransactionRequest::default()
+                        .to(safe_addr)
+                        .input(call.abi_encode().into());
+                    let approved = self
+                        .provider
+                        .call_returns::<contract::approvedHashesCall>(tx)
+                        .await?;
+                    if approved._0.is_zero() {
+                        return Err(crate::Error::SignError(format!(
+                            "{} has not approved the hash",
+                            owner
+                        )));
+                    }
+                }
+                operations::SafeSignature::Contract { owner, signature } => {
+                    // the encoded safe tx is only fetched once, for the first contract owner
+                    if tx_data.is_none()
+                        && let Some(input) = signatures.encode_tx_data_call()?
+                    {
+                        let tx = TransactionRequest::default()
+                            .to(safe_addr)
+                            .input(input.into());
+                        let data = self
+                            .provider
+                            .call_returns::<contract::encodeTransactionDataCall>(tx)
+                            .await?;
+                        tx_data = Some(data._0);
+                    }
+
+                    let valid = self
+                        .is_valid_contract_signature(*owner, tx_data.as_ref(), signature)
+                        .await?;
+                    if !valid {
+                        return Err(crate::Error::SignError(format!(
+                            "invalid eip-1271 signature of {}",
+                            owner
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(signatures.to_hex())
+    }
+
+    // safe v1.3.0 only calls the legacy isValidSignature(bytes,bytes) with the encoded safe tx,
+    // validators without it are rejected by checkSignatures (GS024)
+    async fn is_valid_contract_signature(
+        &self,
+        owner: Address,
+        tx_data: Option<&alloy::primitives::Bytes>,
+        signature: &[u8],
+    ) -> crate::Result<bool> {
+        let data = tx_data.ok_or(crate::Error::SignError(
+            "the safe tx data is required to check contract signatures".to_string(),
+        ))?;
+
+        let call = contract::ISignatureValidator::isValidSignatureCall {
+            _data: data.clone(),
+            _signature: signature.to_vec().into(),
+        };
+        let tx = TransactionRequest::default()
+            .to(owner)
+            .input(call.abi_encode().into());
+        match self
+            .provider
+            .call_returns::<contract::ISignatureValidator::isValidSignatureCall>(tx)
+            .await
+        {
+            Ok(magic) => Ok(magic._0.0 == operations::LEGACY_EIP1271_MAGIC_VALUE),
+            Err(crate::Error::HexError(_)) => Ok(false),
+            Err(err) if super::is_revert(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    // eth_call execTransaction before signing or sending, with the collected signatures
+    // or, without them, the executor approval and the threshold overridden to 1
+    pub async fn simulate_multisig_tx(
+        &self,
+        mut params: operations::SafeSimulationOpt,
+    ) -> crate::Result<operations::SafeSimulation> {
+        let nonce = self
+            .provider
+            .call_returns::<contract::nonceCall>(params.nonce_tx())
+            .await?;
+        params.check_nonce(nonce.nonce)?;
+
+        let (tx, overrides) = match params.signatures.clone() {
+            Some(signatures) => (params.exec_tx(signatures), None),
+            None => {
+                // the approval is only accepted from an owner
+                let (owners, _) = self.safe_owners(&params.safe.to_string()).await?;
+                if !owners.contains(&params.executor)
+                    && let Some(owner) = owners.first()
+                {
+                    params.executor = *owner;
+                }
+                let signatures = params.executor_signature();
+                (
+                    params.exec_tx(signatures),
+                    Some(params.threshold_override()),
+                )
+            }
+        };
+        let state_override = overrides.is_some();
+
+        let call = match overrides.clone() {
+            Some(overrides) => {
+                self.provider
+                    .eth_call_with_override(tx.clone(), overrides)
+                    .await
+            }
+            None => self.provider.eth_call(tx.clone()).await,
+        };
+
+        if let Err(err) = call {
+            let Some(data) = super::revert_data(&err) else {
+                if super::is_revert(&err) {
+                    let revert = super::ContractRevert::decode(&[], None);
+                    return Ok(operations::SafeSimulation::failed(revert, state_override));
+                }
+                return Err(err);
+            };
+
+            let revert = super::ContractRevert::decode(&data, None);
+            let mut res = operations::SafeSimulation::failed(revert, state_override);
+            if res.inner_call_failed()
+                && let Some(inner) = params.inner_tx()
+                && let Err(e) = self.provider.eth_call(inner).await
+                && let Some(data) = super::revert_data(&e)
+            {
+                res.inner_revert = Some(super::ContractRevert::decode(&data, None));
+            }
+            return Ok(res);
+        }
+
+        let gas = match overrides {
+            Some(overrides) => {
+                self.provider
+                    .estimate_gas_with_override(tx, overrides)
+                    .await
+            }
+            None => self.provider.estimate_gas(tx).await,
+        };
+        Ok(operations::SafeSimulation::success(
+            gas.ok().map(|g| g.to::<u64>()),
+            state_override,
+        ))
+    }
+
     pub async fn build_multisig_tx(
         &self,
         mut params: operations::MultisigTransferOpt,
@@ -2,3 +2,14 @@ pub const ETH_DECIMAL: u8 = 18;
 pub const ETH_GWEI: u8 = 9;
 pub const ETH_GWEI_VALUE: u64 = 1_000_000_000;
 pub const ETH_VALUE: f64 = 1_000_000_000_000_000_000.0;
+
+// op-stack GasPriceOracle predeploy
+pub const OP_GAS_PRICE_ORACLE: &str = "0x420000000000000000000000000000000000000F";
+// arbitrum NodeInterface, only reachable through eth_call
+pub const ARB_NODE_INTERFACE: &str = "0x00000000000000000000000000000000000000C8";
+
+// uniswap permit2, same address on every chain
+pub const PERMIT2: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+
+// ens registry with fallback, mainnet / sepolia / holesky
+pub const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
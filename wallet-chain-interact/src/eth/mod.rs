@@ -7,3 +7,5 @@ pub mod consts;
 mod protocol;
 mod provider;
 pub use provider::*;
+mod revert;
+pub use revert::*;
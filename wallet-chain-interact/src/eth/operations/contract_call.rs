@@ -0,0 +1,299 @@
+// 根据 abi json 动态构造合约调用
+use crate::types;
+use alloy::{
+    dyn_abi::{DynSolType, DynSolValue, JsonAbiExt as _, Specifier as _},
+    json_abi::{Function, JsonAbi, Param},
+    network::TransactionBuilder as _,
+    primitives::{self, U256},
+    rpc::types::TransactionRequest,
+};
+use wallet_utils::address;
+
+/// Contract call built from an abi json fragment, for dApp requests
+/// that have no `sol!` binding in the wallet.
+pub struct ContractCall {
+    pub from: primitives::Address,
+    pub contract: primitives::Address,
+    pub value: U256,
+    pub abi: JsonAbi,
+    pub function: Function,
+    pub args: Vec<DynSolValue>,
+}
+
+impl ContractCall {
+    // `function` is the name or the full signature like `transfer(address,uint256)`,
+    // `args` is a json array matching the function inputs
+    pub fn new(
+        from: &str,
+        contract: &str,
+        abi_json: &str,
+        function: &str,
+        args: serde_json::Value,
+    ) -> crate::Result<Self> {
+        let abi = parse_abi(abi_json)?;
+
+        let args = match args {
+            serde_json::Value::Array(args) => args,
+            serde_json::Value::Null => vec![],
+            other => vec![other],
+        };
+        let function = select_function(&abi, function, args.len())?.clone();
+        if function.inputs.len() != args.len() {
+            return Err(crate::Error::AbiParseError(format!(
+                "function {} expects {} args",
+                function.name,
+                function.inputs.len()
+            )));
+        }
+
+        let args = function
+            .inputs
+            .iter()
+            .zip(args.iter())
+            .map(|(param, value)| json_to_value(&resolve(param)?, value))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            from: address::parse_eth_address(from)?,
+            contract: address::parse_eth_address(contract)?,
+            value: U256::ZERO,
+            abi,
+            function,
+            args,
+        })
+    }
+
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn calldata(&self) -> crate::Result<Vec<u8>> {
+        self.function
+            .abi_encode_input(&self.args)
+            .map_err(|e| crate::Error::AbiParseError(e.to_string()))
+    }
+
+    // return data as json, an object when every output is named, otherwise an array
+    pub fn decode_output(&self, data: &[u8]) -> crate::Result<serde_json::Value> {
+        let values = self
+            .function
+            .abi_decode_output(data, true)
+            .map_err(|e| crate::Error::AbiParseError(e.to_string()))?;
+        Ok(values_to_json(&values, &self.function.outputs))
+    }
+
+    pub fn decode_revert(&self, data: &[u8]) -> crate::eth::ContractRevert {
+        crate::eth::ContractRevert::decode(data, Some(&self.abi))
+    }
+}
+
+impl types::Transaction<TransactionRequest> for ContractCall {
+    fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
+        Ok(TransactionRequest::default()
+            .from(self.from)
+            .to(self.contract)
+            .value(self.value)
+            .with_input(self.calldata()?))
+    }
+}
+
+// full abi array or a single abi item
+pub fn parse_abi(abi_json: &str) -> crate::Result<JsonAbi> {
+    let abi_json = abi_json.trim();
+    let res = if abi_json.starts_with('{') {
+        serde_json::from_str::<JsonAbi>(&format!("[{}]", abi_json))
+    } else {
+        serde_json::from_str::<JsonAbi>(abi_json)
+    };
+    res.map_err(|e| crate::Error::AbiParseError(e.to_string()))
+}
+
+fn select_function<'a>(
+    abi: &'a JsonAbi,
+    function: &str,
+    args_len: usize,
+) -> crate::Result<&'a Function> {
+    let not_found = || crate::Error::AbiParseError(format!("function {} not in abi", function));
+
+    // full signature picks the overload directly
+    if function.contains('(') {
+        let name = function.split('(').next().unwrap_or_default();
+        return abi
+            .function(name)
+            .and_then(|f| f.iter().find(|f| f.signature() == function))
+            .ok_or_else(not_found);
+    }
+
+    let overloads = abi.function(function).ok_or_else(not_found)?;
+    let mut candidates = overloads.iter().filter(|f| f.inputs.len() == args_len);
+    match (candidates.next(), candidates.next()) {
+        (Some(f), None) => Ok(f),
+        (Some(_), Some(_)) => Err(crate::Error::AbiParseError(format!(
+            "function {} is overloaded, use the full signature",
+            function
+        ))),
+        _ => Err(crate::Error::AbiParseError(format!(
+            "function {} expects a different number of args",
+            function
+        ))),
+    }
+}
+
+fn resolve(param: &Param) -> crate::Result<DynSolType> {
+    param
+        .resolve()
+        .map_err(|e| crate::Error::AbiParseError(e.to_string()))
+}
+
+// arrays and tuples are walked as json arrays, leaves are coerced from their string form
+pub fn json_to_value(ty: &DynSolType, value: &serde_json::Value) -> crate::Result<DynSolValue> {
+    let mismatch = || crate::Error::AbiParseError(format!("{} is not a valid {}", value, ty));
+
+    match (ty, value) {
+        (DynSolType::Array(inner), serde_json::Value::Array(items)) => Ok(DynSolValue::Array(
+            items
+                .iter()
+                .map(|v| json_to_value(inner, v))
+                .collect::<crate::Result<_>>()?,
+        )),
+        (DynSolType::FixedArray(inner, len), serde_json::Value::Array(items)) => {
+            if items.len() != *len {
+                return Err(mismatch());
+            }
+            Ok(DynSolValue::FixedArray(
+                items
+                    .iter()
+                    .map(|v| json_to_value(inner, v))
+                    .collect::<crate::Result<_>>()?,
+            ))
+        }
+        (DynSolType::Tuple(types), serde_json::Value::Array(items)) => {
+            if items.len() != types.len() {
+                return Err(mismatch());
+            }
+            Ok(DynSolValue::Tuple(
+                types
+                    .iter()
+                    .zip(items)
+                    .map(|(t, v)| json_to_value(t, v))
+                    .collect::<crate::Result<_>>()?,
+            ))
+        }
+        (_, serde_json::Value::String(s)) => ty.coerce_str(s).map_err(|_| mismatch()),
+        (_, serde_json::Value::Number(n)) => ty.coerce_str(&n.to_string()).map_err(|_| mismatch()),
+        (_, serde_json::Value::Bool(b)) => ty.coerce_str(&b.to_string()).map_err(|_| mismatch()),
+        _ => Err(mismatch()),
+    }
+}
+
+pub fn values_to_json(values: &[DynSolValue], params: &[Param]) -> serde_json::Value {
+    let named = !params.is_empty() && params.iter().all(|p| !p.name.is_empty());
+
+    if named {
+        let map = values
+            .iter()
+            .zip(params)
+            .map(|(v, p)| (p.name.clone(), value_to_json(v, &p.components)))
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::Value::Object(map)
+    } else {
+        let components = params.iter().map(|p| p.components.as_slice());
+        serde_json::Value::Array(
+            values
+                .iter()
+                .zip(components.chain(std::iter::repeat(&[][..])))
+                .map(|(v, c)| value_to_json(v, c))
+                .collect(),
+        )
+    }
+}
+
+// numbers are strings to keep the precision of 256 bit integers
+fn value_to_json(value: &DynSolValue, components: &[Param]) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        DynSolValue::Bool(b) => Value::Bool(*b),
+        DynSolValue::Int(i, _) => Value::String(i.to_string()),
+        DynSolValue::Uint(u, _) => Value::String(u.to_string()),
+        DynSolValue::FixedBytes(word, size) => {
+            Value::String(format!("0x{}", hex::encode(&word[..*size])))
+        }
+        DynSolValue::Address(a) => Value::String(a.to_checksum(None)),
+        DynSolValue::Function(f) => Value::String(format!("0x{}", hex::encode(f.as_slice()))),
+        DynSolValue::Bytes(b) => Value::String(format!("0x{}", hex::encode(b))),
+        DynSolValue::String(s) => Value::String(s.clone()),
+        DynSolValue::Array(items) | DynSolValue::FixedArray(items) => {
+            Value::Array(items.iter().map(|v| value_to_json(v, components)).collect())
+        }
+        DynSolValue::Tuple(items) => values_to_json(items, components),
+        #[allow(unreachable_patterns)]
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Transaction as _;
+
+    const ABI: &str = r#"[
+        {"type":"function","name":"transfer","stateMutability":"nonpayable",
+         "inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],
+         "outputs":[{"name":"","type":"bool"}]},
+        {"type":"function","name":"getReserves","stateMutability":"view","inputs":[],
+         "outputs":[{"name":"reserve0","type":"uint112"},{"name":"reserve1","type":"uint112"},{"name":"blockTimestampLast","type":"uint32"}]},
+        {"type":"error","name":"InsufficientBalance",
+         "inputs":[{"name":"available","type":"uint256"},{"name":"required","type":"uint256"}]}
+    ]"#;
+    const FROM: &str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+    const CONTRACT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+
+    #[test]
+    fn test_encode_transfer() {
+        let call = ContractCall::new(
+            FROM,
+            CONTRACT,
+            ABI,
+            "transfer",
+            serde_json::json!(["0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD", 1000]),
+        )
+        .unwrap();
+
+        let tx = call.build_transaction().unwrap();
+        let input = hex::encode(tx.input.input().unwrap());
+        assert_eq!(
+            input,
+            "a9059cbb0000000000000000000000003fc91a3afd70395cd496c647d5a6cc9d4b2b7fad00000000000000000000000000000000000000000000000000000000000003e8"
+        );
+
+        // full signature of a single function item
+        let single = r#"{"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"}"#;
+        let call2 = ContractCall::new(
+            FROM,
+            CONTRACT,
+            single,
+            "transfer(address,uint256)",
+            serde_json::json!(["0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD", "1000"]),
+        )
+        .unwrap();
+        assert_eq!(hex::encode(call2.calldata().unwrap()), input);
+    }
+
+    #[test]
+    fn test_decode_output() {
+        let call =
+            ContractCall::new(FROM, CONTRACT, ABI, "getReserves", serde_json::Value::Null).unwrap();
+        let data = hex::decode("000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000001e").unwrap();
+
+        let res = call.decode_output(&data).unwrap();
+        assert_eq!(
+            res,
+            serde_json::json!({"reserve0": "10", "reserve1": "20", "blockTimestampLast": "30"})
+        );
+
+        let revert = call.decode_revert(&hex::decode("cf47918100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002").unwrap());
+        assert_eq!(revert.reason, "InsufficientBalance");
+    }
+}
@@ -0,0 +1,195 @@
+// ens 域名解析, 支持 ensip-10 通配符以及 eip-3668 ccip-read
+use crate::eth::protocol::contract::{IENSRegistry, IENSResolver, OffchainLookup};
+use alloy::{
+    network::TransactionBuilder as _,
+    primitives::{self, Address, B256, keccak256},
+    rpc::types::TransactionRequest,
+    sol_types::{SolCall as _, SolValue as _},
+};
+
+// interface id of IExtendedResolver
+pub const EXTENDED_RESOLVER_INTERFACE: [u8; 4] = [0x90, 0x61, 0xb9, 0x23];
+// max offchain lookups followed for one call, as recommended by eip-3668
+pub const MAX_CCIP_LOOKUPS: usize = 4;
+
+// eip-137 namehash
+pub fn namehash(name: &str) -> B256 {
+    let mut node = B256::ZERO;
+    if name.is_empty() {
+        return node;
+    }
+
+    for label in name.rsplit('.') {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(node.as_slice());
+        bytes[32..].copy_from_slice(keccak256(label.as_bytes()).as_slice());
+        node = keccak256(bytes);
+    }
+    node
+}
+
+// dns wire format used by IExtendedResolver.resolve
+pub fn dns_encode(name: &str) -> crate::Result<Vec<u8>> {
+    let mut res = Vec::with_capacity(name.len() + 2);
+    for label in name.split('.') {
+        if label.is_empty() || label.len() > 255 {
+            return Err(crate::Error::Other(format!("invalid ens label {}", label)));
+        }
+        res.push(label.len() as u8);
+        res.extend_from_slice(label.as_bytes());
+    }
+    res.push(0);
+    Ok(res)
+}
+
+// reverse record of an address, `<hex address>.addr.reverse`
+pub fn reverse_name(address: Address) -> String {
+    format!("{}.addr.reverse", hex::encode(address))
+}
+
+pub fn resolver_tx(registry: Address, name: &str) -> TransactionRequest {
+    let call = IENSRegistry::resolverCall {
+        node: namehash(name),
+    };
+    TransactionRequest::default()
+        .with_to(registry)
+        .with_input(call.abi_encode())
+}
+
+pub fn addr_data(name: &str) -> Vec<u8> {
+    IENSResolver::addrCall {
+        node: namehash(name),
+    }
+    .abi_encode()
+}
+
+pub fn name_data(name: &str) -> Vec<u8> {
+    IENSResolver::nameCall {
+        node: namehash(name),
+    }
+    .abi_encode()
+}
+
+/// Request to the gateway of an `OffchainLookup` revert.
+#[derive(Debug, PartialEq)]
+pub enum CcipRequest {
+    Get(String),
+    Post(String, serde_json::Value),
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CcipResponse {
+    data: String,
+}
+
+impl CcipRequest {
+    // urls with `{data}` are requested by GET, the others by POST with a json body
+    pub fn new(url: &str, sender: Address, call_data: &[u8]) -> Self {
+        let sender = format!("0x{}", hex::encode(sender));
+        let data = format!("0x{}", hex::encode(call_data));
+
+        if url.contains("{data}") {
+            CcipRequest::Get(url.replace("{sender}", &sender).replace("{data}", &data))
+        } else {
+            CcipRequest::Post(
+                url.replace("{sender}", &sender),
+                serde_json::json!({ "data": data, "sender": sender }),
+            )
+        }
+    }
+
+    pub async fn send(&self) -> crate::Result<Vec<u8>> {
+        let timeout = Some(std::time::Duration::from_secs(15));
+        let res = match self {
+            CcipRequest::Get(url) => {
+                let client = wallet_transport::client::HttpClient::new(url, None, timeout)?;
+                wallet_transport::request_builder::ReqBuilder(client.client.get(&client.base_url))
+                    .send::<CcipResponse>()
+                    .await?
+            }
+            CcipRequest::Post(url, body) => {
+                let client = wallet_transport::client::HttpClient::new(url, None, timeout)?;
+                wallet_transport::request_builder::ReqBuilder(client.client.post(&client.base_url))
+                    .json(body)
+                    .send::<CcipResponse>()
+                    .await?
+            }
+        };
+
+        let data = res.data.strip_prefix("0x").unwrap_or(&res.data);
+        Ok(wallet_utils::hex_func::hex_decode(data)?)
+    }
+}
+
+// calldata of the callback with the gateway response, callback(bytes response, bytes extraData)
+pub fn callback_data(lookup: &OffchainLookup, response: Vec<u8>) -> Vec<u8> {
+    let mut data = lookup.callbackFunction.to_vec();
+    data.extend_from_slice(
+        &(primitives::Bytes::from(response), lookup.extraData.clone()).abi_encode_params(),
+    );
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::sol_types::SolError as _;
+
+    #[test]
+    fn test_namehash() {
+        assert_eq!(namehash(""), B256::ZERO);
+        assert_eq!(
+            namehash("eth").to_string(),
+            "0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+        );
+        assert_eq!(
+            namehash("foo.eth").to_string(),
+            "0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+        );
+    }
+
+    #[test]
+    fn test_dns_encode() {
+        let res = dns_encode("vitalik.eth").unwrap();
+        assert_eq!(hex::encode(res), "07766974616c696b0365746800");
+        assert!(dns_encode("a..eth").is_err());
+
+        let address = Address::repeat_byte(0xab);
+        assert_eq!(
+            reverse_name(address),
+            "abababababababababababababababababababab.addr.reverse"
+        );
+    }
+
+    #[test]
+    fn test_ccip_request() {
+        let sender = Address::repeat_byte(0x11);
+        let req = CcipRequest::new("https://gw.example/{sender}/{data}.json", sender, &[0xab]);
+        assert_eq!(
+            req,
+            CcipRequest::Get(
+                "https://gw.example/0x1111111111111111111111111111111111111111/0xab.json"
+                    .to_string()
+            )
+        );
+
+        let req = CcipRequest::new("https://gw.example/lookup", sender, &[0xab]);
+        assert!(matches!(req, CcipRequest::Post(_, body) if body["data"] == "0xab"));
+
+        let lookup = OffchainLookup {
+            sender,
+            urls: vec![],
+            callData: vec![0xab].into(),
+            callbackFunction: [0x12, 0x34, 0x56, 0x78].into(),
+            extraData: vec![0xcd].into(),
+        };
+        let encoded = lookup.abi_encode();
+        let decoded = OffchainLookup::abi_decode(&encoded, true).unwrap();
+        assert_eq!(decoded.sender, sender);
+
+        // selector, two offsets, then both length prefixed bytes
+        let data = callback_data(&decoded, vec![0xef]);
+        assert_eq!(&data[..4], &[0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(data.len(), 4 + 32 * 6);
+    }
+}
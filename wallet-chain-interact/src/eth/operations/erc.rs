@@ -1,15 +1,22 @@
 // 封装erc20相关的操作
 use crate::{
-    eth::protocol::contract::{allowanceCall, approveCall, depositCall, withdrawCall},
+    eth::protocol::contract::{
+        DOMAIN_SEPARATORCall, IPermit2, Permit, PermitBatch, PermitDetails, PermitSingle,
+        TokenSpenderPair, allowanceCall, approveCall, depositCall, noncesCall, permitCall,
+        withdrawCall,
+    },
     types,
 };
 use alloy::{
     network::TransactionBuilder as _,
-    primitives::{self, U256},
+    primitives::{
+        self, B256, U256,
+        aliases::{U48, U160},
+    },
     rpc::types::TransactionRequest,
-    sol_types::SolCall as _,
+    sol_types::{SolCall as _, SolStruct as _, eip712_domain},
 };
-use wallet_utils::address;
+use wallet_utils::{address, sign};
 
 pub struct Approve {
     pub spender: primitives::Address,
@@ -37,6 +44,13 @@ impl Approve {
     }
 }
 
+impl Approve {
+    // approve 0 to clear the outstanding allowance
+    pub fn revoke(from: &str, spender: &str, contract: &str) -> crate::Result<Self> {
+        Self::new(from, spender, U256::ZERO, contract)
+    }
+}
+
 impl types::Transaction<TransactionRequest> for Approve {
     fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
         let call = approveCall {
@@ -149,3 +163,386 @@ impl types::Transaction<TransactionRequest> for Allowance {
             .with_input(call.abi_encode()))
     }
 }
+
+// allowance of one token, used to inspect outstanding approvals
+#[derive(Debug)]
+pub struct TokenAllowance {
+    pub token: String,
+    pub spender: String,
+    pub amount: U256,
+    // expiration of the permit2 allowance, None for erc20 allowance
+    pub expiration: Option<u64>,
+}
+
+impl TokenAllowance {
+    // wallets approve U256::MAX or close to it
+    pub fn is_unlimited(&self) -> bool {
+        self.amount >= U256::MAX >> 1 || self.amount >= U256::from(U160::MAX)
+    }
+}
+
+// eip-2612 nonce of the owner
+pub fn permit_nonce_tx(owner: &str, contract: &str) -> crate::Result<TransactionRequest> {
+    let call = noncesCall {
+        owner: address::parse_eth_address(owner)?,
+    };
+
+    Ok(TransactionRequest::default()
+        .to(address::parse_eth_address(contract)?)
+        .with_input(call.abi_encode()))
+}
+
+// eip-2612 domain separator of the token
+pub fn domain_separator_tx(contract: &str) -> crate::Result<TransactionRequest> {
+    Ok(TransactionRequest::default()
+        .to(address::parse_eth_address(contract)?)
+        .with_input(DOMAIN_SEPARATORCall {}.abi_encode()))
+}
+
+// permit2 allowance of (owner, token, spender)
+pub fn permit2_allowance_tx(
+    owner: &str,
+    token: &str,
+    spender: &str,
+) -> crate::Result<TransactionRequest> {
+    let call = IPermit2::allowanceCall {
+        user: address::parse_eth_address(owner)?,
+        token: address::parse_eth_address(token)?,
+        spender: address::parse_eth_address(spender)?,
+    };
+
+    Ok(TransactionRequest::default()
+        .to(address::parse_eth_address(super::super::consts::PERMIT2)?)
+        .with_input(call.abi_encode()))
+}
+
+/// r,s,v of a typed data signature
+#[derive(Debug, Clone)]
+pub struct PermitSignature {
+    pub v: u8,
+    pub r: B256,
+    pub s: B256,
+}
+
+impl PermitSignature {
+    // sign the eip-712 digest, v is 27/28
+    pub fn sign(hash: B256, key: &types::ChainPrivateKey) -> crate::Result<Self> {
+        let signature = sign::sign_tron(&hash.to_string(), key, Some(27))?;
+        let bytes = wallet_utils::hex_func::hex_decode(&signature)?;
+
+        Ok(Self {
+            r: B256::from_slice(&bytes[..32]),
+            s: B256::from_slice(&bytes[32..64]),
+            v: bytes[64],
+        })
+    }
+
+    // 65 bytes r || s || v
+    pub fn to_bytes(&self) -> primitives::Bytes {
+        let mut bytes = Vec::with_capacity(65);
+        bytes.extend_from_slice(self.r.as_slice());
+        bytes.extend_from_slice(self.s.as_slice());
+        bytes.push(self.v);
+        bytes.into()
+    }
+}
+
+/// EIP-2612 permit, the owner signs off-chain and anyone can submit the approval.
+pub struct Erc20Permit {
+    pub owner: primitives::Address,
+    pub spender: primitives::Address,
+    pub value: U256,
+    pub nonce: U256,
+    pub deadline: U256,
+    // DOMAIN_SEPARATOR() of the token
+    pub domain_separator: B256,
+    pub contract: primitives::Address,
+    pub signature: Option<PermitSignature>,
+    // account that submits the permit, defaults to the owner
+    pub submitter: Option<primitives::Address>,
+}
+
+impl Erc20Permit {
+    pub fn new(
+        owner: &str,
+        spender: &str,
+        value: U256,
+        deadline: u64,
+        contract: &str,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            owner: address::parse_eth_address(owner)?,
+            spender: address::parse_eth_address(spender)?,
+            value,
+            nonce: U256::ZERO,
+            deadline: U256::from(deadline),
+            domain_separator: B256::ZERO,
+            contract: address::parse_eth_address(contract)?,
+            signature: None,
+            submitter: None,
+        })
+    }
+
+    // nonce and domain separator are read from the token by eth_call
+    pub fn with_domain(mut self, nonce: U256, domain_separator: B256) -> Self {
+        self.nonce = nonce;
+        self.domain_separator = domain_separator;
+        self
+    }
+
+    pub fn with_submitter(mut self, submitter: &str) -> crate::Result<Self> {
+        self.submitter = Some(address::parse_eth_address(submitter)?);
+        Ok(self)
+    }
+
+    // keccak256(0x1901 || domain separator || hashStruct(Permit))
+    pub fn signing_hash(&self) -> B256 {
+        let permit = Permit {
+            owner: self.owner,
+            spender: self.spender,
+            value: self.value,
+            nonce: self.nonce,
+            deadline: self.deadline,
+        };
+
+        let mut digest = Vec::with_capacity(66);
+        digest.extend_from_slice(&[0x19, 0x01]);
+        digest.extend_from_slice(self.domain_separator.as_slice());
+        digest.extend_from_slice(permit.eip712_hash_struct().as_slice());
+        primitives::keccak256(digest)
+    }
+
+    pub fn sign(&mut self, key: &types::ChainPrivateKey) -> crate::Result<PermitSignature> {
+        if self.domain_separator.is_zero() {
+            return Err(crate::Error::Other(
+                "permit domain separator not set".to_string(),
+            ));
+        }
+        let signature = PermitSignature::sign(self.signing_hash(), key)?;
+        self.signature = Some(signature.clone());
+        Ok(signature)
+    }
+}
+
+impl types::Transaction<TransactionRequest> for Erc20Permit {
+    fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
+        let signature = self
+            .signature
+            .as_ref()
+            .ok_or(crate::Error::Other("permit not signed".to_string()))?;
+
+        let call = permitCall {
+            owner: self.owner,
+            spender: self.spender,
+            value: self.value,
+            deadline: self.deadline,
+            v: signature.v,
+            r: signature.r,
+            s: signature.s,
+        };
+
+        Ok(TransactionRequest::default()
+            .from(self.submitter.unwrap_or(self.owner))
+            .to(self.contract)
+            .value(U256::ZERO)
+            .with_input(call.abi_encode()))
+    }
+}
+
+/// One token allowance inside a permit2 signature.
+pub struct Permit2Detail {
+    pub token: primitives::Address,
+    pub amount: U160,
+    pub expiration: U48,
+    // nonce from IPermit2.allowance
+    pub nonce: U48,
+}
+
+impl Permit2Detail {
+    pub fn new(token: &str, amount: U256, expiration: u64, nonce: u64) -> crate::Result<Self> {
+        let amount = if amount > U256::from(U160::MAX) {
+            U160::MAX
+        } else {
+            U160::from(amount)
+        };
+        // expiration and nonce are uint48 in permit2
+        let expiration = U48::try_from(expiration).map_err(|_| {
+            crate::Error::Other(format!("permit2 expiration {} overflow", expiration))
+        })?;
+        let nonce = U48::try_from(nonce)
+            .map_err(|_| crate::Error::Other(format!("permit2 nonce {} overflow", nonce)))?;
+
+        Ok(Self {
+            token: address::parse_eth_address(token)?,
+            amount,
+            expiration,
+            nonce,
+        })
+    }
+
+    fn details(&self) -> PermitDetails {
+        PermitDetails {
+            token: self.token,
+            amount: self.amount,
+            expiration: self.expiration,
+            nonce: self.nonce,
+        }
+    }
+}
+
+/// Uniswap Permit2 `PermitSingle` / `PermitBatch` typed data,
+/// the signature is handed to the spender which submits it.
+pub struct Permit2Opt {
+    pub chain_id: u64,
+    pub spender: primitives::Address,
+    pub sig_deadline: U256,
+    pub details: Vec<Permit2Detail>,
+}
+
+impl Permit2Opt {
+    pub fn new(chain_id: u64, spender: &str, sig_deadline: u64) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            spender: address::parse_eth_address(spender)?,
+            sig_deadline: U256::from(sig_deadline),
+            details: vec![],
+        })
+    }
+
+    pub fn with_detail(mut self, detail: Permit2Detail) -> Self {
+        self.details.push(detail);
+        self
+    }
+
+    // single detail is signed as PermitSingle, more as PermitBatch
+    pub fn signing_hash(&self) -> crate::Result<B256> {
+        let domain = eip712_domain! {
+            name: "Permit2",
+            chain_id: self.chain_id,
+            verifying_contract: address::parse_eth_address(super::super::consts::PERMIT2)?,
+        };
+
+        let hash = match self.details.as_slice() {
+            [] => return Err(crate::Error::Other("permit2 details is empty".to_string())),
+            [detail] => PermitSingle {
+                details: detail.details(),
+                spender: self.spender,
+                sigDeadline: self.sig_deadline,
+            }
+            .eip712_signing_hash(&domain),
+            details => PermitBatch {
+                details: details.iter().map(|d| d.details()).collect(),
+                spender: self.spender,
+                sigDeadline: self.sig_deadline,
+            }
+            .eip712_signing_hash(&domain),
+        };
+        Ok(hash)
+    }
+
+    pub fn sign(&self, key: &types::ChainPrivateKey) -> crate::Result<PermitSignature> {
+        PermitSignature::sign(self.signing_hash()?, key)
+    }
+}
+
+/// Revoke permit2 allowances, `lockdown` sets the amount of each pair to 0.
+pub struct Permit2Revoke {
+    pub from: primitives::Address,
+    pub approvals: Vec<(primitives::Address, primitives::Address)>,
+}
+
+impl Permit2Revoke {
+    // pairs of (token, spender)
+    pub fn new(from: &str, approvals: Vec<(String, String)>) -> crate::Result<Self> {
+        let approvals = approvals
+            .iter()
+            .map(|(token, spender)| {
+                Ok((
+                    address::parse_eth_address(token)?,
+                    address::parse_eth_address(spender)?,
+                ))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            from: address::parse_eth_address(from)?,
+            approvals,
+        })
+    }
+}
+
+impl types::Transaction<TransactionRequest> for Permit2Revoke {
+    fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
+        let call = IPermit2::lockdownCall {
+            approvals: self
+                .approvals
+                .iter()
+                .map(|(token, spender)| TokenSpenderPair {
+                    token: *token,
+                    spender: *spender,
+                })
+                .collect(),
+        };
+
+        Ok(TransactionRequest::default()
+            .from(self.from)
+            .to(address::parse_eth_address(super::super::consts::PERMIT2)?)
+            .value(U256::ZERO)
+            .with_input(call.abi_encode()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::Signature;
+
+    const KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    const OWNER: &str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+    const TOKEN: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+    const SPENDER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+
+    fn recover(hash: B256, sig: &PermitSignature) -> primitives::Address {
+        let signature = Signature::try_from(sig.to_bytes().as_ref()).unwrap();
+        signature.recover_address_from_prehash(&hash).unwrap()
+    }
+
+    #[test]
+    fn test_erc20_permit_sign() {
+        let mut permit = Erc20Permit::new(OWNER, SPENDER, U256::from(100), 1_900_000_000, TOKEN)
+            .unwrap()
+            .with_domain(U256::from(3), B256::repeat_byte(0xaa));
+
+        let sig = permit.sign(&KEY.into()).unwrap();
+        assert!(sig.v == 27 || sig.v == 28);
+        assert_eq!(recover(permit.signing_hash(), &sig), permit.owner);
+    }
+
+    #[test]
+    fn test_permit2_sign() {
+        let single = Permit2Opt::new(1, SPENDER, 1_900_000_000)
+            .unwrap()
+            .with_detail(Permit2Detail::new(TOKEN, U256::MAX, 1_900_000_000, 0).unwrap());
+        let batch = Permit2Opt::new(1, SPENDER, 1_900_000_000)
+            .unwrap()
+            .with_detail(Permit2Detail::new(TOKEN, U256::MAX, 1_900_000_000, 0).unwrap())
+            .with_detail(Permit2Detail::new(OWNER, U256::from(1), 1_900_000_000, 1).unwrap());
+
+        let single_hash = single.signing_hash().unwrap();
+        assert_ne!(single_hash, batch.signing_hash().unwrap());
+
+        let sig = single.sign(&KEY.into()).unwrap();
+        assert_eq!(
+            recover(single_hash, &sig),
+            address::parse_eth_address(OWNER).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_permit2_detail_overflow() {
+        let max = (1u64 << 48) - 1;
+        assert!(Permit2Detail::new(TOKEN, U256::MAX, max, max).is_ok());
+        assert!(Permit2Detail::new(TOKEN, U256::MAX, max + 1, 0).is_err());
+        assert!(Permit2Detail::new(TOKEN, U256::MAX, 1_900_000_000, u64::MAX).is_err());
+    }
+}
@@ -3,7 +3,11 @@ pub mod multisig;
 pub use multisig::*;
 pub mod transfer;
 pub use transfer::*;
+pub mod contract_call;
+pub use contract_call::ContractCall;
+pub mod ens;
 pub mod erc;
+pub mod nft;
 use wallet_utils::address;
 
 pub struct EthereumBaseTransaction {
@@ -5,6 +5,7 @@ use crate::{
 use alloy::{
     network::TransactionBuilder, primitives, rpc::types::TransactionRequest, sol_types::SolCall,
 };
+use wallet_types::chain::evm::{EvmChainInfo, SAFE_CANONICAL};
 use wallet_utils::{address, unit};
 
 pub struct MultisigAccountOpt {
@@ -12,6 +13,8 @@ pub struct MultisigAccountOpt {
     pub threshold: i32,
     pub owners: Vec<primitives::Address>,
     pub nonce: Option<primitives::U256>,
+    pub factory: primitives::Address,
+    pub singleton: primitives::Address,
 }
 
 impl MultisigAccountOpt {
@@ -22,9 +25,20 @@ impl MultisigAccountOpt {
             threshold,
             owners: vec![],
             nonce: None,
+            factory: address::parse_eth_address(SAFE_CANONICAL.proxy_factory)?,
+            singleton: address::parse_eth_address(SAFE_CANONICAL.singleton)?,
         })
     }
 
+    // use the safe contracts deployed on the chain, keep the default when the chain has none in the registry
+    pub fn with_safe_deployment(mut self, chain: &EvmChainInfo) -> Result<Self, crate::Error> {
+        if let Some(safe) = chain.safe {
+            self.factory = address::parse_eth_address(safe.proxy_factory)?;
+            self.singleton = address::parse_eth_address(safe.singleton)?;
+        }
+        Ok(self)
+    }
+
     pub fn set_nonce(mut self, nonce: &str) -> Result<Self, crate::Error> {
         let nonce = unit::u256_from_str(nonce)?;
         self.nonce = Some(nonce);
@@ -60,9 +74,6 @@ impl MultisigAccountOpt {
 
 impl types::Transaction<TransactionRequest> for MultisigAccountOpt {
     fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
-        let factory_addr = address::parse_eth_address(super::MULTISIG_FACTORY)?;
-        let safe_addr = address::parse_eth_address(super::SAFE)?;
-
         let default_address = primitives::Address::default();
         let set_up = setupCall {
             _owners: self.owners.clone(),
@@ -76,14 +87,14 @@ impl types::Transaction<TransactionRequest> for MultisigAccountOpt {
         };
 
         let create = createProxyWithNonceCall {
-            _singleton: safe_addr,
+            _singleton: self.singleton,
             initializer: set_up.abi_encode().into(),
             saltNonce: self.get_nonce()?,
         };
 
         Ok(TransactionRequest::default()
             .with_from(self.from)
-            .with_to(factory_addr)
+            .with_to(self.factory)
             .with_value(primitives::U256::ZERO)
             .with_input(create.abi_encode()))
     }
@@ -2,6 +2,9 @@ pub mod account;
 pub use account::*;
 pub mod transaction;
 pub use transaction::*;
-
-const MULTISIG_FACTORY: &str = "0xa6B71E26C5e0845f74c812102Ca7114b6a896AB2";
-const SAFE: &str = "0x3E5c63644E683549055b9Be8653de26E0B4CD36E";
+pub mod owner;
+pub use owner::*;
+pub mod signature;
+pub use signature::*;
+pub mod simulation;
+pub use simulation::*;
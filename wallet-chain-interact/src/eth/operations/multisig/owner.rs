@@ -0,0 +1,303 @@
+// safe 的 owner 管理、multisend 批量交易以及链上 approveHash
+use crate::eth::protocol::contract;
+use crate::types;
+use alloy::{
+    network::TransactionBuilder as _,
+    primitives::{self, Address, B256, U256},
+    rpc::types::TransactionRequest,
+    sol_types::SolCall as _,
+};
+use wallet_utils::address;
+
+// head of the owners linked list in the safe contract
+pub const SENTINEL_OWNERS: Address = Address::with_last_byte(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SafeOperation {
+    Call = 0,
+    DelegateCall = 1,
+}
+
+/// Call executed by the safe through `execTransaction`.
+#[derive(Debug, Clone)]
+pub struct SafeCall {
+    pub to: Address,
+    pub value: U256,
+    pub data: primitives::Bytes,
+    pub operation: SafeOperation,
+}
+
+impl SafeCall {
+    pub fn new(to: &str, value: U256, data: Vec<u8>) -> crate::Result<Self> {
+        Ok(Self {
+            to: address::parse_eth_address(to)?,
+            value,
+            data: data.into(),
+            operation: SafeOperation::Call,
+        })
+    }
+
+    // owner management calls are sent by the safe to itself
+    fn to_self(safe: Address, data: Vec<u8>) -> Self {
+        Self {
+            to: safe,
+            value: U256::ZERO,
+            data: data.into(),
+            operation: SafeOperation::Call,
+        }
+    }
+}
+
+/// Owner changes of a safe, each one becomes a proposal signed by the current owners.
+pub struct SafeOwnerManager {
+    pub safe: Address,
+    // current owners in the order returned by getOwners
+    pub owners: Vec<Address>,
+    pub threshold: u64,
+}
+
+impl SafeOwnerManager {
+    pub fn new(safe: &str, owners: Vec<Address>, threshold: u64) -> crate::Result<Self> {
+        Ok(Self {
+            safe: address::parse_eth_address(safe)?,
+            owners,
+            threshold,
+        })
+    }
+
+    // the safe keeps owners in a linked list, removing or swapping needs the previous one
+    pub fn prev_owner(&self, owner: Address) -> crate::Result<Address> {
+        let index = self
+            .owners
+            .iter()
+            .position(|o| *o == owner)
+            .ok_or(crate::Error::Other(format!("{} is not an owner", owner)))?;
+
+        Ok(if index == 0 {
+            SENTINEL_OWNERS
+        } else {
+            self.owners[index - 1]
+        })
+    }
+
+    fn check_threshold(&self, threshold: u64, owners: usize) -> crate::Result<()> {
+        if threshold == 0 || threshold as usize > owners {
+            return Err(crate::Error::Other(format!(
+                "threshold {} out of range 1..={}",
+                threshold, owners
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn add_owner(&self, owner: &str, threshold: u64) -> crate::Result<SafeCall> {
+        let owner = address::parse_eth_address(owner)?;
+        if self.owners.contains(&owner) {
+            return Err(crate::Error::Other(format!(
+                "{} is already an owner",
+                owner
+            )));
+        }
+        self.check_threshold(threshold, self.owners.len() + 1)?;
+
+        let call = contract::addOwnerWithThresholdCall {
+            owner,
+            _threshold: U256::from(threshold),
+        };
+        Ok(SafeCall::to_self(self.safe, call.abi_encode()))
+    }
+
+    pub fn remove_owner(&self, owner: &str, threshold: u64) -> crate::Result<SafeCall> {
+        let owner = address::parse_eth_address(owner)?;
+        let prev_owner = self.prev_owner(owner)?;
+        self.check_threshold(threshold, self.owners.len() - 1)?;
+
+        let call = contract::removeOwnerCall {
+            prevOwner: prev_owner,
+            owner,
+            _threshold: U256::from(threshold),
+        };
+        Ok(SafeCall::to_self(self.safe, call.abi_encode()))
+    }
+
+    pub fn swap_owner(&self, old_owner: &str, new_owner: &str) -> crate::Result<SafeCall> {
+        let old_owner = address::parse_eth_address(old_owner)?;
+        let new_owner = address::parse_eth_address(new_owner)?;
+        if self.owners.contains(&new_owner) {
+            return Err(crate::Error::Other(format!(
+                "{} is already an owner",
+                new_owner
+            )));
+        }
+        let prev_owner = self.prev_owner(old_owner)?;
+
+        let call = contract::swapOwnerCall {
+            prevOwner: prev_owner,
+            oldOwner: old_owner,
+            newOwner: new_owner,
+        };
+        Ok(SafeCall::to_self(self.safe, call.abi_encode()))
+    }
+
+    pub fn change_threshold(&self, threshold: u64) -> crate::Result<SafeCall> {
+        self.check_threshold(threshold, self.owners.len())?;
+
+        let call = contract::changeThresholdCall {
+            _threshold: U256::from(threshold),
+        };
+        Ok(SafeCall::to_self(self.safe, call.abi_encode()))
+    }
+}
+
+/// Batch of calls delegatecalled to the MultiSendCallOnly library.
+pub struct MultiSend {
+    pub multi_send: Address,
+    pub calls: Vec<SafeCall>,
+}
+
+impl MultiSend {
+    pub fn new(multi_send: &str) -> crate::Result<Self> {
+        Ok(Self {
+            multi_send: address::parse_eth_address(multi_send)?,
+            calls: vec![],
+        })
+    }
+
+    pub fn push(mut self, call: SafeCall) -> Self {
+        self.calls.push(call);
+        self
+    }
+
+    // operation(1) | to(20) | value(32) | data length(32) | data, packed
+    pub fn encode_transactions(&self) -> crate::Result<Vec<u8>> {
+        let mut res = vec![];
+        for call in self.calls.iter() {
+            if call.operation != SafeOperation::Call {
+                return Err(crate::Error::Other(
+                    "multisend call only does not support delegatecall".to_string(),
+                ));
+            }
+            res.push(call.operation as u8);
+            res.extend_from_slice(call.to.as_slice());
+            res.extend_from_slice(&call.value.to_be_bytes::<32>());
+            res.extend_from_slice(&U256::from(call.data.len()).to_be_bytes::<32>());
+            res.extend_from_slice(&call.data);
+        }
+        Ok(res)
+    }
+
+    pub fn build(&self) -> crate::Result<SafeCall> {
+        if self.calls.is_empty() {
+            return Err(crate::Error::Other("multisend is empty".to_string()));
+        }
+
+        let call = contract::multiSendCall {
+            transactions: self.encode_transactions()?.into(),
+        };
+        Ok(SafeCall {
+            to: self.multi_send,
+            value: U256::ZERO,
+            data: call.abi_encode().into(),
+            operation: SafeOperation::DelegateCall,
+        })
+    }
+}
+
+/// On-chain approval of a safe tx hash by an owner, replaces its off-chain signature.
+pub struct ApproveHashOpt {
+    pub from: Address,
+    pub safe: Address,
+    pub hash: B256,
+}
+
+impl ApproveHashOpt {
+    pub fn new(from: &str, safe: &str, hash: &str) -> crate::Result<Self> {
+        let hash = hash
+            .parse::<B256>()
+            .map_err(|e| crate::Error::HexError(e.to_string()))?;
+
+        Ok(Self {
+            from: address::parse_eth_address(from)?,
+            safe: address::parse_eth_address(safe)?,
+            hash,
+        })
+    }
+}
+
+impl types::Transaction<TransactionRequest> for ApproveHashOpt {
+    fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
+        let call = contract::approveHashCall {
+            hashToApprove: self.hash,
+        };
+
+        Ok(TransactionRequest::default()
+            .with_from(self.from)
+            .with_to(self.safe)
+            .with_value(U256::ZERO)
+            .with_input(call.abi_encode()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAFE: &str = "0x7d3cf8a8d1e9b0b0d1c1f2a3d4e5f6a7b8c9d0e1";
+
+    fn owners() -> Vec<Address> {
+        vec![Address::repeat_byte(0x11), Address::repeat_byte(0x22)]
+    }
+
+    #[test]
+    fn test_owner_calls() {
+        let manager = SafeOwnerManager::new(SAFE, owners(), 1).unwrap();
+
+        assert_eq!(
+            manager.prev_owner(Address::repeat_byte(0x11)).unwrap(),
+            SENTINEL_OWNERS
+        );
+        assert_eq!(
+            manager.prev_owner(Address::repeat_byte(0x22)).unwrap(),
+            Address::repeat_byte(0x11)
+        );
+
+        let call = manager
+            .remove_owner(&Address::repeat_byte(0x22).to_string(), 1)
+            .unwrap();
+        let decoded = contract::removeOwnerCall::abi_decode(&call.data, true).unwrap();
+        assert_eq!(decoded.prevOwner, Address::repeat_byte(0x11));
+        assert_eq!(call.to, manager.safe);
+
+        // threshold can not exceed the owners left
+        assert!(
+            manager
+                .remove_owner(&Address::repeat_byte(0x22).to_string(), 2)
+                .is_err()
+        );
+        assert!(
+            manager
+                .add_owner(&Address::repeat_byte(0x11).to_string(), 1)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_multi_send() {
+        let to = Address::repeat_byte(0x33);
+        let call = SafeCall::new(&to.to_string(), U256::from(1), vec![0xaa, 0xbb]).unwrap();
+
+        let multi = MultiSend::new("0x40A2aCCbd92BCA938b02010E17A5b8929b49130D")
+            .unwrap()
+            .push(call.clone())
+            .push(call);
+        let packed = multi.encode_transactions().unwrap();
+        // 1 + 20 + 32 + 32 + 2 per call
+        assert_eq!(packed.len(), 87 * 2);
+        assert_eq!(packed[0], 0);
+        assert_eq!(&packed[1..21], to.as_slice());
+
+        let batch = multi.build().unwrap();
+        assert_eq!(batch.operation, SafeOperation::DelegateCall);
+    }
+}
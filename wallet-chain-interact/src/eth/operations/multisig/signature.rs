@@ -0,0 +1,315 @@
+// safe 签名的收集、校验与排序
+use crate::eth::protocol::contract;
+use alloy::primitives::{Address, B256, Signature, U256};
+use alloy::sol_types::SolCall as _;
+use wallet_utils::address;
+
+// legacy isValidSignature(bytes,bytes) magic value, checked by safe v1.3.0
+pub const LEGACY_EIP1271_MAGIC_VALUE: [u8; 4] = [0x20, 0xc1, 0x3b, 0x0b];
+
+/// One owner confirmation of a safe transaction.
+#[derive(Debug, Clone)]
+pub enum SafeSignature {
+    // 65 bytes r,s,v, v is 27/28 or 31/32 for eth_sign
+    Ecdsa { owner: Address, signature: Vec<u8> },
+    // confirmed on chain by approveHash, or the executor itself
+    ApprovedHash { owner: Address },
+    // eip-1271 signature of a contract owner
+    Contract { owner: Address, signature: Vec<u8> },
+}
+
+impl SafeSignature {
+    // the owner is recovered from the signature of the safe tx hash
+    pub fn from_ecdsa(safe_tx_hash: B256, signature: &str) -> crate::Result<Self> {
+        let bytes =
+            wallet_utils::hex_func::hex_decode(signature.strip_prefix("0x").unwrap_or(signature))?;
+        if bytes.len() != 65 {
+            return Err(crate::Error::SignError(format!(
+                "invalid signature length {}",
+                bytes.len()
+            )));
+        }
+
+        let owner = recover_owner(safe_tx_hash, &bytes)?;
+        Ok(Self::Ecdsa {
+            owner,
+            signature: bytes,
+        })
+    }
+
+    pub fn approved_hash(owner: &str) -> crate::Result<Self> {
+        Ok(Self::ApprovedHash {
+            owner: address::parse_eth_address(owner)?,
+        })
+    }
+
+    pub fn contract(owner: &str, signature: Vec<u8>) -> crate::Result<Self> {
+        Ok(Self::Contract {
+            owner: address::parse_eth_address(owner)?,
+            signature,
+        })
+    }
+
+    pub fn owner(&self) -> Address {
+        match self {
+            SafeSignature::Ecdsa { owner, .. }
+            | SafeSignature::ApprovedHash { owner }
+            | SafeSignature::Contract { owner, .. } => *owner,
+        }
+    }
+}
+
+fn recover_owner(hash: B256, bytes: &[u8]) -> crate::Result<Address> {
+    let v = bytes[64];
+    let mut raw = bytes.to_vec();
+
+    // eth_sign signatures have v + 4 and sign the eip-191 prefixed hash
+    let eth_sign = v > 30;
+    if eth_sign {
+        raw[64] = v - 4;
+    }
+
+    let signature =
+        Signature::try_from(raw.as_slice()).map_err(|e| crate::Error::SignError(e.to_string()))?;
+    let recovered = if eth_sign {
+        signature.recover_address_from_msg(hash.as_slice())
+    } else {
+        signature.recover_address_from_prehash(&hash)
+    };
+    recovered.map_err(|e| crate::Error::SignError(e.to_string()))
+}
+
+/// Signatures collected for one safe tx hash, validated against the owners
+/// and encoded in the order `execTransaction` expects.
+#[derive(Debug)]
+pub struct SafeSignatures {
+    pub safe_tx_hash: B256,
+    pub signatures: Vec<SafeSignature>,
+    // getTransactionHash call data of the proposal, needed to check contract owners on safe v1.3.0
+    pub hash_input: Option<Vec<u8>>,
+}
+
+impl SafeSignatures {
+    pub fn new(safe_tx_hash: &str) -> crate::Result<Self> {
+        let safe_tx_hash = safe_tx_hash
+            .parse::<B256>()
+            .map_err(|e| crate::Error::HexError(e.to_string()))?;
+
+        Ok(Self {
+            safe_tx_hash,
+            signatures: vec![],
+            hash_input: None,
+        })
+    }
+
+    // the input data of the multisig payload
+    pub fn with_hash_input(mut self, input_data: &str) -> crate::Result<Self> {
+        let bytes = hex::decode(input_data.trim_start_matches("0x"))
+            .map_err(|e| crate::Error::HexError(e.to_string()))?;
+        self.hash_input = Some(bytes);
+        Ok(self)
+    }
+
+    // encodeTransactionData call with the same parameters as the getTransactionHash input
+    pub fn encode_tx_data_call(&self) -> crate::Result<Option<Vec<u8>>> {
+        let Some(input) = self.hash_input.as_ref() else {
+            return Ok(None);
+        };
+        let call = contract::getTransactionHashCall::abi_decode(input, true)
+            .map_err(|e| crate::Error::HexError(e.to_string()))?;
+
+        let call = contract::encodeTransactionDataCall {
+            to: call.to,
+            value: call.value,
+            data: call.data,
+            operation: call.operation,
+            safeTxGas: call.safeTxGas,
+            baseGas: call.baseGas,
+            gasPrice: call.gasPrice,
+            gasToken: call.gasToken,
+            refundReceiver: call.refundReceiver,
+            _nonce: call._nonce,
+        };
+        Ok(Some(call.abi_encode()))
+    }
+
+    // the same owner can confirm only once
+    pub fn add(&mut self, signature: SafeSignature) -> crate::Result<()> {
+        let owner = signature.owner();
+        if self.signatures.iter().any(|s| s.owner() == owner) {
+            return Err(crate::Error::SignError(format!(
+                "duplicate signature of {}",
+                owner
+            )));
+        }
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    pub fn add_ecdsa(&mut self, signature: &str) -> crate::Result<Address> {
+        let signature = SafeSignature::from_ecdsa(self.safe_tx_hash, signature)?;
+        let owner = signature.owner();
+        self.add(signature)?;
+        Ok(owner)
+    }
+
+    // every signer has to be an owner and the count has to reach the threshold
+    pub fn validate(&self, owners: &[Address], threshold: u64) -> crate::Result<()> {
+        for signature in self.signatures.iter() {
+            if !owners.contains(&signature.owner()) {
+                return Err(crate::Error::SignError(format!(
+                    "{} is not an owner of the safe",
+                    signature.owner()
+                )));
+            }
+        }
+
+        if (self.signatures.len() as u64) < threshold {
+            return Err(crate::Error::SignError(format!(
+                "signatures {} below threshold {}",
+                self.signatures.len(),
+                threshold
+            )));
+        }
+        Ok(())
+    }
+
+    // static part sorted by owner ascending, contract signatures appended as dynamic data
+    pub fn encode(&self) -> Vec<u8> {
+        let mut signatures = self.signatures.iter().collect::<Vec<_>>();
+        signatures.sort_by_key(|s| s.owner());
+
+        let mut head = Vec::with_capacity(signatures.len() * 65);
+        let mut dynamic = vec![];
+        let dynamic_offset = signatures.len() * 65;
+
+        for signature in signatures {
+            match signature {
+                SafeSignature::Ecdsa { signature, .. } => head.extend_from_slice(signature),
+                SafeSignature::ApprovedHash { owner } => {
+                    head.extend_from_slice(owner.into_word().as_slice());
+                    head.extend_from_slice(&[0u8; 32]);
+                    head.push(1);
+                }
+                SafeSignature::Contract { owner, signature } => {
+                    let offset = U256::from(dynamic_offset + dynamic.len());
+                    head.extend_from_slice(owner.into_word().as_slice());
+                    head.extend_from_slice(&offset.to_be_bytes::<32>());
+                    head.push(0);
+
+                    dynamic.extend_from_slice(&U256::from(signature.len()).to_be_bytes::<32>());
+                    dynamic.extend_from_slice(signature);
+                }
+            }
+        }
+
+        head.extend_from_slice(&dynamic);
+        head
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.encode()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ChainPrivateKey;
+
+    const KEY1: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    const KEY2: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+
+    fn sign(hash: B256, key: &str) -> String {
+        let key = ChainPrivateKey::from(key);
+        wallet_utils::sign::sign_tron(&hash.to_string(), &key, Some(27)).unwrap()
+    }
+
+    #[test]
+    fn test_collect_and_encode() {
+        let hash = B256::repeat_byte(0x42);
+        let mut signatures = SafeSignatures::new(&hash.to_string()).unwrap();
+
+        let owner1 = signatures.add_ecdsa(&sign(hash, KEY1)).unwrap();
+        let owner2 = signatures.add_ecdsa(&sign(hash, KEY2)).unwrap();
+        assert_eq!(
+            owner1,
+            address::parse_eth_address("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23").unwrap()
+        );
+        // private key 1
+        assert_eq!(
+            owner2,
+            address::parse_eth_address("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf").unwrap()
+        );
+
+        // duplicates and non owners are rejected
+        assert!(signatures.add_ecdsa(&sign(hash, KEY1)).is_err());
+        assert!(signatures.validate(&[owner1], 1).is_err());
+        assert!(signatures.validate(&[owner1, owner2], 3).is_err());
+        signatures.validate(&[owner1, owner2], 2).unwrap();
+
+        // sorted by owner, 0x2c.. before 0x7e..
+        let encoded = signatures.encode();
+        assert_eq!(encoded.len(), 130);
+        assert_eq!(recover_owner(hash, &encoded[..65]).unwrap(), owner1);
+    }
+
+    #[test]
+    fn test_contract_signature_layout() {
+        let hash = B256::repeat_byte(0x42);
+        let mut signatures = SafeSignatures::new(&hash.to_string()).unwrap();
+
+        let contract_owner = Address::repeat_byte(0xff);
+        let approved = Address::repeat_byte(0x01);
+        signatures
+            .add(SafeSignature::Contract {
+                owner: contract_owner,
+                signature: vec![0xab; 3],
+            })
+            .unwrap();
+        signatures
+            .add(SafeSignature::ApprovedHash { owner: approved })
+            .unwrap();
+
+        let encoded = signatures.encode();
+        // approved hash first: r = owner, s = 0, v = 1
+        assert_eq!(&encoded[12..32], approved.as_slice());
+        assert_eq!(encoded[64], 1);
+        // contract signature points to the dynamic part after the two static slots
+        assert_eq!(&encoded[65 + 12..65 + 32], contract_owner.as_slice());
+        assert_eq!(
+            U256::from_be_slice(&encoded[65 + 32..65 + 64]),
+            U256::from(130)
+        );
+        assert_eq!(encoded[129], 0);
+        assert_eq!(U256::from_be_slice(&encoded[130..162]), U256::from(3));
+        assert_eq!(&encoded[162..], &[0xab; 3]);
+    }
+
+    #[test]
+    fn test_encode_tx_data_call() {
+        let hash = B256::repeat_byte(0x42);
+        let signatures = SafeSignatures::new(&hash.to_string()).unwrap();
+        assert!(signatures.encode_tx_data_call().unwrap().is_none());
+
+        let hash_call = contract::getTransactionHashCall {
+            to: Address::repeat_byte(0x11),
+            value: U256::from(5),
+            data: vec![0xde, 0xad].into(),
+            operation: 0,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            _nonce: U256::from(7),
+        };
+        let input = format!("0x{}", hex::encode(hash_call.abi_encode()));
+        let signatures = signatures.with_hash_input(&input).unwrap();
+
+        let call = signatures.encode_tx_data_call().unwrap().unwrap();
+        assert_eq!(call[..4], contract::encodeTransactionDataCall::SELECTOR);
+        // same parameters, only the selector differs
+        assert_eq!(call[4..], hash_call.abi_encode()[4..]);
+    }
+}
@@ -0,0 +1,297 @@
+// safe 交易执行前的模拟
+use super::{MultisigPayloadOpt, SafeSignature, SafeSignatures};
+use crate::eth::{ContractRevert, RevertKind, protocol::contract};
+use alloy::{
+    network::TransactionBuilder as _,
+    primitives::{self, Address, B256, U256},
+    rpc::types::TransactionRequest,
+    sol_types::SolCall as _,
+};
+use std::str::FromStr as _;
+use wallet_utils::address;
+
+// storage slot of the threshold in safe v1.3.0 (singleton, modules, owners, ownerCount, threshold)
+pub const SAFE_THRESHOLD_SLOT: u8 = 4;
+
+// safe error codes, https://github.com/safe-global/safe-smart-account/blob/main/docs/error_codes.md
+pub fn safe_error_message(code: &str) -> Option<&'static str> {
+    let message = match code {
+        "GS000" => "Could not finish initialization",
+        "GS001" => "Threshold needs to be defined",
+        "GS010" => "Not enough gas to execute Safe transaction",
+        "GS011" => "Could not pay gas costs with ether",
+        "GS012" => "Could not pay gas costs with token",
+        "GS013" => "Safe transaction failed when gasPrice and safeTxGas were 0",
+        "GS020" => "Signatures data too short",
+        "GS021" => "Invalid contract signature location: inside static part",
+        "GS022" => "Invalid contract signature location: length not present",
+        "GS023" => "Invalid contract signature location: data not complete",
+        "GS024" => "Invalid contract signature provided",
+        "GS025" => "Hash has not been approved",
+        "GS026" => "Invalid owner provided",
+        "GS030" => "Only owners can approve a hash",
+        "GS031" => "Method can only be called from this contract",
+        "GS100" => "Modules have already been initialized",
+        "GS101" => "Invalid module address provided",
+        "GS102" => "Module has already been added",
+        "GS103" => "Invalid prevModule, module pair provided",
+        "GS104" => "Method can only be called from an enabled module",
+        "GS200" => "Owners have already been setup",
+        "GS201" => "Threshold cannot exceed owner count",
+        "GS202" => "Threshold needs to be greater than 0",
+        "GS203" => "Invalid owner address provided",
+        "GS204" => "Address is already an owner",
+        "GS205" => "Invalid prevOwner, owner pair provided",
+        "GS300" => "Guard does not implement IERC165",
+        _ => return None,
+    };
+    Some(message)
+}
+
+/// Result of simulating `execTransaction` before the owners sign or the executor sends it.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeSimulation {
+    pub success: bool,
+    // gas of execTransaction, None when it could not be estimated
+    pub gas: Option<u64>,
+    // signatures were replaced by the executor approval with the threshold overridden to 1
+    pub state_override: bool,
+    pub revert: Option<ContractRevert>,
+    // description of the GSxxx code
+    pub safe_error: Option<String>,
+    // revert of the inner call when the safe reports GS013
+    pub inner_revert: Option<ContractRevert>,
+}
+
+impl SafeSimulation {
+    pub fn success(gas: Option<u64>, state_override: bool) -> Self {
+        Self {
+            success: true,
+            gas,
+            state_override,
+            revert: None,
+            safe_error: None,
+            inner_revert: None,
+        }
+    }
+
+    pub fn failed(revert: ContractRevert, state_override: bool) -> Self {
+        let safe_error = match revert.kind {
+            RevertKind::Error => safe_error_message(&revert.reason).map(|m| m.to_string()),
+            _ => None,
+        };
+
+        Self {
+            success: false,
+            gas: None,
+            state_override,
+            revert: Some(revert),
+            safe_error,
+            inner_revert: None,
+        }
+    }
+
+    // GS013 hides the revert of the inner call
+    pub fn inner_call_failed(&self) -> bool {
+        self.revert
+            .as_ref()
+            .map(|r| r.reason == "GS013")
+            .unwrap_or(false)
+    }
+}
+
+/// execTransaction built from the payload every owner signs.
+pub struct SafeSimulationOpt {
+    pub safe: Address,
+    pub executor: Address,
+    pub hash_call: contract::getTransactionHashCall,
+    // collected signatures, None to simulate with the executor approval
+    pub signatures: Option<primitives::Bytes>,
+}
+
+impl SafeSimulationOpt {
+    // raw_data is the `MultisigTxResp::raw_data` of build_multisig_tx
+    pub fn new(safe: &str, executor: &str, raw_data: &str) -> crate::Result<Self> {
+        let payload = MultisigPayloadOpt::from_str(raw_data)?;
+        let input = payload
+            .input_data
+            .strip_prefix("0x")
+            .unwrap_or(&payload.input_data);
+        let bytes = wallet_utils::hex_func::hex_decode(input)?;
+        let hash_call = contract::getTransactionHashCall::abi_decode(&bytes, false)
+            .map_err(|e| crate::Error::HexError(e.to_string()))?;
+
+        Ok(Self {
+            safe: address::parse_eth_address(safe)?,
+            executor: address::parse_eth_address(executor)?,
+            hash_call,
+            signatures: None,
+        })
+    }
+
+    pub fn with_signatures(mut self, signatures: &str) -> crate::Result<Self> {
+        let bytes = wallet_utils::hex_func::hex_decode(
+            signatures.strip_prefix("0x").unwrap_or(signatures),
+        )?;
+        self.signatures = Some(bytes.into());
+        Ok(self)
+    }
+
+    // approval of the executor itself (v = 1, r = executor), accepted without a signature
+    // when msg.sender is the owner
+    pub fn executor_signature(&self) -> primitives::Bytes {
+        let mut signatures = SafeSignatures {
+            safe_tx_hash: B256::ZERO,
+            signatures: vec![],
+            hash_input: None,
+        };
+        signatures.signatures.push(SafeSignature::ApprovedHash {
+            owner: self.executor,
+        });
+        signatures.encode().into()
+    }
+
+    pub fn exec_tx(&self, signatures: primitives::Bytes) -> TransactionRequest {
+        let call = contract::execTransactionCall {
+            to: self.hash_call.to,
+            value: self.hash_call.value,
+            data: self.hash_call.data.clone(),
+            operation: self.hash_call.operation,
+            safeTxGas: self.hash_call.safeTxGas,
+            baseGas: self.hash_call.baseGas,
+            gasPrice: self.hash_call.gasPrice,
+            gasToken: self.hash_call.gasToken,
+            refundReceiver: self.hash_call.refundReceiver,
+            signatures,
+        };
+
+        TransactionRequest::default()
+            .with_from(self.executor)
+            .with_to(self.safe)
+            .with_input(call.abi_encode())
+    }
+
+    pub fn nonce_tx(&self) -> TransactionRequest {
+        TransactionRequest::default()
+            .with_to(self.safe)
+            .with_input(contract::nonceCall {}.abi_encode())
+    }
+
+    // the proposal can only be executed at the current nonce of the safe,
+    // an executed or replaced proposal would fail with an invalid signature
+    pub fn check_nonce(&self, safe_nonce: U256) -> crate::Result<()> {
+        if self.hash_call._nonce != safe_nonce {
+            return Err(crate::Error::Other(format!(
+                "safe nonce is {}, the transaction nonce is {}",
+                safe_nonce, self.hash_call._nonce
+            )));
+        }
+        Ok(())
+    }
+
+    // the inner call as sent by the safe, to recover the reason hidden by GS013
+    pub fn inner_tx(&self) -> Option<TransactionRequest> {
+        // delegatecall runs in the safe context and can not be replayed as a plain call
+        if self.hash_call.operation != super::SafeOperation::Call as u8 {
+            return None;
+        }
+
+        Some(
+            TransactionRequest::default()
+                .with_from(self.safe)
+                .with_to(self.hash_call.to)
+                .with_value(self.hash_call.value)
+                .with_input(self.hash_call.data.clone()),
+        )
+    }
+
+    // state override setting the threshold to 1
+    pub fn threshold_override(&self) -> serde_json::Value {
+        let slot = B256::from(U256::from(SAFE_THRESHOLD_SLOT));
+        let value = B256::from(U256::from(1));
+
+        let mut state_diff = serde_json::Map::new();
+        state_diff.insert(slot.to_string(), serde_json::json!(value.to_string()));
+
+        let mut overrides = serde_json::Map::new();
+        overrides.insert(
+            self.safe.to_string(),
+            serde_json::json!({ "stateDiff": state_diff }),
+        );
+        serde_json::Value::Object(overrides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_error() {
+        let revert = ContractRevert {
+            kind: RevertKind::Error,
+            reason: "GS026".to_string(),
+            args: None,
+            data: String::new(),
+        };
+        let res = SafeSimulation::failed(revert, false);
+        assert_eq!(res.safe_error.as_deref(), Some("Invalid owner provided"));
+        assert!(!res.inner_call_failed());
+    }
+
+    #[test]
+    fn test_exec_tx() {
+        let hash_call = contract::getTransactionHashCall {
+            to: Address::repeat_byte(0x33),
+            value: U256::from(1),
+            data: primitives::Bytes::default(),
+            operation: 0,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            _nonce: U256::from(7),
+        };
+        let payload = MultisigPayloadOpt::new(
+            format!("0x{}", hex::encode(hash_call.abi_encode())),
+            String::new(),
+        );
+
+        let opt = SafeSimulationOpt::new(
+            &Address::repeat_byte(0x11).to_string(),
+            &Address::repeat_byte(0x22).to_string(),
+            &payload.to_string().unwrap(),
+        )
+        .unwrap();
+
+        // built at nonce 7, stale once the safe moved on
+        opt.check_nonce(U256::from(7)).unwrap();
+        assert!(opt.check_nonce(U256::from(8)).is_err());
+        let nonce = opt.nonce_tx();
+        assert_eq!(nonce.to, Some(Address::repeat_byte(0x11).into()));
+        assert_eq!(
+            nonce.input.input().unwrap()[..],
+            contract::nonceCall::SELECTOR
+        );
+
+        let signature = opt.executor_signature();
+        assert_eq!(signature.len(), 65);
+        assert_eq!(&signature[12..32], Address::repeat_byte(0x22).as_slice());
+        assert_eq!(signature[64], 1);
+
+        let tx = opt.exec_tx(signature);
+        let exec =
+            contract::execTransactionCall::abi_decode(tx.input.input().unwrap(), true).unwrap();
+        assert_eq!(exec.to, Address::repeat_byte(0x33));
+        assert!(opt.inner_tx().is_some());
+
+        let overrides = opt.threshold_override();
+        let diff = &overrides[Address::repeat_byte(0x11).to_string()]["stateDiff"];
+        assert_eq!(
+            diff["0x0000000000000000000000000000000000000000000000000000000000000004"],
+            "0x0000000000000000000000000000000000000000000000000000000000000001"
+        );
+    }
+}
@@ -16,6 +16,8 @@ pub struct MultisigTransferOpt {
     pub value: primitives::U256,
     pub nonce: primitives::U256,
     pub token: Option<primitives::Address>,
+    // arbitrary safe call (owner management, multisend), replaces the transfer when set
+    pub call: Option<super::SafeCall>,
     pub exec_params: Option<ExecParams>,
 }
 pub struct ExecParams {
@@ -39,6 +41,22 @@ impl MultisigTransferOpt {
             value,
             nonce: primitives::U256::ZERO,
             token: None,
+            call: None,
+            exec_params: None,
+        })
+    }
+
+    // proposal for an arbitrary call executed by the safe
+    pub fn from_call(multisig_account: &str, call: super::SafeCall) -> Result<Self, crate::Error> {
+        let multisig_account = address::parse_eth_address(multisig_account)?;
+
+        Ok(Self {
+            multisig_account,
+            to: call.to,
+            value: call.value,
+            nonce: primitives::U256::ZERO,
+            token: None,
+            call: Some(call),
             exec_params: None,
         })
     }
@@ -81,7 +99,20 @@ impl MultisigTransferOpt {
         let default_value = primitives::U256::ZERO;
         let default_address = primitives::Address::default();
 
-        let tx_data = if let Some(token) = self.token {
+        let tx_data = if let Some(call) = self.call.as_ref() {
+            contract::getTransactionHashCall {
+                to: call.to,
+                value: call.value,
+                data: call.data.clone(),
+                operation: call.operation as u8,
+                safeTxGas: default_value,
+                baseGas: default_value,
+                gasPrice: default_value,
+                gasToken: default_address,
+                refundReceiver: default_address,
+                _nonce: self.nonce,
+            }
+        } else if let Some(token) = self.token {
             let data = contract::transferCall {
                 from: self.to,
                 amount: self.value,
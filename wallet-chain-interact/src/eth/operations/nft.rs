@@ -0,0 +1,378 @@
+// 封装erc721 / erc1155 相关的操作
+use crate::{
+    eth::protocol::contract::{IERC165, IERC721, IERC1155},
+    types,
+};
+use alloy::{
+    network::TransactionBuilder as _,
+    primitives::{self, FixedBytes, U256},
+    rpc::types::TransactionRequest,
+    sol_types::SolCall as _,
+};
+use wallet_utils::address;
+
+// erc-165 interface ids
+pub const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+pub const ERC721_METADATA_INTERFACE_ID: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+pub const ERC1155_INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+pub const ERC1155_METADATA_URI_INTERFACE_ID: [u8; 4] = [0x0e, 0x89, 0x34, 0x1c];
+
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NftStandard {
+    Erc721,
+    Erc1155,
+}
+
+impl NftStandard {
+    pub fn interface_id(&self) -> [u8; 4] {
+        match self {
+            NftStandard::Erc721 => ERC721_INTERFACE_ID,
+            NftStandard::Erc1155 => ERC1155_INTERFACE_ID,
+        }
+    }
+}
+
+/// ERC-721 `safeTransferFrom(from, to, tokenId, data)`.
+pub struct Erc721Transfer {
+    pub from: primitives::Address,
+    pub to: primitives::Address,
+    pub token_id: U256,
+    pub contract: primitives::Address,
+    pub data: primitives::Bytes,
+}
+
+impl Erc721Transfer {
+    pub fn new(from: &str, to: &str, token_id: U256, contract: &str) -> crate::Result<Self> {
+        Ok(Self {
+            from: address::parse_eth_address(from)?,
+            to: address::parse_eth_address(to)?,
+            token_id,
+            contract: address::parse_eth_address(contract)?,
+            data: primitives::Bytes::default(),
+        })
+    }
+
+    pub fn with_data(mut self, data: Vec<u8>) -> Self {
+        self.data = data.into();
+        self
+    }
+}
+
+impl types::Transaction<TransactionRequest> for Erc721Transfer {
+    fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
+        let call = IERC721::safeTransferFromCall {
+            from: self.from,
+            to: self.to,
+            tokenId: self.token_id,
+            data: self.data.clone(),
+        };
+
+        Ok(TransactionRequest::default()
+            .from(self.from)
+            .to(self.contract)
+            .value(U256::ZERO)
+            .with_input(call.abi_encode()))
+    }
+}
+
+/// ERC-1155 transfer, a single id uses `safeTransferFrom`, more ids `safeBatchTransferFrom`.
+pub struct Erc1155Transfer {
+    pub from: primitives::Address,
+    pub to: primitives::Address,
+    pub ids: Vec<U256>,
+    pub amounts: Vec<U256>,
+    pub contract: primitives::Address,
+    pub data: primitives::Bytes,
+}
+
+impl Erc1155Transfer {
+    pub fn new(
+        from: &str,
+        to: &str,
+        id: U256,
+        amount: U256,
+        contract: &str,
+    ) -> crate::Result<Self> {
+        Self::new_batch(from, to, vec![(id, amount)], contract)
+    }
+
+    // pairs of (id, amount)
+    pub fn new_batch(
+        from: &str,
+        to: &str,
+        items: Vec<(U256, U256)>,
+        contract: &str,
+    ) -> crate::Result<Self> {
+        if items.is_empty() {
+            return Err(crate::Error::Other("erc1155 transfer is empty".to_string()));
+        }
+        let (ids, amounts) = items.into_iter().unzip();
+
+        Ok(Self {
+            from: address::parse_eth_address(from)?,
+            to: address::parse_eth_address(to)?,
+            ids,
+            amounts,
+            contract: address::parse_eth_address(contract)?,
+            data: primitives::Bytes::default(),
+        })
+    }
+
+    pub fn with_data(mut self, data: Vec<u8>) -> Self {
+        self.data = data.into();
+        self
+    }
+}
+
+impl types::Transaction<TransactionRequest> for Erc1155Transfer {
+    fn build_transaction(&self) -> Result<TransactionRequest, crate::Error> {
+        let input = if self.ids.len() == 1 {
+            IERC1155::safeTransferFromCall {
+                from: self.from,
+                to: self.to,
+                id: self.ids[0],
+                amount: self.amounts[0],
+                data: self.data.clone(),
+            }
+            .abi_encode()
+        } else {
+            IERC1155::safeBatchTransferFromCall {
+                from: self.from,
+                to: self.to,
+                ids: self.ids.clone(),
+                amounts: self.amounts.clone(),
+                data: self.data.clone(),
+            }
+            .abi_encode()
+        };
+
+        Ok(TransactionRequest::default()
+            .from(self.from)
+            .to(self.contract)
+            .value(U256::ZERO)
+            .with_input(input))
+    }
+}
+
+fn call_tx(contract: &str, input: Vec<u8>) -> crate::Result<TransactionRequest> {
+    Ok(TransactionRequest::default()
+        .to(address::parse_eth_address(contract)?)
+        .with_input(input))
+}
+
+pub fn supports_interface_tx(
+    contract: &str,
+    interface_id: [u8; 4],
+) -> crate::Result<TransactionRequest> {
+    let call = IERC165::supportsInterfaceCall {
+        interfaceId: FixedBytes(interface_id),
+    };
+    call_tx(contract, call.abi_encode())
+}
+
+pub fn owner_of_tx(contract: &str, token_id: U256) -> crate::Result<TransactionRequest> {
+    call_tx(
+        contract,
+        IERC721::ownerOfCall { tokenId: token_id }.abi_encode(),
+    )
+}
+
+pub fn erc721_balance_tx(contract: &str, owner: &str) -> crate::Result<TransactionRequest> {
+    let call = IERC721::balanceOfCall {
+        owner: address::parse_eth_address(owner)?,
+    };
+    call_tx(contract, call.abi_encode())
+}
+
+pub fn erc1155_balance_tx(
+    contract: &str,
+    owner: &str,
+    id: U256,
+) -> crate::Result<TransactionRequest> {
+    let call = IERC1155::balanceOfCall {
+        account: address::parse_eth_address(owner)?,
+        id,
+    };
+    call_tx(contract, call.abi_encode())
+}
+
+pub fn token_uri_tx(contract: &str, token_id: U256) -> crate::Result<TransactionRequest> {
+    call_tx(
+        contract,
+        IERC721::tokenURICall { tokenId: token_id }.abi_encode(),
+    )
+}
+
+pub fn uri_tx(contract: &str, id: U256) -> crate::Result<TransactionRequest> {
+    call_tx(contract, IERC1155::uriCall { id }.abi_encode())
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct NftMetadata {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub image: Option<String>,
+    #[serde(default)]
+    pub animation_url: Option<String>,
+    #[serde(default)]
+    pub attributes: Option<serde_json::Value>,
+}
+
+pub enum MetadataSource {
+    // metadata embedded as data:application/json
+    Inline(NftMetadata),
+    // url to fetch the metadata from
+    Url(String),
+}
+
+// erc1155 replaces {id} with the lowercase hex id padded to 64 chars,
+// ipfs:// is rewritten to the gateway
+pub fn resolve_uri(uri: &str, token_id: U256, gateway: &str) -> crate::Result<MetadataSource> {
+    let uri = uri.trim();
+    let uri = if uri.contains("{id}") {
+        uri.replace("{id}", &hex::encode(token_id.to_be_bytes::<32>()))
+    } else {
+        uri.to_string()
+    };
+
+    if let Some(data) = uri.strip_prefix("data:application/json") {
+        let metadata = if let Some(encoded) = data.strip_prefix(";base64,") {
+            let bytes = wallet_utils::base64_to_bytes(encoded)?;
+            serde_json::from_slice(&bytes)
+        } else {
+            let raw = data.strip_prefix(",").unwrap_or(data);
+            let raw = percent_decode(raw);
+            serde_json::from_str(&raw)
+        }
+        .map_err(|e| crate::Error::Other(format!("nft metadata: {}", e)))?;
+        return Ok(MetadataSource::Inline(metadata));
+    }
+
+    Ok(MetadataSource::Url(to_gateway_url(&uri, gateway)))
+}
+
+pub fn to_gateway_url(uri: &str, gateway: &str) -> String {
+    match uri.strip_prefix("ipfs://") {
+        Some(path) => {
+            let path = path.strip_prefix("ipfs/").unwrap_or(path);
+            format!("{}/{}", gateway.trim_end_matches('/'), path)
+        }
+        None => uri.to_string(),
+    }
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut res = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && let Ok(hex) = std::str::from_utf8(&bytes[i + 1..i + 3])
+            && let Ok(v) = u8::from_str_radix(hex, 16)
+        {
+            res.push(v);
+            i += 3;
+            continue;
+        }
+        res.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&res).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Transaction as _;
+
+    const CONTRACT: &str = "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D";
+    const FROM: &str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+    const TO: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+
+    #[test]
+    fn test_transfer_selector() {
+        let tx = Erc721Transfer::new(FROM, TO, U256::from(1), CONTRACT)
+            .unwrap()
+            .build_transaction()
+            .unwrap();
+        let input = tx.input.input().unwrap();
+        // safeTransferFrom(address,address,uint256,bytes)
+        assert_eq!(&input[..4], &[0xb8, 0x8d, 0x4f, 0xde]);
+
+        let single = Erc1155Transfer::new(FROM, TO, U256::from(1), U256::from(2), CONTRACT)
+            .unwrap()
+            .build_transaction()
+            .unwrap();
+        // safeTransferFrom(address,address,uint256,uint256,bytes)
+        assert_eq!(
+            &single.input.input().unwrap()[..4],
+            &[0xf2, 0x42, 0x43, 0x2a]
+        );
+
+        let batch = Erc1155Transfer::new_batch(
+            FROM,
+            TO,
+            vec![
+                (U256::from(1), U256::from(2)),
+                (U256::from(3), U256::from(4)),
+            ],
+            CONTRACT,
+        )
+        .unwrap()
+        .build_transaction()
+        .unwrap();
+        // safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+        assert_eq!(
+            &batch.input.input().unwrap()[..4],
+            &[0x2e, 0xb2, 0xc2, 0xd6]
+        );
+    }
+
+    #[test]
+    fn test_resolve_uri() {
+        let res = resolve_uri(
+            "ipfs://QmeSjSinHpPnmXmspMjwiXyN6zS4E9zccariGR3jxcaWtq/1",
+            U256::from(1),
+            DEFAULT_IPFS_GATEWAY,
+        )
+        .unwrap();
+        assert!(matches!(
+            res,
+            MetadataSource::Url(url) if url == "https://ipfs.io/ipfs/QmeSjSinHpPnmXmspMjwiXyN6zS4E9zccariGR3jxcaWtq/1"
+        ));
+
+        let res = resolve_uri(
+            "https://example.com/{id}.json",
+            U256::from(0x4cce0),
+            DEFAULT_IPFS_GATEWAY,
+        )
+        .unwrap();
+        assert!(matches!(
+            res,
+            MetadataSource::Url(url) if url == "https://example.com/000000000000000000000000000000000000000000000000000000000004cce0.json"
+        ));
+
+        // {"name":"a"}
+        let res = resolve_uri(
+            "data:application/json;base64,eyJuYW1lIjoiYSJ9",
+            U256::ZERO,
+            DEFAULT_IPFS_GATEWAY,
+        )
+        .unwrap();
+        assert!(matches!(res, MetadataSource::Inline(m) if m.name.as_deref() == Some("a")));
+
+        let res = resolve_uri(
+            "data:application/json,%7B%22name%22%3A%22b%22%7D",
+            U256::ZERO,
+            DEFAULT_IPFS_GATEWAY,
+        )
+        .unwrap();
+        assert!(matches!(res, MetadataSource::Inline(m) if m.name.as_deref() == Some("b")));
+    }
+}
@@ -1,18 +1,20 @@
 use alloy::primitives::U256;
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct FeeSetting {
     pub base_fee: U256,
     pub max_priority_fee_per_gas: U256,
     pub max_fee_per_gas: U256,
     pub gas_limit: U256,
+    // l1 data fee of op-stack rollups, paid on top of the gas limit
+    pub l1_fee: U256,
 }
 
 impl FeeSetting {
     pub fn transaction_fee(&self) -> U256 {
         let price = self.base_fee + self.max_priority_fee_per_gas;
         // let price = self.max_fee_per_gas;
-        self.gas_limit * price
+        self.gas_limit * price + self.l1_fee
     }
 
     pub fn new_with_price(price: U256) -> Self {
@@ -24,6 +26,7 @@ impl FeeSetting {
             gas_limit: U256::from(300000),
             max_priority_fee_per_gas: priority_fee,
             max_fee_per_gas: max_fee,
+            l1_fee: U256::ZERO,
         }
     }
 }
@@ -33,3 +36,27 @@ pub struct EtherFee {
     pub base_fee: U256,
     pub priority_fee_per_gas: U256,
 }
+
+// l1 data cost of a layer 2 transaction
+#[derive(Default, Debug)]
+pub struct L1DataFee {
+    // total l1 cost in wei
+    pub fee: U256,
+    // part of the gas limit used to pay the l1 cost (arbitrum), 0 for op-stack
+    pub gas: U256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FeeSetting;
+    use alloy::primitives::U256;
+
+    #[test]
+    fn test_transaction_fee_with_l1() {
+        let mut fee = FeeSetting::new_with_price(U256::from(1_000_000u64));
+        let l2_fee = fee.transaction_fee();
+
+        fee.l1_fee = U256::from(42_000u64);
+        assert_eq!(fee.transaction_fee(), l2_fee + U256::from(42_000u64));
+    }
+}
@@ -41,6 +41,20 @@ sol!(
         uint256 _nonce
     ) public view override returns (bytes32);
 
+    // the preimage of getTransactionHash, passed to contract owners by safe v1.3.0
+    function encodeTransactionData(
+        address to,
+        uint256 value,
+        bytes calldata data,
+        uint8 operation,
+        uint256 safeTxGas,
+        uint256 baseGas,
+        uint256 gasPrice,
+        address gasToken,
+        address refundReceiver,
+        uint256 _nonce
+    ) public view returns (bytes memory);
+
     function execTransaction(
         address to,
         uint256 value,
@@ -57,4 +71,125 @@ sol!(
     function nonce() public view returns (uint256 nonce);
 
     function proxyCreationCode() public pure returns (bytes memory);
+
+    // safe owner management
+    function getOwners() public view returns (address[] memory);
+    function getThreshold() public view returns (uint256);
+    function addOwnerWithThreshold(address owner, uint256 _threshold) public;
+    function removeOwner(address prevOwner, address owner, uint256 _threshold) public;
+    function swapOwner(address prevOwner, address oldOwner, address newOwner) public;
+    function changeThreshold(uint256 _threshold) public;
+    function approveHash(bytes32 hashToApprove) external;
+    function approvedHashes(address owner, bytes32 hash) public view returns (uint256);
+    function multiSend(bytes memory transactions) public payable;
+
+    // eip-2612
+    function nonces(address owner) external view returns (uint256);
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+    function permit(
+        address owner,
+        address spender,
+        uint256 value,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    // uniswap permit2
+    struct PermitDetails {
+        address token;
+        uint160 amount;
+        uint48 expiration;
+        uint48 nonce;
+    }
+
+    struct PermitSingle {
+        PermitDetails details;
+        address spender;
+        uint256 sigDeadline;
+    }
+
+    struct PermitBatch {
+        PermitDetails[] details;
+        address spender;
+        uint256 sigDeadline;
+    }
+
+    struct TokenSpenderPair {
+        address token;
+        address spender;
+    }
+
+    interface IPermit2 {
+        function allowance(address user, address token, address spender) external view returns (uint160 amount, uint48 expiration, uint48 nonce);
+        function approve(address token, address spender, uint160 amount, uint48 expiration) external;
+        function lockdown(TokenSpenderPair[] calldata approvals) external;
+    }
+
+    // legacy eip-1271 used by safe v1.3.0 to check contract owners
+    interface ISignatureValidator {
+        function isValidSignature(bytes memory _data, bytes memory _signature) public view returns (bytes4);
+    }
+
+    // nft
+    interface IERC165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+
+    interface IERC721 {
+        function ownerOf(uint256 tokenId) external view returns (address);
+        function balanceOf(address owner) external view returns (uint256);
+        function tokenURI(uint256 tokenId) external view returns (string);
+        function safeTransferFrom(address from, address to, uint256 tokenId, bytes data) external;
+    }
+
+    interface IERC1155 {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function balanceOfBatch(address[] accounts, uint256[] ids) external view returns (uint256[]);
+        function uri(uint256 id) external view returns (string);
+        function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data) external;
+        function safeBatchTransferFrom(address from, address to, uint256[] ids, uint256[] amounts, bytes data) external;
+    }
+
+    // op-stack GasPriceOracle predeploy
+    function getL1Fee(bytes memory _data) external view returns (uint256);
+
+    // arbitrum NodeInterface precompile
+    function gasEstimateComponents(
+        address to,
+        bool contractCreation,
+        bytes calldata data
+    ) external payable returns (
+        uint64 gasEstimate,
+        uint64 gasEstimateForL1,
+        uint256 baseFee,
+        uint256 l1BaseFeeEstimate
+    );
+
+    // ens
+    interface IENSRegistry {
+        function resolver(bytes32 node) external view returns (address);
+    }
+
+    interface IENSResolver {
+        function addr(bytes32 node) external view returns (address);
+        function name(bytes32 node) external view returns (string);
+    }
+
+    // ensip-10 wildcard resolution
+    interface IExtendedResolver {
+        function resolve(bytes name, bytes data) external view returns (bytes);
+    }
+
+    // eip-3668 ccip-read
+    error OffchainLookup(address sender, string[] urls, bytes callData, bytes4 callbackFunction, bytes extraData);
 );
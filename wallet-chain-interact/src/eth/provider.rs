@@ -2,7 +2,10 @@ use super::{
     EtherFee,
     protocol::contract::{isBlackListedCall, nameCall, symbolCall},
 };
-use crate::eth::protocol::contract::{balanceOfCall, decimalsCall};
+use crate::eth::protocol::contract::{
+    balanceOfCall, decimalsCall, gasEstimateComponentsCall, gasEstimateComponentsReturn,
+    getL1FeeCall,
+};
 use alloy::{
     network::{TransactionBuilder, eip2718::Encodable2718},
     primitives::U256,
@@ -11,7 +14,7 @@ use alloy::{
 };
 use serde_json::json;
 use wallet_transport::{client::RpcClient, types::JsonRpcParams};
-use wallet_types::chain::chain::ChainCode;
+use wallet_types::chain::evm::EvmFeeModel;
 use wallet_utils::{address, unit};
 
 pub struct Provider {
@@ -68,26 +71,91 @@ impl Provider {
             gas_limit,
             max_fee_per_gas: max_fee,
             max_priority_fee_per_gas,
+            l1_fee: U256::ZERO,
         };
         Ok(res)
     }
 
+    // the fee model comes from the evm chain registry
     pub async fn set_transaction_fee(
         &self,
         tx: TransactionRequest,
         fee: super::params::FeeSetting,
-        chain_code: ChainCode,
+        fee_model: EvmFeeModel,
     ) -> crate::Result<TransactionRequest> {
-        // 币安链 单独处理
-        let max_fee = match chain_code {
-            ChainCode::BnbSmartChain => fee.max_priority_fee_per_gas.to::<u128>(),
-            _ => fee.max_fee_per_gas.to::<u128>(),
+        let tx = tx.with_gas_limit(fee.gas_limit.to::<u64>());
+
+        Ok(match fee_model {
+            EvmFeeModel::Legacy => tx.with_gas_price(fee.max_fee_per_gas.to::<u128>()),
+            EvmFeeModel::Eip1559 => tx
+                .with_max_priority_fee_per_gas(fee.max_priority_fee_per_gas.to::<u128>())
+                .with_max_fee_per_gas(fee.max_fee_per_gas.to::<u128>()),
+            // base fee is 0, max fee equals the priority fee
+            EvmFeeModel::Eip1559ZeroBaseFee => tx
+                .with_max_priority_fee_per_gas(fee.max_priority_fee_per_gas.to::<u128>())
+                .with_max_fee_per_gas(fee.max_priority_fee_per_gas.to::<u128>()),
+        })
+    }
+
+    // 2718 encoded transaction signed by a throwaway key, only used to price the l1 data
+    pub async fn serialize_for_fee(&self, tx: TransactionRequest) -> crate::Result<Vec<u8>> {
+        let nonce = match tx.from {
+            Some(from) => self.nonce(&from.to_string()).await?,
+            None => 0,
         };
+        let chain_id = self.chain_id().await?;
+        let tx = tx.with_nonce(nonce).with_chain_id(chain_id);
+
+        let signer = alloy::signers::local::PrivateKeySigner::from_bytes(
+            &alloy::primitives::B256::repeat_byte(1),
+        )
+        .map_err(|e| crate::Error::SignError(e.to_string()))?;
+        let tx = tx.with_from(signer.address());
+        let wallet = alloy::network::EthereumWallet::from(signer);
+
+        let tx_envelope = tx
+            .build(&wallet)
+            .await
+            .map_err(|e| crate::Error::SignError(e.to_string()))?;
+        Ok(tx_envelope.encoded_2718())
+    }
+
+    // op-stack l1 data fee in wei
+    pub async fn op_l1_fee(&self, serialized: Vec<u8>) -> crate::Result<U256> {
+        let oracle = address::parse_eth_address(super::consts::OP_GAS_PRICE_ORACLE)?;
+        let call = getL1FeeCall {
+            _data: serialized.into(),
+        };
+        let tx = TransactionRequest::default()
+            .with_to(oracle)
+            .with_input(call.abi_encode());
+
+        let r = self.eth_call(tx).await?;
+        Ok(unit::u256_from_str(&r)?)
+    }
+
+    // arbitrum gas estimate with the l1 component
+    pub async fn arb_gas_components(
+        &self,
+        tx: &TransactionRequest,
+    ) -> crate::Result<gasEstimateComponentsReturn> {
+        let node_interface = address::parse_eth_address(super::consts::ARB_NODE_INTERFACE)?;
+        let to = tx.to.and_then(|to| to.to().copied());
+        let call = gasEstimateComponentsCall {
+            to: to.unwrap_or_default(),
+            contractCreation: to.is_none(),
+            data: tx.input.input().cloned().unwrap_or_default(),
+        };
+
+        let mut req = TransactionRequest::default()
+            .with_to(node_interface)
+            .with_value(tx.value.unwrap_or_default())
+            .with_input(call.abi_encode());
+        if let Some(from) = tx.from {
+            req = req.with_from(from);
+        }
 
-        Ok(tx
-            .with_gas_limit(fee.gas_limit.to::<u64>())
-            .with_max_priority_fee_per_gas(fee.max_priority_fee_per_gas.to::<u128>())
-            .with_max_fee_per_gas(max_fee))
+        self.call_returns::<gasEstimateComponentsCall>(req).await
     }
 
     pub async fn send_raw_transaction(
@@ -149,6 +217,51 @@ impl Provider {
         Ok(self.client.invoke_request::<_, String>(params).await?)
     }
 
+    // eth_call with the hex decoded return data
+    pub async fn call_bytes(&self, tx: TransactionRequest) -> crate::Result<Vec<u8>> {
+        let res = self.eth_call(tx).await?;
+        Ok(wallet_utils::hex_func::hex_decode(
+            res.strip_prefix("0x").unwrap_or(&res),
+        )?)
+    }
+
+    // eth_call decoded as the returns of the call
+    pub async fn call_returns<C: SolCall>(
+        &self,
+        tx: TransactionRequest,
+    ) -> crate::Result<C::Return> {
+        let bytes = self.call_bytes(tx).await?;
+        C::abi_decode_returns(&bytes, true).map_err(|e| crate::Error::HexError(e.to_string()))
+    }
+
+    // eth_call with a state override set, not every node supports the third param
+    pub async fn eth_call_with_override(
+        &self,
+        tx: TransactionRequest,
+        overrides: serde_json::Value,
+    ) -> crate::Result<String> {
+        let params = JsonRpcParams::default().method("eth_call").params(vec![
+            json!(tx),
+            json!("latest"),
+            overrides,
+        ]);
+
+        Ok(self.client.invoke_request::<_, String>(params).await?)
+    }
+
+    pub async fn estimate_gas_with_override(
+        &self,
+        tx: TransactionRequest,
+        overrides: serde_json::Value,
+    ) -> crate::Result<U256> {
+        let params = JsonRpcParams::default()
+            .method("eth_estimateGas")
+            .params(vec![json!(tx), json!("latest"), overrides]);
+
+        let r = self.client.invoke_request::<_, String>(params).await?;
+        Ok(unit::u256_from_str(&r)?)
+    }
+
     pub async fn get_block_height(&self) -> crate::Result<String> {
         let params: JsonRpcParams<()> = JsonRpcParams::default()
             .method("eth_blockNumber")
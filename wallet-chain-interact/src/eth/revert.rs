@@ -0,0 +1,201 @@
+// 合约执行 revert 的解析
+use alloy::{
+    dyn_abi::JsonAbiExt as _,
+    json_abi::JsonAbi,
+    primitives::U256,
+    sol_types::{Panic, Revert, SolError as _},
+};
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RevertKind {
+    // require / revert("reason")
+    Error,
+    // assert, overflow, division by zero ...
+    Panic,
+    // custom error declared in the abi
+    Custom,
+    // revert without data or with an unknown selector
+    Unknown,
+}
+
+/// Decoded revert of a contract call.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractRevert {
+    pub kind: RevertKind,
+    // reason string, panic description or custom error name
+    pub reason: String,
+    // arguments of the custom error
+    pub args: Option<serde_json::Value>,
+    // raw revert data in hex
+    pub data: String,
+}
+
+impl std::fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.args {
+            Some(args) => write!(f, "{}{}", self.reason, args),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl ContractRevert {
+    // the abi is used to decode custom errors
+    pub fn decode(data: &[u8], abi: Option<&JsonAbi>) -> Self {
+        let hex_data = format!("0x{}", hex::encode(data));
+
+        if data.len() < 4 {
+            return Self::unknown("execution reverted", hex_data);
+        }
+        let (selector, body) = data.split_at(4);
+
+        if selector == Revert::SELECTOR {
+            if let Ok(revert) = Revert::abi_decode(data, true) {
+                return Self {
+                    kind: RevertKind::Error,
+                    reason: revert.reason,
+                    args: None,
+                    data: hex_data,
+                };
+            }
+        } else if selector == Panic::SELECTOR {
+            if let Ok(panic) = Panic::abi_decode(data, true) {
+                return Self {
+                    kind: RevertKind::Panic,
+                    reason: panic_reason(panic.code).to_string(),
+                    args: Some(serde_json::json!([panic.code.to_string()])),
+                    data: hex_data,
+                };
+            }
+        } else if let Some(abi) = abi {
+            for error in abi.errors() {
+                if error.selector().as_slice() != selector {
+                    continue;
+                }
+                if let Ok(values) = error.abi_decode_input(body, true) {
+                    let args =
+                        super::operations::contract_call::values_to_json(&values, &error.inputs);
+                    return Self {
+                        kind: RevertKind::Custom,
+                        reason: error.name.clone(),
+                        args: Some(args),
+                        data: hex_data,
+                    };
+                }
+            }
+        }
+
+        Self::unknown(
+            &format!("unknown revert 0x{}", hex::encode(selector)),
+            hex_data,
+        )
+    }
+
+    fn unknown(reason: &str, data: String) -> Self {
+        Self {
+            kind: RevertKind::Unknown,
+            reason: reason.to_string(),
+            args: None,
+            data,
+        }
+    }
+}
+
+// solidity panic codes
+fn panic_reason(code: U256) -> &'static str {
+    match code.saturating_to::<u64>() {
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array",
+        0x31 => "pop on empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to uninitialized function",
+        _ => "panic",
+    }
+}
+
+// revert data from the json rpc error of eth_call / eth_estimateGas,
+// nodes return it as `data` or `data.data` (or inside the message for some providers)
+pub fn revert_data(err: &crate::Error) -> Option<Vec<u8>> {
+    let crate::Error::TransportError(wallet_transport::TransportError::NodeResponseError(err)) =
+        err
+    else {
+        return None;
+    };
+
+    let data = match &err.data {
+        Some(serde_json::Value::String(data)) => Some(data.clone()),
+        Some(serde_json::Value::Object(obj)) => obj
+            .get("data")
+            .and_then(|d| d.as_str())
+            .map(|d| d.to_string()),
+        _ => None,
+    };
+
+    let data = data.or_else(|| {
+        let message = err.message.as_deref()?;
+        let start = message.find("0x")?;
+        let hex = message[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_hexdigit() || *c == 'x')
+            .collect::<String>();
+        Some(hex)
+    })?;
+
+    hex::decode(data.trim_start_matches("0x")).ok()
+}
+
+// whether the rpc error is an execution revert, as opposed to a network or node failure
+pub fn is_revert(err: &crate::Error) -> bool {
+    match err {
+        crate::Error::TransportError(wallet_transport::TransportError::NodeResponseError(e)) => {
+            e.code == 3
+                || e.message
+                    .as_deref()
+                    .map(|m| m.contains("revert"))
+                    .unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_revert() {
+        // Error("GS026")
+        let data = hex::decode("08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000054753303236000000000000000000000000000000000000000000000000000000").unwrap();
+        let revert = ContractRevert::decode(&data, None);
+        assert!(matches!(revert.kind, RevertKind::Error));
+        assert_eq!(revert.reason, "GS026");
+
+        // Panic(0x11)
+        let data =
+            hex::decode("4e487b710000000000000000000000000000000000000000000000000000000000000011")
+                .unwrap();
+        let revert = ContractRevert::decode(&data, None);
+        assert!(matches!(revert.kind, RevertKind::Panic));
+        assert_eq!(revert.reason, "arithmetic overflow or underflow");
+
+        // InsufficientBalance(uint256,uint256), selector 0xcf479181
+        let abi: JsonAbi = serde_json::from_str(
+            r#"[{"type":"error","name":"InsufficientBalance","inputs":[{"name":"available","type":"uint256"},{"name":"required","type":"uint256"}]}]"#,
+        )
+        .unwrap();
+        let data = hex::decode("cf47918100000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002").unwrap();
+        let revert = ContractRevert::decode(&data, Some(&abi));
+        assert!(matches!(revert.kind, RevertKind::Custom));
+        assert_eq!(revert.reason, "InsufficientBalance");
+        assert_eq!(
+            revert.args,
+            Some(serde_json::json!({"available": "1", "required": "2"}))
+        );
+    }
+}
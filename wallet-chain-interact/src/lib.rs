@@ -5,12 +5,15 @@ pub mod ltc;
 pub use ltc::script as ltc_scripts;
 pub mod dog;
 pub use dog::script as dog_scripts;
+pub mod bch;
 pub mod eth;
+pub mod name;
 pub mod sol;
 pub mod sui;
 pub mod ton;
 pub mod tron;
 mod utils;
+pub mod utxo;
 pub use utils::*;
 mod params;
 pub use params::*;
@@ -3,13 +3,8 @@ pub use chain::*;
 use std::str::FromStr;
 pub mod consts;
 pub mod operations;
-pub mod params;
-pub mod protocol;
-pub mod provider;
 pub mod script;
-pub mod signature;
-// mod tx_build;
-pub mod utxos;
+pub use crate::utxo::{params, protocol, provider, utxos};
 
 pub struct ParseLtcAddress {
     pub network: litecoin::Network,
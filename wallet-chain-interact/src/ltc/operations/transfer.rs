@@ -1,325 +1,44 @@
-use crate::ltc::{
-    ParseLtcAddress,
-    consts::{self, EXPEND_FEE_RATE},
-    provider::Provider,
-    signature::{self},
-    utxos::UtxoList,
+use crate::utxo::NetworkParams;
+pub use crate::utxo::transfer::{TransferArg, TransferBuilder};
+use wallet_types::chain::{
+    self,
+    address::r#type::{BtcAddressType, LtcAddressType},
 };
-use litecoin::{Amount, TxIn, consensus, transaction::Version};
-use wallet_types::chain::{self, address::r#type::LtcAddressType};
-use wallet_utils::unit;
 
-#[derive(Debug)]
-pub struct TransferArg {
-    pub from: litecoin::Address,
-    pub to: litecoin::Address,
-    pub value: litecoin::Amount,
-    pub change_address: litecoin::Address,
-    pub address_type: LtcAddressType,
-    pub fee_rate: Option<u64>,
-    pub spend_all: bool,
-}
-
-impl TransferArg {
-    pub fn new(
-        from: &str,
-        to: &str,
-        value: &str,
-        address_type: LtcAddressType,
-        network: chain::network::NetworkKind,
-    ) -> crate::Result<Self> {
-        let paras = ParseLtcAddress::new(network);
-
-        let value = unit::convert_to_u256(value, consts::LTC_DECIMAL)?;
-        let value = litecoin::Amount::from_sat(value.to::<u64>());
-
-        // let address_type = LtcAddressType::try_from(address_type)?;
-        Ok(Self {
-            from: paras.parse_address(from)?,
-            to: paras.parse_address(to)?,
-            change_address: paras.parse_address(from)?,
-            value,
-            address_type,
-            fee_rate: None,
-            spend_all: false,
-        })
-    }
-
-    pub fn with_spend_all(mut self, spend_all: bool) -> Self {
-        self.spend_all = spend_all;
-        self
-    }
-
-    /// unit is sat/vb
-    pub async fn get_fee_rate(
-        &self,
-        provider: &Provider,
-        _network: wallet_types::chain::network::NetworkKind,
-    ) -> crate::Result<litecoin::Amount> {
-        if let Some(fee_rate) = self.fee_rate {
-            Ok(litecoin::Amount::from_sat(fee_rate))
-        } else {
-            let fetched_fee_rate = provider.fetch_fee_rate(consts::FEE_RATE as u32).await?;
-
-            Ok(fetched_fee_rate)
-        }
-    }
-}
-
-impl TransferArg {
-    pub fn build_transaction(&self, mut utxo: UtxoList) -> crate::Result<TransferBuilder> {
-        let (input, output) = if self.spend_all {
-            (utxo.selected_all()?, vec![])
-        } else {
-            (
-                utxo.inputs_from_utxo(self.value)?,
-                vec![litecoin::TxOut {
-                    value: self.value,
-                    script_pubkey: self.to.script_pubkey(),
-                }],
-            )
-        };
-
-        let transaction = litecoin::Transaction {
-            version: Version(2),
-            lock_time: litecoin::absolute::LockTime::ZERO,
-            input,
-            output,
-        };
-
-        Ok(TransferBuilder { transaction, utxo })
-    }
-
-    // build transaction with fee  fee unit is Btc
-    // include change
-    pub fn build_with_fee(
-        &self,
-        mut utxo: UtxoList,
-        fee: Amount,
-    ) -> crate::Result<TransferBuilder> {
-        let amount = self.value + fee;
-        let input = utxo.inputs_from_utxo(amount)?;
-
-        let mut output = vec![];
-        let spend = litecoin::TxOut {
-            value: self.value,
-            script_pubkey: self.to.script_pubkey(),
-        };
-        output.push(spend);
-
-        // select utxo amount
-        let total_input = utxo.total_input_amount();
-
-        if total_input > amount {
-            let change = total_input - amount;
-            let change_output = litecoin::TxOut {
-                value: change,
-                script_pubkey: self.change_address.script_pubkey(),
-            };
-            output.push(change_output);
-        }
-
-        let transaction = litecoin::Transaction {
-            version: Version(2),
-            lock_time: litecoin::absolute::LockTime::ZERO,
-            input,
-            output,
-        };
-
-        Ok(TransferBuilder { transaction, utxo })
-    }
-}
-
-pub struct TransferBuilder {
-    pub transaction: litecoin::Transaction,
-    pub utxo: UtxoList,
-}
-
-// build
-impl TransferBuilder {
-    pub fn transactin_size(
-        &mut self,
-        fee_rate: Amount,
-        params: &TransferArg,
-    ) -> crate::Result<usize> {
-        if params.spend_all {
-            self.spent_all_set_fee(fee_rate, params.to.clone(), params.address_type)
-        } else {
-            // 找零和手续费配置
-            self.change_and_fee(
-                fee_rate,
-                params.change_address.clone(),
-                params.address_type,
-                params.value,
-            )
-        }
-    }
-
-    pub fn change_and_fee(
-        &mut self,
-        fee_rate: litecoin::Amount,
-        change_address: litecoin::Address,
-        address_type: LtcAddressType,
-        value: litecoin::Amount,
-    ) -> crate::Result<usize> {
-        loop {
-            // 在预估交易大小是使用交易的副本
-            let size = signature::predict_transaction_size(
-                self.transaction.clone(),
-                change_address.clone(),
-                address_type,
-                &None,
-            )?;
-
-            let res = self.set_transaction_fee(fee_rate, size, value)?;
-
-            // 没有额外的输入进行找零
-            if !res.0 {
-                self.change(res.1, change_address);
-                return Ok(size);
-            }
-        }
-    }
-
-    pub fn spent_all_set_fee(
-        &mut self,
-        fee_rate: litecoin::Amount,
-        spend_address: litecoin::Address,
-        address_type: LtcAddressType,
-    ) -> crate::Result<usize> {
-        // 模拟交易的大小
-        let size = signature::predict_transaction_size(
-            self.transaction.clone(),
-            spend_address.clone(),
-            address_type,
-            &None,
-        )?;
-
-        let total_input = self.utxo.total_input_amount();
-        let transaction_fee = fee_rate * size as u64;
-
-        if total_input < transaction_fee {
-            return Err(crate::UtxoError::InsufficientFee(transaction_fee.to_btc()).into());
-        }
-
-        // add spend
-        let spend = litecoin::TxOut {
-            value: total_input - transaction_fee,
-            script_pubkey: spend_address.script_pubkey(),
-        };
-        self.transaction.output.push(spend);
-
-        Ok(size)
-    }
-
-    fn set_transaction_fee(
-        &mut self,
-        fee_rate: litecoin::Amount,
-        size: usize,
-        value: litecoin::Amount,
-    ) -> crate::Result<(bool, litecoin::Amount)> {
-        // The total amount of selected UTXOs
-        let total_input = self.utxo.total_input_amount();
-
-        // transaction fee
-        let transaction_fee = fee_rate * size as u64;
-
-        // Whether there is a new input; if there is, the size of the transaction changes,
-        // and the fee needs to be recalculated
-        let mut has_new_input = false;
-        let required_amount = value + transaction_fee;
-
-        // In the case where the current input amount is insufficient
-        if total_input < required_amount {
-            // How much additional is required
-            let additional_required = required_amount - total_input;
-
-            // The total additional input
-            let mut additional_input = litecoin::Amount::from_sat(0);
-
-            // UTXOs that have not been selected
-            let available = self.utxo.available_utxo();
-
-            for utxo in available {
-                additional_input += litecoin::Amount::from_sat(utxo.value);
-
-                self.transaction.input.push(TxIn::from(utxo.clone()));
-                has_new_input = true;
-
-                // Mark this UTXO as used
-                self.utxo.tag_select(&utxo.txid, utxo.vout);
-
-                // If the additional input is sufficient
-                if additional_input >= additional_required {
-                    break;
-                }
-            }
-
-            // If all UTXOs have been iterated and there is still not enough money
-            if additional_input < additional_required {
-                return Err(crate::UtxoError::InsufficientFee(additional_required.to_btc()).into());
-            }
-        }
-        Ok((has_new_input, required_amount))
-    }
-
-    // change
-    fn change(&mut self, required_amount: Amount, change_address: litecoin::Address) {
-        let total_input = self.utxo.total_input_amount();
-        let change = total_input - required_amount;
-        if change > Amount::default() {
-            self.transaction.output.push(litecoin::TxOut {
-                value: change,
-                script_pubkey: change_address.script_pubkey(),
-            });
-        }
-    }
-
-    pub fn act_transfer_fee(&self) -> Amount {
-        let input_total = self.utxo.total_input_amount();
-
-        let out_total = self
-            .transaction
-            .output
-            .iter()
-            .map(|item| item.value)
-            .sum::<Amount>();
-
-        input_total - out_total
-    }
-
-    // 如果实际的手续费大于 给定的手续费 5倍，那么报错
-    pub fn exceeds_max_fee(&self, fee: Amount) -> bool {
-        let act_fee = self.act_transfer_fee();
-
-        act_fee > (fee * 5)
-    }
-
-    // 转账金额小于手续费
-    pub fn is_dust_tx(&self, amount: Amount, fee: Amount) -> bool {
-        amount <= (fee / EXPEND_FEE_RATE)
-    }
-
-    pub fn get_raw_transaction(&self) -> String {
-        consensus::encode::serialize_hex(&self.transaction)
-    }
+// transfer args of litecoin, built with the litecoin network parameters
+pub fn transfer_arg(
+    from: &str,
+    to: &str,
+    value: &str,
+    address_type: LtcAddressType,
+    network: chain::network::NetworkKind,
+) -> crate::Result<TransferArg> {
+    let address_type = BtcAddressType::try_from(address_type.as_ref())?;
+
+    TransferArg::new(
+        NetworkParams::litecoin(network),
+        from,
+        to,
+        value,
+        address_type,
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use wallet_types::chain::address::r#type::LtcAddressType;
 
-    use super::TransferArg;
+    use super::transfer_arg;
     use crate::ltc::utxos::{Utxo, UtxoList};
 
     #[test]
     pub fn condition_1() {
         // 选择了两个utxo 并且所选择的utxo满足了手续费的要求
         let from = "n2xfjp4NfSMWao3V119b5JEU3CKZ7jDZAK";
-        let to = "bcrt1qjx3d2sfu5v0jykpzs3a668nf26cgh9awsh7ek9";
+        let to = "rltc1qjx3d2sfu5v0jykpzs3a668nf26cgh9aww6yspm";
         let value = "0.0051";
         let network = wallet_types::chain::network::NetworkKind::Regtest;
-        let params = TransferArg::new(from, to, value, LtcAddressType::P2pkh, network).unwrap();
+        let params = transfer_arg(from, to, value, LtcAddressType::P2pkh, network).unwrap();
 
         let mut transaction_build = params.build_transaction(utxos()).unwrap();
 
@@ -327,7 +46,7 @@ mod tests {
             "select utxo {:?}",
             transaction_build.utxo.used_utxo_to_hash_map()
         );
-        let fee_rate = litecoin::Amount::from_sat(20);
+        let fee_rate = bitcoin::Amount::from_sat(20);
         let _c = transaction_build
             .change_and_fee(fee_rate, params.from, params.address_type, params.value)
             .unwrap();
@@ -338,14 +57,14 @@ mod tests {
     pub fn condition_2() {
         // 选择了两个utxo,选择的手续费utxo不满足手续费的要求，需要在额外的添加一个utxo进来
         let from = "n2xfjp4NfSMWao3V119b5JEU3CKZ7jDZAK";
-        let to = "bcrt1qjx3d2sfu5v0jykpzs3a668nf26cgh9awsh7ek9";
+        let to = "rltc1qjx3d2sfu5v0jykpzs3a668nf26cgh9aww6yspm";
         let value = "0.0051";
         let network = wallet_types::chain::network::NetworkKind::Regtest;
-        let params = TransferArg::new(from, to, value, LtcAddressType::P2pkh, network).unwrap();
+        let params = transfer_arg(from, to, value, LtcAddressType::P2pkh, network).unwrap();
 
         let mut transaction_build = params.build_transaction(utxos()).unwrap();
 
-        let fee_rate = litecoin::Amount::from_sat(2700);
+        let fee_rate = bitcoin::Amount::from_sat(2700);
         let c = transaction_build
             .change_and_fee(fee_rate, params.from, params.address_type, params.value)
             .unwrap();
@@ -1,21 +0,0 @@
-use serde::Deserialize;
-#[derive(Deserialize)]
-
-pub struct BlockHeader {
-    pub hash: String,
-    pub confirmations: u32,
-    pub height: u32,
-    pub version: u32,
-    #[serde(rename = "versionHex")]
-    pub version_hex: String,
-    pub merkleroot: String,
-    pub time: u32,
-    pub mediantime: u32,
-    pub nonce: u32,
-    pub bits: String,
-    pub difficulty: f64,
-    pub chainwork: String,
-    #[serde(rename = "nTx")]
-    pub n_tx: u32,
-    pub previousblockhash: String,
-}
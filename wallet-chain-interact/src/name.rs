@@ -0,0 +1,91 @@
+// 多链域名解析, 具体的解析在各链的 chain 中实现
+// tron 的域名(tns, `.trx`)没有统一的链上注册合约,注册合约地址由调用方传入
+use wallet_types::chain::chain::ChainCode;
+
+/// Name service picked from the top level domain of the name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum NameService {
+    // ens, also dns names imported through ensip-10 / dnssec
+    Ens,
+    // solana name service, `.sol`
+    Sns,
+    // sui name service, `.sui`
+    SuiNs,
+    // ton dns, `.ton` and `.t.me`
+    TonDns,
+    // tron name service, `.trx`
+    Tns,
+}
+
+impl NameService {
+    pub fn detect(name: &str) -> Option<Self> {
+        let name = name.trim().trim_end_matches('.').to_lowercase();
+        if !name.contains('.') {
+            return None;
+        }
+        let service = if name.ends_with(".sol") {
+            NameService::Sns
+        } else if name.ends_with(".sui") {
+            NameService::SuiNs
+        } else if name.ends_with(".ton") || name.ends_with(".t.me") {
+            NameService::TonDns
+        } else if name.ends_with(".trx") {
+            NameService::Tns
+        } else {
+            NameService::Ens
+        };
+        Some(service)
+    }
+
+    pub fn chain_code(&self) -> ChainCode {
+        match self {
+            NameService::Ens => ChainCode::Ethereum,
+            NameService::Sns => ChainCode::Solana,
+            NameService::SuiNs => ChainCode::Sui,
+            NameService::TonDns => ChainCode::Ton,
+            NameService::Tns => ChainCode::Tron,
+        }
+    }
+
+    // ton dns has no reverse records
+    pub fn supports_reverse(&self) -> bool {
+        !matches!(self, NameService::TonDns)
+    }
+}
+
+// lowercase without the trailing dot, every label has to be non empty.
+// full ensip-15 (uts-46) normalization is left to the caller
+pub fn normalize(name: &str) -> crate::Result<String> {
+    let name = name.trim().trim_end_matches('.').to_lowercase();
+
+    if name.is_empty() || name.split('.').any(|label| label.is_empty()) {
+        return Err(crate::Error::Other(format!("invalid name {}", name)));
+    }
+    if name.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(crate::Error::Other(format!("invalid name {}", name)));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(NameService::detect("vitalik.eth"), Some(NameService::Ens));
+        assert_eq!(NameService::detect("Bonfida.SOL"), Some(NameService::Sns));
+        assert_eq!(NameService::detect("example.sui"), Some(NameService::SuiNs));
+        assert_eq!(
+            NameService::detect("foundation.ton"),
+            Some(NameService::TonDns)
+        );
+        assert_eq!(NameService::detect("durov.t.me"), Some(NameService::TonDns));
+        assert_eq!(NameService::detect("0x1234"), None);
+        assert_eq!(NameService::detect("justin.trx"), Some(NameService::Tns));
+        assert_eq!(NameService::Tns.chain_code(), ChainCode::Tron);
+
+        assert_eq!(normalize(" Vitalik.ETH. ").unwrap(), "vitalik.eth");
+        assert!(normalize("a..eth").is_err());
+    }
+}
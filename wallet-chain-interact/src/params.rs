@@ -8,6 +8,8 @@ pub struct FeeResponse {
     pub energy_used: U256,
     pub energy_price: U256,
     pub fee: Option<U256>,
+    // l1 data fee of evm rollups
+    pub l1_fee: U256,
 }
 impl FeeResponse {
     pub fn new(gas_price: U256, gas_used: U256) -> Self {
@@ -17,6 +19,7 @@ impl FeeResponse {
             energy_used: U256::ZERO,
             energy_price: U256::ZERO,
             fee: None,
+            l1_fee: U256::ZERO,
         }
     }
     pub fn energy_price(mut self, energy_price: U256) -> Self {
@@ -28,17 +31,22 @@ impl FeeResponse {
         self
     }
 
+    pub fn l1_fee(mut self, l1_fee: U256) -> Self {
+        self.l1_fee = l1_fee;
+        self
+    }
+
     pub fn set_fee(mut self, fee: U256) -> Self {
         self.fee = Some(fee);
         self
     }
 
     pub fn calc_fee(&self, unit: u8) -> Result<String, wallet_utils::error::Error> {
-        let rs = self.gas_price * self.gas_used + self.energy_price * self.energy_used;
+        let rs = self.calc_fee_original()?;
         wallet_utils::unit::format_to_string(rs, unit)
     }
     pub fn calc_fee_original(&self) -> Result<U256, wallet_utils::error::Error> {
-        Ok(self.gas_price * self.gas_used + self.energy_price * self.energy_used)
+        Ok(self.gas_price * self.gas_used + self.energy_price * self.energy_used + self.l1_fee)
     }
 }
 
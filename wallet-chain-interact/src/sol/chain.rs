@@ -1,7 +1,10 @@
 use super::{
     SolFeeSetting,
     operations::{self},
-    protocol::transaction::{CommitmentConfig, Status},
+    protocol::{
+        stake::{InflationReward, VoteAccount},
+        transaction::{CommitmentConfig, Status},
+    },
     provider::Provider,
 };
 use crate::{
@@ -11,7 +14,7 @@ use crate::{
 };
 use alloy::primitives::U256;
 use solana_sdk::{
-    compute_budget, instruction::Instruction, message::Message, signer::Signer,
+    compute_budget, instruction::Instruction, message::Message, pubkey::Pubkey, signer::Signer,
     transaction::Transaction,
 };
 
@@ -62,6 +65,25 @@ impl SolanaChain {
         Ok(name.chars().filter(|c| c.is_alphanumeric()).collect())
     }
 
+    pub async fn mint_extension(
+        &self,
+        token: &str,
+    ) -> crate::Result<operations::extension::MintExtension> {
+        let mint = wallet_utils::address::parse_sol_address(token)?;
+        operations::extension::MintExtension::fetch(&self.provider, &mint).await
+    }
+
+    // token amount withheld by a token-2022 transfer fee in the current epoch,unit is the token's smallest unit
+    pub async fn token_transfer_fee(&self, token: &str, amount: u64) -> crate::Result<u64> {
+        let mint = self.mint_extension(token).await?;
+        if mint.transfer_fee.is_none() {
+            return Ok(0);
+        }
+
+        let epoch = self.provider.epoch_info().await?.epoch;
+        Ok(mint.transfer_fee(epoch, amount))
+    }
+
     pub async fn black_address(&self, token: &str, owner: &str) -> crate::Result<bool> {
         let res = self.provider.token_balance(token, owner).await?;
         if res.value.is_empty() {
@@ -116,6 +138,79 @@ impl SolanaChain {
         Ok(Some(res))
     }
 
+    // stake accounts whose withdraw authority is the owner
+    pub async fn stake_accounts(
+        &self,
+        owner: &str,
+    ) -> crate::Result<Vec<operations::stake::StakeAccount>> {
+        let filters = vec![serde_json::json!({
+            "memcmp": {
+                "offset": operations::stake::WITHDRAWER_OFFSET,
+                "bytes": owner,
+            }
+        })];
+        let accounts = self
+            .provider
+            .program_accounts(operations::stake::STAKE_PRAMS_ID, filters)
+            .await?;
+        let epoch = self.provider.epoch_info().await?.epoch;
+
+        accounts
+            .iter()
+            .map(|item| {
+                operations::stake::StakeAccount::from_account(&item.pubkey, &item.account, epoch)
+            })
+            .collect()
+    }
+
+    pub async fn stake_account(
+        &self,
+        addr: &str,
+    ) -> crate::Result<operations::stake::StakeAccount> {
+        let pubkey = wallet_utils::address::parse_sol_address(addr)?;
+        let account =
+            self.provider
+                .account_info(pubkey)
+                .await?
+                .value
+                .ok_or(crate::Error::Other(format!(
+                    "stake account {} not found",
+                    addr
+                )))?;
+        let epoch = self.provider.epoch_info().await?.epoch;
+
+        operations::stake::StakeAccount::from_account(addr, &account, epoch)
+    }
+
+    // inflation rewards of the stake account for the latest `epochs` completed epochs
+    pub async fn stake_rewards(
+        &self,
+        stake_account: &str,
+        epochs: u64,
+    ) -> crate::Result<Vec<InflationReward>> {
+        let current = self.provider.epoch_info().await?.epoch;
+        let addresses = vec![stake_account.to_string()];
+
+        let mut rewards = vec![];
+        for epoch in (current.saturating_sub(epochs)..current).rev() {
+            let res = self
+                .provider
+                .inflation_reward(&addresses, Some(epoch))
+                .await?;
+            if let Some(Some(reward)) = res.into_iter().next() {
+                rewards.push(reward);
+            }
+        }
+        Ok(rewards)
+    }
+
+    // active validators ordered by activated stake
+    pub async fn validators(&self) -> crate::Result<Vec<VoteAccount>> {
+        let mut validators = self.provider.vote_accounts().await?.current;
+        validators.sort_by(|a, b| b.activated_stake.cmp(&a.activated_stake));
+        Ok(validators)
+    }
+
     /// build empty instruction to get per signature fee
     pub async fn per_signature_fee(&self) -> crate::Result<SolFeeSetting> {
         let keypair = solana_sdk::signature::Keypair::from_base58_string(consts::TEMP_SOL_KEYPAIR);
@@ -239,3 +334,46 @@ impl SolanaChain {
         ))
     }
 }
+
+// about sns
+impl SolanaChain {
+    async fn account_data(&self, key: Pubkey) -> crate::Result<Option<Vec<u8>>> {
+        match self.provider.account_info(key).await?.value {
+            Some(info) => Ok(Some(info.data_bytes()?)),
+            None => Ok(None),
+        }
+    }
+
+    // owner of the `.sol` domain
+    pub async fn resolve_name(&self, name: &str) -> crate::Result<Option<Pubkey>> {
+        let name = crate::name::normalize(name)?;
+        let key = operations::sns::domain_key(&name)?;
+
+        match self.account_data(key).await? {
+            Some(data) => Ok(Some(operations::sns::registry_owner(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    // primary domain of the address, only returned while the address still owns it
+    pub async fn lookup_address(&self, addr: &str) -> crate::Result<Option<String>> {
+        let owner = wallet_utils::address::parse_sol_address(addr)?;
+
+        let favourite = operations::sns::favourite_domain_key(&owner)?;
+        let Some(data) = self.account_data(favourite).await? else {
+            return Ok(None);
+        };
+        let name_account = operations::sns::favourite_name_account(&data)?;
+
+        let reverse = operations::sns::reverse_key(&name_account)?;
+        let Some(data) = self.account_data(reverse).await? else {
+            return Ok(None);
+        };
+        let name = operations::sns::reverse_name(&data)?;
+
+        match self.account_data(name_account).await? {
+            Some(data) if operations::sns::registry_owner(&data)? == owner => Ok(Some(name)),
+            _ => Ok(None),
+        }
+    }
+}
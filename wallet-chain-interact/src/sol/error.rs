@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SolError {
+    #[error("token {0} is non-transferable")]
+    NonTransferable(String),
+    #[error("token account {0} requires a memo for incoming transfers")]
+    MemoRequired(String),
+    #[error("token account {0} only accepts confidential transfers")]
+    ConfidentialCreditsOnly(String),
+    #[error("unpack token extension error: {0}")]
+    UnpackExtension(String),
+    #[error("transfer hook error: {0}")]
+    TransferHook(String),
+}
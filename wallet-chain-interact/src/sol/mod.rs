@@ -1,6 +1,7 @@
 mod chain;
 pub use chain::*;
 pub mod consts;
+pub mod error;
 pub mod operations;
 mod params;
 pub use params::*;
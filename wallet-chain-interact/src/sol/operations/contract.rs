@@ -1,14 +1,14 @@
+use super::extension::{MintExtension, TokenAccountExtension, memo_instruction};
+use crate::sol::error::SolError;
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account,
+    get_associated_token_address_with_program_id, instruction::create_associated_token_account,
 };
-use wallet_utils::address;
 
 //  token param id
 pub const TOKEN_PRAMS_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 pub const META_PRAMS_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
 
 pub struct TokenTransferBuild<'a> {
-    pub program_id: solana_sdk::pubkey::Pubkey,
     pub params: &'a super::transfer::TransferOpt<'a>,
     pub authority_pubkey: solana_sdk::pubkey::Pubkey,
     pub mint_pubkey: solana_sdk::pubkey::Pubkey,
@@ -17,15 +17,12 @@ impl<'a> TokenTransferBuild<'a> {
     pub fn new(
         params: &'a super::transfer::TransferOpt,
         mint_pubkey: solana_sdk::pubkey::Pubkey,
-    ) -> crate::Result<Self> {
-        let program_id = address::parse_sol_address(TOKEN_PRAMS_ID)?;
-
-        Ok(Self {
-            program_id,
+    ) -> Self {
+        Self {
             params,
             authority_pubkey: params.from,
             mint_pubkey,
-        })
+        }
     }
 }
 
@@ -36,8 +33,19 @@ impl<'a> TokenTransferBuild<'a> {
     ) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
         let mut instruction = vec![];
 
-        let source_pubkey = get_associated_token_address(&self.params.from, &self.mint_pubkey);
-        let destination_pubkey = get_associated_token_address(&self.params.to, &self.mint_pubkey);
+        let mint = MintExtension::fetch(self.params.provider, &self.mint_pubkey).await?;
+        mint.check_transferable()?;
+
+        let source_pubkey = get_associated_token_address_with_program_id(
+            &self.params.from,
+            &self.mint_pubkey,
+            &mint.program_id,
+        );
+        let destination_pubkey = get_associated_token_address_with_program_id(
+            &self.params.to,
+            &self.mint_pubkey,
+            &mint.program_id,
+        );
 
         // Check whether the address has a token account.
         let to_account = self
@@ -45,33 +53,96 @@ impl<'a> TokenTransferBuild<'a> {
             .provider
             .account_info(destination_pubkey)
             .await?;
-        if to_account.value.is_none() {
-            instruction.push(self.associated_account_instruction());
+        match to_account.value {
+            Some(account) => {
+                let extension = TokenAccountExtension::from_account(&account)?;
+                if !extension.allow_non_confidential_credits {
+                    return Err(
+                        SolError::ConfidentialCreditsOnly(destination_pubkey.to_string()).into(),
+                    );
+                }
+                if extension.memo_required && self.params.memo.is_none() {
+                    return Err(SolError::MemoRequired(destination_pubkey.to_string()).into());
+                }
+            }
+            None => instruction.push(self.associated_account_instruction(&mint.program_id)),
         }
 
-        let transfer = spl_token_2022::instruction::transfer_checked(
-            &self.program_id,
-            &source_pubkey,
-            &self.mint_pubkey,
-            &destination_pubkey,
-            &self.authority_pubkey,
-            &[],
-            self.params.value,
-            self.params.decimal,
-        )
+        // the memo must directly precede the transfer
+        if let Some(memo) = self.params.memo.as_ref() {
+            instruction.push(memo_instruction(memo, &self.authority_pubkey)?);
+        }
+
+        let mut transfer = if mint.transfer_fee.is_some() {
+            let epoch = self.params.provider.epoch_info().await?.epoch;
+            let fee = mint.transfer_fee(epoch, self.params.value);
+
+            spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee(
+                &mint.program_id,
+                &source_pubkey,
+                &self.mint_pubkey,
+                &destination_pubkey,
+                &self.authority_pubkey,
+                &[],
+                self.params.value,
+                self.params.decimal,
+                fee,
+            )
+        } else {
+            spl_token_2022::instruction::transfer_checked(
+                &mint.program_id,
+                &source_pubkey,
+                &self.mint_pubkey,
+                &destination_pubkey,
+                &self.authority_pubkey,
+                &[],
+                self.params.value,
+                self.params.decimal,
+            )
+        }
         .map_err(|e| crate::Error::Other(format!("build transfer instruction error:{}", e)))?;
+
+        // append the accounts required by the transfer hook program
+        if mint.transfer_hook_program.is_some() {
+            let provider = self.params.provider;
+            let fetch_account_data = |pubkey: solana_sdk::pubkey::Pubkey| async move {
+                match provider.account_info(pubkey).await {
+                    Ok(res) => match res.value {
+                        Some(account) => account.data_bytes().map(Some),
+                        None => Ok(None),
+                    },
+                    Err(e) => Err(e),
+                }
+                .map_err(|e| e.to_string().into())
+            };
+
+            spl_token_2022::offchain::add_extra_account_metas(
+                &mut transfer,
+                &source_pubkey,
+                &self.mint_pubkey,
+                &destination_pubkey,
+                &self.authority_pubkey,
+                self.params.value,
+                fetch_account_data,
+            )
+            .await
+            .map_err(|e| SolError::TransferHook(e.to_string()))?;
+        }
         instruction.push(transfer);
 
         Ok(instruction)
     }
 
     // associated token account instruction
-    pub fn associated_account_instruction(&self) -> solana_sdk::instruction::Instruction {
+    pub fn associated_account_instruction(
+        &self,
+        token_program_id: &solana_sdk::pubkey::Pubkey,
+    ) -> solana_sdk::instruction::Instruction {
         create_associated_token_account(
             &self.authority_pubkey,
             &self.params.to,
             &self.mint_pubkey,
-            &self.program_id,
+            token_program_id,
         )
     }
 }
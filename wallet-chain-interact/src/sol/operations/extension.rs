@@ -0,0 +1,264 @@
+use crate::sol::{error::SolError, protocol::account::AccountInfo, provider::Provider};
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    confidential_transfer::ConfidentialTransferAccount, memo_transfer::MemoTransfer,
+    non_transferable::NonTransferable, transfer_fee::TransferFeeConfig, transfer_hook,
+};
+use spl_token_2022::state::{Account, Mint};
+use wallet_utils::address;
+
+//  token 2022 program id
+pub const TOKEN_2022_PRAMS_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EHFLwKqGEz5G3rE4TZk2M";
+//  memo program id
+pub const MEMO_PRAMS_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Mint state with the token-2022 extensions that affect a transfer.
+/// Legacy spl-token mints are parsed the same way and simply carry no extensions.
+#[derive(Debug, Clone)]
+pub struct MintExtension {
+    pub mint: Pubkey,
+    // owner program of the mint, spl-token or token-2022
+    pub program_id: Pubkey,
+    pub decimals: u8,
+    pub extensions: Vec<ExtensionType>,
+    pub transfer_fee: Option<TransferFeeConfig>,
+    pub transfer_hook_program: Option<Pubkey>,
+    pub non_transferable: bool,
+}
+
+impl MintExtension {
+    pub async fn fetch(provider: &Provider, mint: &Pubkey) -> crate::Result<Self> {
+        let account = provider
+            .account_info(*mint)
+            .await?
+            .value
+            .ok_or(crate::Error::Other(format!(
+                "mint account {} not found",
+                mint
+            )))?;
+
+        Self::from_account(mint, &account)
+    }
+
+    pub fn from_account(mint: &Pubkey, account: &AccountInfo) -> crate::Result<Self> {
+        let program_id = address::parse_sol_address(&account.owner)?;
+        let data = account.data_bytes()?;
+
+        let state = StateWithExtensions::<Mint>::unpack(&data)
+            .map_err(|e| SolError::UnpackExtension(e.to_string()))?;
+
+        let extensions = state
+            .get_extension_types()
+            .map_err(|e| SolError::UnpackExtension(e.to_string()))?;
+
+        Ok(Self {
+            mint: *mint,
+            program_id,
+            decimals: state.base.decimals,
+            extensions,
+            transfer_fee: state.get_extension::<TransferFeeConfig>().ok().copied(),
+            transfer_hook_program: transfer_hook::get_program_id(&state),
+            non_transferable: state.get_extension::<NonTransferable>().is_ok(),
+        })
+    }
+
+    pub fn is_token_2022(&self) -> bool {
+        self.program_id.to_string() == TOKEN_2022_PRAMS_ID
+    }
+
+    pub fn check_transferable(&self) -> crate::Result<()> {
+        if self.non_transferable {
+            return Err(SolError::NonTransferable(self.mint.to_string()).into());
+        }
+        Ok(())
+    }
+
+    // the amount withheld by the mint when transferring `amount` in `epoch`, unit is the token's smallest unit
+    pub fn transfer_fee(&self, epoch: u64, amount: u64) -> u64 {
+        self.transfer_fee
+            .and_then(|config| config.calculate_epoch_fee(epoch, amount))
+            .unwrap_or(0)
+    }
+
+    // size of a newly created associated token account for this mint
+    pub fn account_len(&self) -> crate::Result<usize> {
+        if !self.is_token_2022() {
+            return Ok(<Account as solana_sdk::program_pack::Pack>::LEN);
+        }
+
+        let mut extensions = ExtensionType::get_required_init_account_extensions(&self.extensions);
+        // the associated token program always initializes token-2022 accounts as immutable owner
+        if !extensions.contains(&ExtensionType::ImmutableOwner) {
+            extensions.push(ExtensionType::ImmutableOwner);
+        }
+
+        let len = ExtensionType::try_calculate_account_len::<Account>(&extensions)
+            .map_err(|e| SolError::UnpackExtension(e.to_string()))?;
+        Ok(len)
+    }
+}
+
+/// Extensions of an existing token account that restrict incoming transfers.
+#[derive(Debug, Default, Clone)]
+pub struct TokenAccountExtension {
+    pub memo_required: bool,
+    pub allow_non_confidential_credits: bool,
+}
+
+impl TokenAccountExtension {
+    pub fn from_account(account: &AccountInfo) -> crate::Result<Self> {
+        let data = account.data_bytes()?;
+
+        let state = StateWithExtensions::<Account>::unpack(&data)
+            .map_err(|e| SolError::UnpackExtension(e.to_string()))?;
+
+        let memo_required = state
+            .get_extension::<MemoTransfer>()
+            .map(|memo| bool::from(memo.require_incoming_transfer_memos))
+            .unwrap_or(false);
+
+        let allow_non_confidential_credits = state
+            .get_extension::<ConfidentialTransferAccount>()
+            .map(|confidential| bool::from(confidential.allow_non_confidential_credits))
+            .unwrap_or(true);
+
+        Ok(Self {
+            memo_required,
+            allow_non_confidential_credits,
+        })
+    }
+}
+
+// memo instruction,signed by the transfer authority
+pub fn memo_instruction(
+    memo: &str,
+    signer: &Pubkey,
+) -> crate::Result<solana_sdk::instruction::Instruction> {
+    let program_id = address::parse_sol_address(MEMO_PRAMS_ID)?;
+
+    Ok(solana_sdk::instruction::Instruction::new_with_bytes(
+        program_id,
+        memo.as_bytes(),
+        vec![solana_sdk::instruction::AccountMeta::new_readonly(
+            *signer, true,
+        )],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sol::operations::contract::TOKEN_PRAMS_ID;
+
+    // Mint: mint_authority COption<Pubkey>, supply u64, decimals u8, is_initialized bool,
+    // freeze_authority COption<Pubkey>
+    fn base_mint(decimals: u8) -> Vec<u8> {
+        let mut data = vec![];
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+        data.push(decimals);
+        data.push(1);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&[0u8; 32]);
+        data
+    }
+
+    // token-2022 layout: base mint padded to the account length, account type 1 (mint), then tlv entries
+    fn extended_mint(decimals: u8, tlv: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = base_mint(decimals);
+        data.resize(165, 0);
+        data.push(1);
+        for (extension, value) in tlv {
+            data.extend_from_slice(&extension.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    // TransferFeeConfig: two authorities, withheld amount, older and newer TransferFee
+    fn transfer_fee_config(older: (u64, u64, u16), newer: (u64, u64, u16)) -> Vec<u8> {
+        let mut data = vec![0u8; 64];
+        data.extend_from_slice(&0u64.to_le_bytes());
+        for (epoch, maximum_fee, basis_points) in [older, newer] {
+            data.extend_from_slice(&epoch.to_le_bytes());
+            data.extend_from_slice(&maximum_fee.to_le_bytes());
+            data.extend_from_slice(&basis_points.to_le_bytes());
+        }
+        data
+    }
+
+    fn account(data: &[u8], owner: &str) -> AccountInfo {
+        AccountInfo {
+            data: vec![wallet_utils::bytes_to_base64(data), "base64".to_string()],
+            executable: false,
+            lamports: 1_461_600,
+            owner: owner.to_string(),
+            rent_epoch: u64::MAX,
+            space: data.len() as u64,
+        }
+    }
+
+    #[test]
+    fn test_legacy_mint() {
+        let mint = Pubkey::new_unique();
+        let info = account(&base_mint(6), TOKEN_PRAMS_ID);
+
+        let ext = MintExtension::from_account(&mint, &info).unwrap();
+        assert!(!ext.is_token_2022());
+        assert_eq!(ext.decimals, 6);
+        assert!(ext.extensions.is_empty());
+        assert!(ext.transfer_fee.is_none());
+        assert_eq!(ext.transfer_fee(100, 1_000_000), 0);
+        assert_eq!(ext.account_len().unwrap(), 165);
+        assert!(ext.check_transferable().is_ok());
+    }
+
+    #[test]
+    fn test_token_2022_mint_without_fee() {
+        let mint = Pubkey::new_unique();
+        let info = account(&base_mint(9), TOKEN_2022_PRAMS_ID);
+
+        let ext = MintExtension::from_account(&mint, &info).unwrap();
+        assert!(ext.is_token_2022());
+        assert!(ext.transfer_fee.is_none());
+        assert_eq!(ext.transfer_fee(100, 1_000_000), 0);
+        // base account, account type and the immutable owner tlv header
+        assert_eq!(ext.account_len().unwrap(), 165 + 1 + 4);
+
+        // NonTransferable carries no data
+        let data = extended_mint(9, &[(9, vec![])]);
+        let ext = MintExtension::from_account(&mint, &account(&data, TOKEN_2022_PRAMS_ID)).unwrap();
+        assert!(ext.non_transferable);
+        assert!(ext.check_transferable().is_err());
+    }
+
+    #[test]
+    fn test_transfer_fee() {
+        let mint = Pubkey::new_unique();
+        // 0.5% capped at 5000 until epoch 500, then 1% capped at 1_000_000
+        let config = transfer_fee_config((0, 5_000, 50), (500, 1_000_000, 100));
+        let data = extended_mint(6, &[(1, config)]);
+
+        let ext = MintExtension::from_account(&mint, &account(&data, TOKEN_2022_PRAMS_ID)).unwrap();
+        assert_eq!(ext.extensions, vec![ExtensionType::TransferFeeConfig]);
+        assert!(ext.transfer_fee.is_some());
+
+        assert_eq!(ext.transfer_fee(100, 1_000), 5);
+        // the fee rounds up
+        assert_eq!(ext.transfer_fee(100, 1), 1);
+        assert_eq!(ext.transfer_fee(100, 201), 2);
+        assert_eq!(ext.transfer_fee(100, 0), 0);
+        // capped at the maximum fee
+        assert_eq!(ext.transfer_fee(100, 10_000_000), 5_000);
+
+        // the newer fee applies from its epoch
+        assert_eq!(ext.transfer_fee(500, 1_000), 10);
+        assert_eq!(ext.transfer_fee(600, 1_000_000_000), 1_000_000);
+
+        // accounts of the mint carry TransferFeeAmount (8 bytes) and ImmutableOwner
+        assert_eq!(ext.account_len().unwrap(), 165 + 1 + (4 + 8) + 4);
+    }
+}
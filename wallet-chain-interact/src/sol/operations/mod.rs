@@ -1,5 +1,8 @@
 pub mod contract;
+pub mod extension;
 pub mod multisig;
+pub mod sns;
+pub mod stake;
 pub mod transfer;
 
 // to build instruction
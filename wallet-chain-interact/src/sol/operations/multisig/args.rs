@@ -100,15 +100,110 @@ impl MultisigCreateArgsV2 {
     }
 }
 
-#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug, serde::Deserialize)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug, serde::Deserialize, Clone)]
 pub struct Member {
     pub key: solana_sdk::pubkey::Pubkey,
     pub permissions: Permissions,
 }
-#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug, serde::Deserialize)]
+#[derive(
+    borsh::BorshSerialize, borsh::BorshDeserialize, Debug, serde::Deserialize, Clone, Copy,
+)]
 pub struct Permissions {
     pub mask: u8,
 }
+impl Permissions {
+    pub const INITIATE: u8 = 1 << 0;
+    pub const VOTE: u8 = 1 << 1;
+    pub const EXECUTE: u8 = 1 << 2;
+
+    pub fn all() -> Self {
+        Self {
+            mask: Self::INITIATE | Self::VOTE | Self::EXECUTE,
+        }
+    }
+
+    pub fn has(&self, permission: u8) -> bool {
+        self.mask & permission == permission
+    }
+}
+
+/// Multisig config change,the variant order must match the squads v4 program.
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug, Clone)]
+pub enum ConfigAction {
+    /// Add a new member to the multisig.
+    AddMember { new_member: Member },
+    /// Remove a member from the multisig.
+    RemoveMember {
+        old_member: solana_sdk::pubkey::Pubkey,
+    },
+    /// Change the `threshold` of the multisig.
+    ChangeThreshold { new_threshold: u16 },
+    /// Change the `time_lock` of the multisig.
+    SetTimeLock { new_time_lock: u32 },
+    /// Add a new spending limit to the multisig.
+    AddSpendingLimit {
+        /// Key that is used to seed the SpendingLimit PDA.
+        create_key: solana_sdk::pubkey::Pubkey,
+        /// The index of the vault that the spending limit is for.
+        vault_index: u8,
+        /// The token mint the spending limit is for,`Pubkey::default()` for SOL.
+        mint: solana_sdk::pubkey::Pubkey,
+        /// The amount of tokens that can be spent in a period.
+        amount: u64,
+        /// The reset period of the spending limit.
+        period: Period,
+        /// Members of the multisig that can use the spending limit.
+        members: Vec<solana_sdk::pubkey::Pubkey>,
+        /// The destination addresses the spending limit is allowed to send funds to,empty for any.
+        destinations: Vec<solana_sdk::pubkey::Pubkey>,
+    },
+    /// Remove a spending limit from the multisig.
+    RemoveSpendingLimit {
+        spending_limit: solana_sdk::pubkey::Pubkey,
+    },
+    /// Set the `rent_collector` config parameter of the multisig.
+    SetRentCollector {
+        new_rent_collector: Option<solana_sdk::pubkey::Pubkey>,
+    },
+}
+
+/// The reset period of the spending limit.
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    /// The spending limit can only be used once.
+    OneTime,
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug)]
+pub struct ConfigTransactionCreateArgs {
+    pub actions: Vec<ConfigAction>,
+    pub memo: Option<String>,
+}
+impl ConfigTransactionCreateArgs {
+    pub fn new(actions: Vec<ConfigAction>) -> Self {
+        Self {
+            actions,
+            memo: None,
+        }
+    }
+
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        borsh::to_vec(self).map_err(|e| crate::Error::Other(e.to_string()))
+    }
+
+    pub fn size(&self) -> usize {
+        let actions_len = get_instance_packed_len(&self.actions).unwrap_or_default();
+        8 +   // anchor account discriminator
+        32 +  // multisig
+        32 +  // creator
+        8 +   // index
+        1 +   // bump
+        actions_len // actions vec
+    }
+}
 
 #[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Debug)]
 pub struct ProposalCreateArgs {
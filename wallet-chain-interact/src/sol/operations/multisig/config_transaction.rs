@@ -0,0 +1,377 @@
+use super::{
+    MULTISIG_PROGRAM_ID,
+    args::{
+        ConfigAction, ConfigTransactionCreateArgs, Member, Period, Permissions, ProposalCreateArgs,
+        ProposalVoteArgs,
+    },
+    pda,
+};
+use crate::sol::{
+    operations::{SolInstructionOperation, SolTransferOperation},
+    provider::Provider,
+};
+use solana_sdk::{instruction::AccountMeta, signer::Signer as _, system_program};
+use wallet_utils::address;
+
+// config transaction of the multisig: members, threshold, time lock, spending limits and rent collector
+pub struct ConfigTransactionOpt<'a> {
+    pub multisig_pda: solana_sdk::pubkey::Pubkey,
+    pub creator: solana_sdk::pubkey::Pubkey,
+    pub program_id: solana_sdk::pubkey::Pubkey,
+    pub actions: Vec<ConfigAction>,
+    pub provider: &'a Provider,
+}
+
+impl<'a> ConfigTransactionOpt<'a> {
+    pub fn new(multisig_pda: &str, creator: &str, provider: &'a Provider) -> crate::Result<Self> {
+        Ok(Self {
+            multisig_pda: address::parse_sol_address(multisig_pda)?,
+            creator: address::parse_sol_address(creator)?,
+            program_id: address::parse_sol_address(MULTISIG_PROGRAM_ID)?,
+            actions: vec![],
+            provider,
+        })
+    }
+
+    pub fn add_member(mut self, member: &str, permissions: Permissions) -> crate::Result<Self> {
+        self.actions.push(ConfigAction::AddMember {
+            new_member: Member {
+                key: address::parse_sol_address(member)?,
+                permissions,
+            },
+        });
+        Ok(self)
+    }
+
+    pub fn remove_member(mut self, member: &str) -> crate::Result<Self> {
+        self.actions.push(ConfigAction::RemoveMember {
+            old_member: address::parse_sol_address(member)?,
+        });
+        Ok(self)
+    }
+
+    pub fn change_threshold(mut self, threshold: u16) -> Self {
+        self.actions.push(ConfigAction::ChangeThreshold {
+            new_threshold: threshold,
+        });
+        self
+    }
+
+    // unit is seconds
+    pub fn set_time_lock(mut self, time_lock: u32) -> Self {
+        self.actions.push(ConfigAction::SetTimeLock {
+            new_time_lock: time_lock,
+        });
+        self
+    }
+
+    // mint is None for SOL, empty destinations allow any destination
+    pub fn add_spending_limit(
+        mut self,
+        vault_index: u8,
+        mint: Option<&str>,
+        amount: u64,
+        period: Period,
+        members: &[String],
+        destinations: &[String],
+    ) -> crate::Result<Self> {
+        let mint = match mint {
+            Some(mint) => address::parse_sol_address(mint)?,
+            None => solana_sdk::pubkey::Pubkey::default(),
+        };
+        let members = members
+            .iter()
+            .map(|m| address::parse_sol_address(m))
+            .collect::<Result<Vec<_>, _>>()?;
+        let destinations = destinations
+            .iter()
+            .map(|d| address::parse_sol_address(d))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.actions.push(ConfigAction::AddSpendingLimit {
+            // only seeds the spending limit pda
+            create_key: solana_sdk::signature::Keypair::new().pubkey(),
+            vault_index,
+            mint,
+            amount,
+            period,
+            members,
+            destinations,
+        });
+        Ok(self)
+    }
+
+    pub fn remove_spending_limit(mut self, spending_limit: &str) -> crate::Result<Self> {
+        self.actions.push(ConfigAction::RemoveSpendingLimit {
+            spending_limit: address::parse_sol_address(spending_limit)?,
+        });
+        Ok(self)
+    }
+
+    // None disables reclaiming the rent of closed transactions
+    pub fn set_rent_collector(mut self, rent_collector: Option<&str>) -> crate::Result<Self> {
+        let new_rent_collector = rent_collector.map(address::parse_sol_address).transpose()?;
+        self.actions
+            .push(ConfigAction::SetRentCollector { new_rent_collector });
+        Ok(self)
+    }
+
+    fn args(&self) -> ConfigTransactionCreateArgs {
+        ConfigTransactionCreateArgs::new(self.actions.clone())
+    }
+
+    fn config_transaction_instruction(
+        &self,
+        transaction_index: u64,
+    ) -> crate::Result<solana_sdk::instruction::Instruction> {
+        let (tx_pda, _) =
+            pda::get_transaction_pda(&self.multisig_pda, transaction_index, &self.program_id);
+
+        let mut data = super::get_selector("config_transaction_create");
+        data.extend(self.args().to_bytes()?);
+
+        Ok(solana_sdk::instruction::Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.multisig_pda, false),
+                AccountMeta::new(tx_pda, false),
+                AccountMeta::new_readonly(self.creator, true),
+                AccountMeta::new(self.creator, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        })
+    }
+
+    fn proposal_instruction(
+        &self,
+        transaction_index: u64,
+    ) -> crate::Result<solana_sdk::instruction::Instruction> {
+        let (proposal_pda, _) =
+            pda::get_proposal_pda(&self.multisig_pda, transaction_index, &self.program_id);
+
+        let mut data = super::get_selector("proposal_create");
+        data.extend(ProposalCreateArgs::new(transaction_index).to_bytes()?);
+
+        Ok(solana_sdk::instruction::Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new_readonly(self.creator, true),
+                AccountMeta::new(self.creator, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SolInstructionOperation for ConfigTransactionOpt<'_> {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        if self.actions.is_empty() {
+            return Err(crate::Error::Other("config actions is empty".to_string()));
+        }
+
+        let multisig = self.provider.get_multisig(&self.multisig_pda).await?;
+        if !multisig.is_member(&self.creator) {
+            return Err(crate::Error::Other(format!(
+                "{} is not a member of the multisig",
+                self.creator
+            )));
+        }
+        multisig.validate_actions(&self.actions)?;
+
+        let transaction_index = multisig.transaction_index + 1;
+
+        Ok(vec![
+            self.config_transaction_instruction(transaction_index)?,
+            self.proposal_instruction(transaction_index)?,
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl SolTransferOperation for ConfigTransactionOpt<'_> {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.creator)
+    }
+
+    // rent of the config transaction and proposal accounts
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        let multisig = self.provider.get_multisig(&self.multisig_pda).await?;
+
+        let transaction_rent = self
+            .provider
+            .get_minimum_balance_for_rent(self.args().size() as u64)
+            .await?;
+        let proposal_rent = self
+            .provider
+            .get_minimum_balance_for_rent(ProposalCreateArgs::size(multisig.members.len()) as u64)
+            .await?;
+
+        Ok(Some(transaction_rent + proposal_rent))
+    }
+}
+
+// execute an approved config transaction
+pub struct ExecConfigTransactionOpt {
+    pub executor: solana_sdk::pubkey::Pubkey,
+    pub multisig_pda: solana_sdk::pubkey::Pubkey,
+    pub transaction_index: u64,
+    pub program_id: solana_sdk::pubkey::Pubkey,
+    // spending limit accounts created or closed by the actions
+    pub spending_limits: Vec<solana_sdk::pubkey::Pubkey>,
+}
+
+impl ExecConfigTransactionOpt {
+    pub fn new(executor: &str, multisig_pda: &str, transaction_index: u64) -> crate::Result<Self> {
+        Ok(Self {
+            executor: address::parse_sol_address(executor)?,
+            multisig_pda: address::parse_sol_address(multisig_pda)?,
+            transaction_index,
+            program_id: address::parse_sol_address(MULTISIG_PROGRAM_ID)?,
+            spending_limits: vec![],
+        })
+    }
+
+    // the actions of the config transaction,spending limits are passed as remaining accounts
+    pub fn with_actions(mut self, actions: &[ConfigAction]) -> Self {
+        self.spending_limits = actions
+            .iter()
+            .filter_map(|action| match action {
+                ConfigAction::AddSpendingLimit { create_key, .. } => Some(
+                    pda::get_spending_limit_pda(&self.multisig_pda, create_key, &self.program_id).0,
+                ),
+                ConfigAction::RemoveSpendingLimit { spending_limit } => Some(*spending_limit),
+                _ => None,
+            })
+            .collect();
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl SolInstructionOperation for ExecConfigTransactionOpt {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        let (proposal_pda, _) =
+            pda::get_proposal_pda(&self.multisig_pda, self.transaction_index, &self.program_id);
+        let (tx_pda, _) =
+            pda::get_transaction_pda(&self.multisig_pda, self.transaction_index, &self.program_id);
+
+        let data = super::get_selector("config_transaction_execute");
+
+        // the executor pays for the multisig account realloc when members are added
+        let mut accounts = vec![
+            AccountMeta::new(self.multisig_pda, false),
+            AccountMeta::new_readonly(self.executor, true),
+            AccountMeta::new(proposal_pda, false),
+            AccountMeta::new_readonly(tx_pda, false),
+            AccountMeta::new(self.executor, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+        accounts.extend(
+            self.spending_limits
+                .iter()
+                .map(|spending_limit| AccountMeta::new(*spending_limit, false)),
+        );
+
+        let instruction = solana_sdk::instruction::Instruction {
+            program_id: self.program_id,
+            accounts,
+            data,
+        };
+
+        Ok(vec![instruction])
+    }
+}
+
+#[async_trait::async_trait]
+impl SolTransferOperation for ExecConfigTransactionOpt {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.executor)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ProposalVote {
+    Approve,
+    Reject,
+    // cancel an approved proposal
+    Cancel,
+}
+
+impl ProposalVote {
+    fn method(&self) -> &'static str {
+        match self {
+            ProposalVote::Approve => "proposal_approve",
+            ProposalVote::Reject => "proposal_reject",
+            ProposalVote::Cancel => "proposal_cancel",
+        }
+    }
+}
+
+// vote on a proposal of the vault or config transaction
+pub struct ProposalVoteOpt {
+    pub member: solana_sdk::pubkey::Pubkey,
+    pub multisig_pda: solana_sdk::pubkey::Pubkey,
+    pub transaction_index: u64,
+    pub vote: ProposalVote,
+    pub program_id: solana_sdk::pubkey::Pubkey,
+}
+
+impl ProposalVoteOpt {
+    pub fn new(
+        member: &str,
+        multisig_pda: &str,
+        transaction_index: u64,
+        vote: ProposalVote,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            member: address::parse_sol_address(member)?,
+            multisig_pda: address::parse_sol_address(multisig_pda)?,
+            transaction_index,
+            vote,
+            program_id: address::parse_sol_address(MULTISIG_PROGRAM_ID)?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SolInstructionOperation for ProposalVoteOpt {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        let (proposal_pda, _) =
+            pda::get_proposal_pda(&self.multisig_pda, self.transaction_index, &self.program_id);
+
+        let mut data = super::get_selector(self.vote.method());
+        data.extend(ProposalVoteArgs::new().to_bytes()?);
+
+        let instruction = solana_sdk::instruction::Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.multisig_pda, false),
+                AccountMeta::new(self.member, true),
+                AccountMeta::new(proposal_pda, false),
+            ],
+            data,
+        };
+
+        Ok(vec![instruction])
+    }
+}
+
+#[async_trait::async_trait]
+impl SolTransferOperation for ProposalVoteOpt {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.member)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
@@ -1,9 +1,11 @@
 pub mod account;
 pub mod args;
 pub mod compiled_keys;
+pub mod config_transaction;
 pub mod pda;
 pub mod program;
 pub mod small_vec;
+pub mod state;
 pub mod transfer;
 pub mod vault_transaction;
 
@@ -20,6 +22,16 @@ pub fn get_selector(method: &str) -> Vec<u8> {
     discriminator.to_vec()
 }
 
+// anchor account discriminator
+pub fn get_account_discriminator(name: &str) -> [u8; 8] {
+    let preimage = format!("account:{}", name);
+    let hash = wallet_utils::sha256(preimage.as_bytes());
+
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
 // 多签程序是否进行创建了账号
 pub fn sods4_v4_has_create_account(data: &str) -> bool {
     let bytes = solana_sdk::bs58::decode(data)
@@ -7,7 +7,7 @@ pub const SEED_TRANSACTION: &[u8] = b"transaction";
 // pub const SEED_BATCH_TRANSACTION: &[u8] = b"batch_transaction";
 pub const SEED_VAULT: &[u8] = b"vault";
 // pub const SEED_EPHEMERAL_SIGNER: &[u8] = b"ephemeral_signer";
-// pub const SEED_SPENDING_LIMIT: &[u8] = b"spending_limit";
+pub const SEED_SPENDING_LIMIT: &[u8] = b"spending_limit";
 
 pub fn get_program_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SEED_PREFIX, SEED_PROGRAM_CONFIG], program_id)
@@ -48,6 +48,22 @@ pub fn get_transaction_pda(
     )
 }
 
+pub fn get_spending_limit_pda(
+    multisig_pda: &Pubkey,
+    create_key: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig_pda.to_bytes().as_ref(),
+            SEED_SPENDING_LIMIT,
+            create_key.to_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
 pub fn get_proposal_pda(
     multisig_pda: &Pubkey,
     transaction_index: u64,
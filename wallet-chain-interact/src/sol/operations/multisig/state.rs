@@ -0,0 +1,537 @@
+use super::{
+    args::{ConfigAction, Member, Permissions},
+    vault_transaction::VaultTransactionMessage,
+};
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+// decode an anchor account,checking and skipping the 8 bytes discriminator
+fn decode_account<T: BorshDeserialize>(name: &str, data: &[u8]) -> crate::Result<T> {
+    let discriminator = super::get_account_discriminator(name);
+    if data.len() < 8 || data[..8] != discriminator[..] {
+        return Err(crate::ParseErr::SolMultisigArgs(format!(
+            "account is not a squads {} account",
+            name
+        ))
+        .into());
+    }
+
+    // accounts are allocated with extra space, so trailing bytes are ignored
+    T::deserialize(&mut &data[8..])
+        .map_err(|e| crate::ParseErr::SolMultisigArgs(e.to_string()).into())
+}
+
+/// On-chain squads v4 multisig account.
+#[derive(BorshDeserialize, Debug)]
+pub struct Multisig {
+    /// Key that is used to seed the multisig PDA.
+    pub create_key: Pubkey,
+    /// The authority that can change the multisig config,`Pubkey::default()` for an autonomous multisig.
+    pub config_authority: Pubkey,
+    /// Threshold for signatures.
+    pub threshold: u16,
+    /// How many seconds must pass between transaction voting settlement and execution.
+    pub time_lock: u32,
+    /// Last transaction index. 0 means no transactions have been created.
+    pub transaction_index: u64,
+    /// Last stale transaction index. All transactions up until this index are stale.
+    pub stale_transaction_index: u64,
+    /// The address where the rent of executed, rejected or cancelled transactions can be reclaimed.
+    pub rent_collector: Option<Pubkey>,
+    /// Bump for the multisig PDA seed.
+    pub bump: u8,
+    /// Members of the multisig.
+    pub members: Vec<Member>,
+}
+
+impl Multisig {
+    pub fn from_bytes(data: &[u8]) -> crate::Result<Self> {
+        decode_account("Multisig", data)
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.iter().any(|m| &m.key == key)
+    }
+
+    // config changes of a controlled multisig go through the config authority,not a config transaction
+    pub fn is_controlled(&self) -> bool {
+        self.config_authority != Pubkey::default()
+    }
+
+    pub fn voters(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|m| m.permissions.has(Permissions::VOTE))
+            .count()
+    }
+
+    // validate the multisig stays usable after the config actions are applied
+    pub fn validate_actions(&self, actions: &[ConfigAction]) -> crate::Result<()> {
+        if self.is_controlled() {
+            return Err(crate::Error::Other(format!(
+                "multisig is controlled by config authority {},config transactions are not allowed",
+                self.config_authority
+            )));
+        }
+
+        let mut members = self.members.clone();
+        let mut threshold = self.threshold;
+
+        for action in actions {
+            match action {
+                ConfigAction::AddMember { new_member } => {
+                    if members.iter().any(|m| m.key == new_member.key) {
+                        return Err(crate::Error::Other(format!(
+                            "{} is already a member",
+                            new_member.key
+                        )));
+                    }
+                    members.push(new_member.clone());
+                }
+                ConfigAction::RemoveMember { old_member } => {
+                    let len = members.len();
+                    members.retain(|m| &m.key != old_member);
+                    if members.len() == len {
+                        return Err(crate::Error::Other(format!(
+                            "{} is not a member",
+                            old_member
+                        )));
+                    }
+                }
+                ConfigAction::ChangeThreshold { new_threshold } => threshold = *new_threshold,
+                ConfigAction::AddSpendingLimit {
+                    amount, members, ..
+                } => {
+                    if *amount == 0 || members.is_empty() {
+                        return Err(crate::Error::Other(
+                            "spending limit needs an amount and members".to_string(),
+                        ));
+                    }
+                }
+                ConfigAction::SetTimeLock { .. }
+                | ConfigAction::RemoveSpendingLimit { .. }
+                | ConfigAction::SetRentCollector { .. } => {}
+            }
+        }
+
+        let voters = members
+            .iter()
+            .filter(|m| m.permissions.has(Permissions::VOTE))
+            .count();
+        if threshold == 0 || threshold as usize > voters {
+            return Err(crate::Error::Other(format!(
+                "invalid threshold {},the multisig has {} voters",
+                threshold, voters
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ProposalStatus {
+    /// Proposal is in the draft mode and can be voted on.
+    Draft { timestamp: i64 },
+    /// Proposal is live and ready for voting.
+    Active { timestamp: i64 },
+    /// Proposal has been rejected.
+    Rejected { timestamp: i64 },
+    /// Proposal has been approved and is pending execution.
+    Approved { timestamp: i64 },
+    /// Deprecated, kept for the layout compatibility.
+    Executing,
+    /// Proposal has been executed.
+    Executed { timestamp: i64 },
+    /// Proposal has been cancelled.
+    Cancelled { timestamp: i64 },
+}
+
+/// On-chain squads v4 proposal account.
+#[derive(BorshDeserialize, Debug)]
+pub struct Proposal {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+    /// Index of the multisig transaction this proposal is associated with.
+    pub transaction_index: u64,
+    /// The status of the transaction.
+    pub status: ProposalStatus,
+    /// PDA bump.
+    pub bump: u8,
+    /// Keys that have approved/signed.
+    pub approved: Vec<Pubkey>,
+    /// Keys that have rejected.
+    pub rejected: Vec<Pubkey>,
+    /// Keys that have cancelled (Approved only).
+    pub cancelled: Vec<Pubkey>,
+}
+
+impl Proposal {
+    pub fn from_bytes(data: &[u8]) -> crate::Result<Self> {
+        decode_account("Proposal", data)
+    }
+}
+
+/// On-chain squads v4 vault transaction account.
+#[derive(BorshDeserialize, Debug)]
+pub struct VaultTransaction {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+    /// Member of the Multisig who submitted the transaction.
+    pub creator: Pubkey,
+    /// Index of this transaction within the multisig.
+    pub index: u64,
+    /// bump for the transaction seeds.
+    pub bump: u8,
+    /// Index of the vault this transaction belongs to.
+    pub vault_index: u8,
+    /// Derivation bump of the vault PDA this transaction belongs to.
+    pub vault_bump: u8,
+    /// Derivation bumps for additional signers.
+    pub ephemeral_signer_bumps: Vec<u8>,
+    /// data required for executing the transaction.
+    pub message: VaultTransactionMessage,
+}
+
+impl VaultTransaction {
+    pub fn from_bytes(data: &[u8]) -> crate::Result<Self> {
+        decode_account("VaultTransaction", data)
+    }
+}
+
+/// On-chain squads v4 config transaction account.
+#[derive(BorshDeserialize, Debug)]
+pub struct ConfigTransaction {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+    /// Member of the Multisig who submitted the transaction.
+    pub creator: Pubkey,
+    /// Index of this transaction within the multisig.
+    pub index: u64,
+    /// bump for the transaction seeds.
+    pub bump: u8,
+    /// Action to be performed on the multisig.
+    pub actions: Vec<ConfigAction>,
+}
+
+impl ConfigTransaction {
+    pub fn from_bytes(data: &[u8]) -> crate::Result<Self> {
+        decode_account("ConfigTransaction", data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sol::operations::multisig::args::Period;
+
+    // account discriminators of the squads v4 idl
+    const MULTISIG: [u8; 8] = [224, 116, 121, 186, 68, 161, 79, 236];
+    const PROPOSAL: [u8; 8] = [26, 94, 189, 187, 116, 136, 53, 33];
+    const VAULT_TRANSACTION: [u8; 8] = [168, 250, 162, 100, 81, 14, 162, 207];
+    const CONFIG_TRANSACTION: [u8; 8] = [94, 8, 4, 35, 113, 139, 139, 112];
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    fn member(seed: u8, mask: u8) -> Member {
+        Member {
+            key: key(seed),
+            permissions: Permissions { mask },
+        }
+    }
+
+    // borsh vec: u32 length then the items
+    fn push_len(data: &mut Vec<u8>, len: usize) {
+        data.extend((len as u32).to_le_bytes());
+    }
+
+    fn push_keys(data: &mut Vec<u8>, seeds: &[u8]) {
+        push_len(data, seeds.len());
+        for seed in seeds {
+            data.extend([*seed; 32]);
+        }
+    }
+
+    // the account as laid out on chain, with the spare space of the allocation
+    fn multisig_bytes(threshold: u16, members: &[(u8, u8)]) -> Vec<u8> {
+        let mut data = MULTISIG.to_vec();
+        data.extend([1; 32]); // create_key
+        data.extend([0; 32]); // config_authority
+        data.extend(threshold.to_le_bytes());
+        data.extend(60u32.to_le_bytes()); // time_lock
+        data.extend(12u64.to_le_bytes()); // transaction_index
+        data.extend(10u64.to_le_bytes()); // stale_transaction_index
+        data.push(1); // rent_collector Some
+        data.extend([2; 32]);
+        data.push(254); // bump
+        push_len(&mut data, members.len());
+        for (seed, mask) in members {
+            data.extend([*seed; 32]);
+            data.push(*mask);
+        }
+        data.extend([0; 64]);
+        data
+    }
+
+    fn multisig(threshold: u16, members: &[(u8, u8)]) -> Multisig {
+        Multisig::from_bytes(&multisig_bytes(threshold, members)).unwrap()
+    }
+
+    #[test]
+    fn test_discriminators() {
+        assert_eq!(
+            super::super::get_account_discriminator("Multisig"),
+            MULTISIG
+        );
+        assert_eq!(
+            super::super::get_account_discriminator("Proposal"),
+            PROPOSAL
+        );
+        assert_eq!(
+            super::super::get_account_discriminator("VaultTransaction"),
+            VAULT_TRANSACTION
+        );
+        assert_eq!(
+            super::super::get_account_discriminator("ConfigTransaction"),
+            CONFIG_TRANSACTION
+        );
+    }
+
+    #[test]
+    fn test_decode_multisig() {
+        let multisig = multisig(2, &[(3, 7), (4, 7), (5, 1)]);
+        assert_eq!(multisig.create_key, key(1));
+        assert_eq!(multisig.config_authority, Pubkey::default());
+        assert!(!multisig.is_controlled());
+        assert_eq!(multisig.threshold, 2);
+        assert_eq!(multisig.time_lock, 60);
+        assert_eq!(multisig.transaction_index, 12);
+        assert_eq!(multisig.stale_transaction_index, 10);
+        assert_eq!(multisig.rent_collector, Some(key(2)));
+        assert_eq!(multisig.bump, 254);
+        assert_eq!(multisig.members.len(), 3);
+        assert!(multisig.is_member(&key(5)));
+        // the initiate-only member can't vote
+        assert_eq!(multisig.voters(), 2);
+
+        // other accounts of the program are rejected
+        let mut data = multisig_bytes(2, &[(3, 7)]);
+        data[..8].copy_from_slice(&PROPOSAL);
+        assert!(Multisig::from_bytes(&data).is_err());
+        assert!(Multisig::from_bytes(&MULTISIG).is_err());
+    }
+
+    #[test]
+    fn test_decode_proposal() {
+        let mut data = PROPOSAL.to_vec();
+        data.extend([1; 32]); // multisig
+        data.extend(12u64.to_le_bytes());
+        data.push(3); // Approved
+        data.extend(1_700_000_000i64.to_le_bytes());
+        data.push(255); // bump
+        push_keys(&mut data, &[3, 4]);
+        push_keys(&mut data, &[5]);
+        push_keys(&mut data, &[]);
+        data.extend([0; 32]);
+
+        let proposal = Proposal::from_bytes(&data).unwrap();
+        assert_eq!(proposal.multisig, key(1));
+        assert_eq!(proposal.transaction_index, 12);
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::Approved {
+                timestamp: 1_700_000_000
+            }
+        );
+        assert_eq!(proposal.bump, 255);
+        assert_eq!(proposal.approved, vec![key(3), key(4)]);
+        assert_eq!(proposal.rejected, vec![key(5)]);
+        assert!(proposal.cancelled.is_empty());
+
+        // the deprecated Executing status has no timestamp
+        let mut data = PROPOSAL.to_vec();
+        data.extend([1; 32]);
+        data.extend(12u64.to_le_bytes());
+        data.push(4);
+        data.push(255);
+        push_keys(&mut data, &[]);
+        push_keys(&mut data, &[]);
+        push_keys(&mut data, &[]);
+        let proposal = Proposal::from_bytes(&data).unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Executing);
+    }
+
+    #[test]
+    fn test_decode_vault_transaction() {
+        let mut data = VAULT_TRANSACTION.to_vec();
+        data.extend([1; 32]); // multisig
+        data.extend([3; 32]); // creator
+        data.extend(12u64.to_le_bytes());
+        data.push(253); // bump
+        data.push(0); // vault_index
+        data.push(252); // vault_bump
+        push_len(&mut data, 0); // ephemeral_signer_bumps
+        // a system transfer from the vault
+        data.extend([1, 1, 1]);
+        push_keys(&mut data, &[6, 7, 0]);
+        push_len(&mut data, 1);
+        data.push(2);
+        data.extend([2, 0, 0, 0, 0, 1]);
+        let mut ix = 2u32.to_le_bytes().to_vec();
+        ix.extend(1_000_000u64.to_le_bytes());
+        push_len(&mut data, ix.len());
+        data.extend(ix);
+        push_len(&mut data, 0); // address_table_lookups
+
+        let tx = VaultTransaction::from_bytes(&data).unwrap();
+        assert_eq!(tx.multisig, key(1));
+        assert_eq!(tx.creator, key(3));
+        assert_eq!(tx.index, 12);
+        assert_eq!(tx.vault_index, 0);
+        assert_eq!(tx.vault_bump, 252);
+        assert!(tx.ephemeral_signer_bumps.is_empty());
+
+        let message = tx.message;
+        assert_eq!(message.num_signers, 1);
+        assert_eq!(message.account_keys, vec![key(6), key(7), key(0)]);
+        assert_eq!(message.instructions.len(), 1);
+        assert_eq!(message.instructions[0].program_id_index, 2);
+        assert_eq!(message.instructions[0].account_indexes, vec![0, 1]);
+        assert_eq!(message.instructions[0].data.len(), 12);
+        assert!(message.address_table_lookups.is_empty());
+    }
+
+    #[test]
+    fn test_decode_config_transaction() {
+        let mut data = CONFIG_TRANSACTION.to_vec();
+        data.extend([1; 32]); // multisig
+        data.extend([3; 32]); // creator
+        data.extend(13u64.to_le_bytes());
+        data.push(251); // bump
+        push_len(&mut data, 4);
+        // AddMember
+        data.push(0);
+        data.extend([8; 32]);
+        data.push(Permissions::VOTE);
+        // ChangeThreshold
+        data.push(2);
+        data.extend(3u16.to_le_bytes());
+        // AddSpendingLimit
+        data.push(4);
+        data.extend([9; 32]); // create_key
+        data.push(0); // vault_index
+        data.extend([0; 32]); // mint
+        data.extend(5_000_000_000u64.to_le_bytes());
+        data.push(1); // Day
+        push_keys(&mut data, &[3]);
+        push_keys(&mut data, &[]);
+        // SetRentCollector None
+        data.push(6);
+        data.push(0);
+
+        let tx = ConfigTransaction::from_bytes(&data).unwrap();
+        assert_eq!(tx.multisig, key(1));
+        assert_eq!(tx.creator, key(3));
+        assert_eq!(tx.index, 13);
+        assert_eq!(tx.bump, 251);
+        assert_eq!(tx.actions.len(), 4);
+
+        assert!(matches!(
+            &tx.actions[0],
+            ConfigAction::AddMember { new_member }
+                if new_member.key == key(8) && new_member.permissions.mask == Permissions::VOTE
+        ));
+        assert!(matches!(
+            tx.actions[1],
+            ConfigAction::ChangeThreshold { new_threshold: 3 }
+        ));
+        assert!(matches!(
+            &tx.actions[2],
+            ConfigAction::AddSpendingLimit { amount: 5_000_000_000, period: Period::Day, members, .. }
+                if members == &vec![key(3)]
+        ));
+        assert!(matches!(
+            tx.actions[3],
+            ConfigAction::SetRentCollector {
+                new_rent_collector: None
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validate_actions() {
+        let multisig = multisig(2, &[(3, 7), (4, 7), (5, 1)]);
+
+        // a threshold within the voters is fine
+        let actions = [ConfigAction::ChangeThreshold { new_threshold: 2 }];
+        assert!(multisig.validate_actions(&actions).is_ok());
+
+        // the initiate-only member doesn't count towards the threshold
+        let actions = [ConfigAction::ChangeThreshold { new_threshold: 3 }];
+        assert!(multisig.validate_actions(&actions).is_err());
+
+        let actions = [
+            ConfigAction::AddMember {
+                new_member: member(6, Permissions::VOTE),
+            },
+            ConfigAction::ChangeThreshold { new_threshold: 3 },
+        ];
+        assert!(multisig.validate_actions(&actions).is_ok());
+
+        let actions = [ConfigAction::ChangeThreshold { new_threshold: 0 }];
+        assert!(multisig.validate_actions(&actions).is_err());
+
+        // removing a voter below the threshold
+        let actions = [ConfigAction::RemoveMember { old_member: key(4) }];
+        assert!(multisig.validate_actions(&actions).is_err());
+        let actions = [
+            ConfigAction::RemoveMember { old_member: key(4) },
+            ConfigAction::ChangeThreshold { new_threshold: 1 },
+        ];
+        assert!(multisig.validate_actions(&actions).is_ok());
+
+        // the initiate-only member can be removed without changing the threshold
+        let actions = [ConfigAction::RemoveMember { old_member: key(5) }];
+        assert!(multisig.validate_actions(&actions).is_ok());
+
+        let actions = [ConfigAction::RemoveMember { old_member: key(9) }];
+        assert!(multisig.validate_actions(&actions).is_err());
+        let actions = [ConfigAction::AddMember {
+            new_member: member(3, 7),
+        }];
+        assert!(multisig.validate_actions(&actions).is_err());
+
+        let spending_limit = |amount: u64, members: Vec<Pubkey>| ConfigAction::AddSpendingLimit {
+            create_key: key(9),
+            vault_index: 0,
+            mint: Pubkey::default(),
+            amount,
+            period: Period::Week,
+            members,
+            destinations: vec![],
+        };
+        assert!(
+            multisig
+                .validate_actions(&[spending_limit(1_000, vec![key(3)])])
+                .is_ok()
+        );
+        assert!(
+            multisig
+                .validate_actions(&[spending_limit(0, vec![key(3)])])
+                .is_err()
+        );
+        assert!(
+            multisig
+                .validate_actions(&[spending_limit(1_000, vec![])])
+                .is_err()
+        );
+
+        // the program rejects config transactions of a controlled multisig
+        let mut controlled = multisig;
+        controlled.config_authority = key(8);
+        assert!(controlled.is_controlled());
+        let actions = [ConfigAction::ChangeThreshold { new_threshold: 2 }];
+        assert!(controlled.validate_actions(&actions).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+// solana name service (.sol) 的账户推导与数据解析
+use solana_sdk::pubkey::Pubkey;
+use wallet_utils::address;
+
+pub const NAME_PROGRAM_ID: &str = "namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX";
+// parent of every .sol domain
+pub const SOL_TLD_AUTHORITY: &str = "58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx";
+// class of the reverse lookup accounts
+pub const REVERSE_LOOKUP_CLASS: &str = "33m47vH6Eav6jhsuXxwYdoX6PHe63mN7iCGYmpFBcrDb";
+// primary domain chosen by the owner
+pub const FAVOURITE_DOMAIN_PROGRAM_ID: &str = "85iDfUvr3HJyLM2zcq5BXSiDvUWfw6cSE1FfNBo8Ap29";
+
+const HASH_PREFIX: &str = "SPL Name Service";
+// parent(32) | owner(32) | class(32) then the record data
+const HEADER_LEN: usize = 96;
+
+pub fn hashed_name(name: &str) -> [u8; 32] {
+    wallet_utils::sha256(format!("{}{}", HASH_PREFIX, name).as_bytes())
+}
+
+pub fn name_account_key(
+    hashed_name: &[u8; 32],
+    class: Option<Pubkey>,
+    parent: Option<Pubkey>,
+) -> crate::Result<Pubkey> {
+    let program = address::parse_sol_address(NAME_PROGRAM_ID)?;
+    let class = class.unwrap_or_default();
+    let parent = parent.unwrap_or_default();
+
+    let (key, _) = Pubkey::find_program_address(
+        &[hashed_name.as_slice(), class.as_ref(), parent.as_ref()],
+        &program,
+    );
+    Ok(key)
+}
+
+// `name.sol` or `sub.name.sol`, subdomains are prefixed with a zero byte under the parent
+pub fn domain_key(domain: &str) -> crate::Result<Pubkey> {
+    let domain = domain.strip_suffix(".sol").unwrap_or(domain);
+    let tld = address::parse_sol_address(SOL_TLD_AUTHORITY)?;
+
+    let labels = domain.split('.').collect::<Vec<_>>();
+    match labels.as_slice() {
+        [name] => name_account_key(&hashed_name(name), None, Some(tld)),
+        [sub, name] => {
+            let parent = name_account_key(&hashed_name(name), None, Some(tld))?;
+            name_account_key(&hashed_name(&format!("\0{}", sub)), None, Some(parent))
+        }
+        _ => Err(crate::Error::Other(format!(
+            "invalid sns domain {}",
+            domain
+        ))),
+    }
+}
+
+pub fn reverse_key(name_account: &Pubkey) -> crate::Result<Pubkey> {
+    let class = address::parse_sol_address(REVERSE_LOOKUP_CLASS)?;
+    name_account_key(&hashed_name(&name_account.to_string()), Some(class), None)
+}
+
+pub fn favourite_domain_key(owner: &Pubkey) -> crate::Result<Pubkey> {
+    let program = address::parse_sol_address(FAVOURITE_DOMAIN_PROGRAM_ID)?;
+    let (key, _) = Pubkey::find_program_address(&[b"favourite_domain", owner.as_ref()], &program);
+    Ok(key)
+}
+
+// owner stored in the name registry header
+pub fn registry_owner(data: &[u8]) -> crate::Result<Pubkey> {
+    if data.len() < HEADER_LEN {
+        return Err(crate::Error::Other(
+            "invalid name registry data".to_string(),
+        ));
+    }
+    let owner: [u8; 32] = data[32..64].try_into().unwrap();
+    Ok(Pubkey::new_from_array(owner))
+}
+
+// u32 le length then the domain without `.sol`
+pub fn reverse_name(data: &[u8]) -> crate::Result<String> {
+    let invalid = || crate::Error::Other("invalid reverse lookup data".to_string());
+
+    let record = data.get(HEADER_LEN..).ok_or_else(invalid)?;
+    let len = u32::from_le_bytes(record.get(..4).ok_or_else(invalid)?.try_into().unwrap());
+    let name = record.get(4..4 + len as usize).ok_or_else(invalid)?;
+
+    let name = String::from_utf8(name.to_vec()).map_err(|_| invalid())?;
+    Ok(format!("{}.sol", name))
+}
+
+// tag(1) then the name account
+pub fn favourite_name_account(data: &[u8]) -> crate::Result<Pubkey> {
+    let account: [u8; 32] =
+        data.get(1..33)
+            .and_then(|d| d.try_into().ok())
+            .ok_or(crate::Error::Other(
+                "invalid favourite domain data".to_string(),
+            ))?;
+    Ok(Pubkey::new_from_array(account))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_key() {
+        // bonfida.sol
+        let key = domain_key("bonfida.sol").unwrap();
+        assert_eq!(
+            key.to_string(),
+            "Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb"
+        );
+        assert_eq!(domain_key("bonfida").unwrap(), key);
+        assert!(domain_key("a.b.c.sol").is_err());
+    }
+
+    #[test]
+    fn test_parse_registry() {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; HEADER_LEN];
+        data[32..64].copy_from_slice(owner.as_ref());
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.extend_from_slice(b"bonfida");
+
+        assert_eq!(registry_owner(&data).unwrap(), owner);
+        assert_eq!(reverse_name(&data).unwrap(), "bonfida.sol");
+        assert!(reverse_name(&data[..HEADER_LEN + 6]).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::sol::protocol::account::AccountInfo;
+use solana_sdk::stake::state::StakeStateV2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum StakeActivationState {
+    // created but never delegated
+    Initialized,
+    Activating,
+    Active,
+    Deactivating,
+    Inactive,
+}
+
+/// Decoded stake account with its activation state in the given epoch.
+///
+/// The state is derived from the delegation epochs only, warmup and cooldown are
+/// rate limited by the cluster so a large stake may stay activating/deactivating for
+/// more than one epoch.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct StakeAccount {
+    pub address: String,
+    // unit lamports
+    pub lamports: u64,
+    pub rent_exempt_reserve: u64,
+    pub staker: String,
+    pub withdrawer: String,
+    // vote account the stake is delegated to
+    pub voter: Option<String>,
+    pub delegated_stake: u64,
+    pub activation_epoch: Option<u64>,
+    pub deactivation_epoch: Option<u64>,
+    pub state: StakeActivationState,
+}
+
+impl StakeAccount {
+    pub fn from_account(
+        address: &str,
+        account: &AccountInfo,
+        current_epoch: u64,
+    ) -> crate::Result<Self> {
+        let data = account.data_bytes()?;
+        let state = wallet_utils::hex_func::bin_decode_bytes::<StakeStateV2>(&data)?;
+
+        match state {
+            StakeStateV2::Initialized(meta) => Ok(Self {
+                address: address.to_string(),
+                lamports: account.lamports,
+                rent_exempt_reserve: meta.rent_exempt_reserve,
+                staker: meta.authorized.staker.to_string(),
+                withdrawer: meta.authorized.withdrawer.to_string(),
+                voter: None,
+                delegated_stake: 0,
+                activation_epoch: None,
+                deactivation_epoch: None,
+                state: StakeActivationState::Initialized,
+            }),
+            StakeStateV2::Stake(meta, stake, _) => {
+                let delegation = stake.delegation;
+
+                let deactivation_epoch = if delegation.deactivation_epoch == u64::MAX {
+                    None
+                } else {
+                    Some(delegation.deactivation_epoch)
+                };
+
+                let state = match deactivation_epoch {
+                    Some(epoch) if epoch < current_epoch => StakeActivationState::Inactive,
+                    Some(_) => StakeActivationState::Deactivating,
+                    None if delegation.activation_epoch >= current_epoch => {
+                        StakeActivationState::Activating
+                    }
+                    None => StakeActivationState::Active,
+                };
+
+                Ok(Self {
+                    address: address.to_string(),
+                    lamports: account.lamports,
+                    rent_exempt_reserve: meta.rent_exempt_reserve,
+                    staker: meta.authorized.staker.to_string(),
+                    withdrawer: meta.authorized.withdrawer.to_string(),
+                    voter: Some(delegation.voter_pubkey.to_string()),
+                    delegated_stake: delegation.stake,
+                    activation_epoch: Some(delegation.activation_epoch),
+                    deactivation_epoch,
+                    state,
+                })
+            }
+            _ => Err(crate::Error::Other(format!(
+                "{} is not an initialized stake account",
+                address
+            ))),
+        }
+    }
+
+    // lamports that can be withdrawn right now
+    pub fn withdrawable(&self) -> u64 {
+        match self.state {
+            StakeActivationState::Initialized | StakeActivationState::Inactive => self.lamports,
+            _ => self
+                .lamports
+                .saturating_sub(self.delegated_stake + self.rent_exempt_reserve),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        pubkey::Pubkey,
+        stake::{
+            stake_flags::StakeFlags,
+            state::{Authorized, Delegation, Lockup, Meta, Stake},
+        },
+    };
+
+    const RESERVE: u64 = 2_282_880;
+    const STAKE: u64 = 5_000_000_000;
+
+    fn meta() -> Meta {
+        Meta {
+            rent_exempt_reserve: RESERVE,
+            authorized: Authorized::auto(&Pubkey::new_unique()),
+            lockup: Lockup::default(),
+        }
+    }
+
+    // stake accounts are 200 bytes, the state is bincode encoded at the start
+    fn account(state: &StakeStateV2, lamports: u64) -> AccountInfo {
+        let mut data = wallet_utils::hex_func::bin_encode_bytes(state).unwrap();
+        data.resize(StakeStateV2::size_of(), 0);
+
+        AccountInfo {
+            data: vec![wallet_utils::bytes_to_base64(&data), "base64".to_string()],
+            executable: false,
+            lamports,
+            owner: "Stake11111111111111111111111111111111111111".to_string(),
+            rent_epoch: u64::MAX,
+            space: data.len() as u64,
+        }
+    }
+
+    fn delegated(activation_epoch: u64, deactivation_epoch: u64) -> StakeStateV2 {
+        let mut delegation = Delegation::new(&Pubkey::new_unique(), STAKE, activation_epoch);
+        delegation.deactivation_epoch = deactivation_epoch;
+
+        StakeStateV2::Stake(
+            meta(),
+            Stake {
+                delegation,
+                credits_observed: 0,
+            },
+            StakeFlags::empty(),
+        )
+    }
+
+    fn decode(state: &StakeStateV2, lamports: u64, epoch: u64) -> StakeAccount {
+        StakeAccount::from_account("stake", &account(state, lamports), epoch).unwrap()
+    }
+
+    #[test]
+    fn test_initialized() {
+        let stake = decode(&StakeStateV2::Initialized(meta()), RESERVE + STAKE, 20);
+        assert_eq!(stake.state, StakeActivationState::Initialized);
+        assert_eq!(stake.voter, None);
+        assert_eq!(stake.delegated_stake, 0);
+        // never delegated, everything including the reserve can be withdrawn
+        assert_eq!(stake.withdrawable(), RESERVE + STAKE);
+    }
+
+    #[test]
+    fn test_delegated() {
+        let lamports = RESERVE + STAKE + 1_000;
+
+        let stake = decode(&delegated(20, u64::MAX), lamports, 20);
+        assert_eq!(stake.state, StakeActivationState::Activating);
+        assert_eq!(stake.deactivation_epoch, None);
+        // only lamports above the stake and the reserve
+        assert_eq!(stake.withdrawable(), 1_000);
+
+        let stake = decode(&delegated(10, u64::MAX), lamports, 20);
+        assert_eq!(stake.state, StakeActivationState::Active);
+        assert_eq!(stake.delegated_stake, STAKE);
+        assert!(stake.voter.is_some());
+        assert_eq!(stake.withdrawable(), 1_000);
+
+        // rewards below the reserve do not underflow
+        let stake = decode(&delegated(10, u64::MAX), RESERVE, 20);
+        assert_eq!(stake.withdrawable(), 0);
+    }
+
+    #[test]
+    fn test_deactivated() {
+        let lamports = RESERVE + STAKE + 1_000;
+
+        let stake = decode(&delegated(10, 20), lamports, 20);
+        assert_eq!(stake.state, StakeActivationState::Deactivating);
+        assert_eq!(stake.deactivation_epoch, Some(20));
+        assert_eq!(stake.withdrawable(), 1_000);
+
+        let stake = decode(&delegated(10, 19), lamports, 20);
+        assert_eq!(stake.state, StakeActivationState::Inactive);
+        assert_eq!(stake.withdrawable(), lamports);
+    }
+
+    #[test]
+    fn test_uninitialized() {
+        let info = account(&StakeStateV2::Uninitialized, RESERVE);
+        assert!(StakeAccount::from_account("stake", &info, 20).is_err());
+    }
+}
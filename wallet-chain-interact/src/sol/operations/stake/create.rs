@@ -0,0 +1,99 @@
+use crate::sol::{
+    operations::{SolInstructionOperation, SolTransferOperation},
+    provider::Provider,
+};
+use async_trait::async_trait;
+use solana_sdk::stake::{
+    instruction as stake_instruction,
+    state::{Authorized, Lockup, StakeStateV2},
+};
+use wallet_utils::address;
+
+// create and fund a stake account derived from the owner with a seed, optionally delegate it in the same transaction
+pub struct CreateStakeOpt<'a> {
+    pub from: solana_sdk::pubkey::Pubkey,
+    pub stake_account: solana_sdk::pubkey::Pubkey,
+    pub seed: String,
+    // unit lamports, includes the rent exempt reserve
+    pub lamports: u64,
+    pub vote_account: Option<solana_sdk::pubkey::Pubkey>,
+    pub provider: &'a Provider,
+}
+
+impl<'a> CreateStakeOpt<'a> {
+    pub fn new(from: &str, value: &str, seed: &str, provider: &'a Provider) -> crate::Result<Self> {
+        let from = address::parse_sol_address(from)?;
+        let lamports = wallet_utils::unit::convert_to_u256(value, crate::sol::consts::SOL_DECIMAL)?;
+
+        Ok(Self {
+            from,
+            stake_account: super::stake_address_with_seed(&from, seed)?,
+            seed: seed.to_string(),
+            lamports: lamports.to::<u64>(),
+            vote_account: None,
+            provider,
+        })
+    }
+
+    pub fn with_vote_account(mut self, vote_account: &str) -> crate::Result<Self> {
+        self.vote_account = Some(address::parse_sol_address(vote_account)?);
+        Ok(self)
+    }
+
+    // rent exempt reserve plus the minimum delegation of the cluster,unit lamports
+    pub async fn minimum_balance(&self) -> crate::Result<u64> {
+        let rent = self
+            .provider
+            .get_minimum_balance_for_rent(StakeStateV2::size_of() as u64)
+            .await?;
+        let minimum_delegation = self.provider.stake_minimum_delegation().await?;
+
+        Ok(rent + minimum_delegation)
+    }
+}
+
+#[async_trait]
+impl SolInstructionOperation for CreateStakeOpt<'_> {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        let minimum = self.minimum_balance().await?;
+        if self.lamports < minimum {
+            return Err(crate::Error::Other(format!(
+                "stake amount less than the minimum {} lamports",
+                minimum
+            )));
+        }
+
+        let authorized = Authorized::auto(&self.from);
+        let mut instructions = stake_instruction::create_account_with_seed(
+            &self.from,
+            &self.stake_account,
+            &self.from,
+            &self.seed,
+            &authorized,
+            &Lockup::default(),
+            self.lamports,
+        );
+
+        if let Some(vote_account) = self.vote_account {
+            instructions.push(stake_instruction::delegate_stake(
+                &self.stake_account,
+                &self.from,
+                &vote_account,
+            ));
+        }
+
+        Ok(instructions)
+    }
+}
+
+#[async_trait]
+impl SolTransferOperation for CreateStakeOpt<'_> {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.from)
+    }
+
+    // the rent of the stake account is taken from the staked lamports
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
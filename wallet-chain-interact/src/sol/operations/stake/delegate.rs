@@ -0,0 +1,79 @@
+use crate::sol::operations::{SolInstructionOperation, SolTransferOperation};
+use async_trait::async_trait;
+use solana_sdk::stake::instruction as stake_instruction;
+use wallet_utils::address;
+
+// delegate an initialized or inactive stake account to a vote account
+pub struct DelegateStakeOpt {
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub stake_account: solana_sdk::pubkey::Pubkey,
+    pub vote_account: solana_sdk::pubkey::Pubkey,
+}
+
+impl DelegateStakeOpt {
+    pub fn new(authority: &str, stake_account: &str, vote_account: &str) -> crate::Result<Self> {
+        Ok(Self {
+            authority: address::parse_sol_address(authority)?,
+            stake_account: address::parse_sol_address(stake_account)?,
+            vote_account: address::parse_sol_address(vote_account)?,
+        })
+    }
+}
+
+#[async_trait]
+impl SolInstructionOperation for DelegateStakeOpt {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        Ok(vec![stake_instruction::delegate_stake(
+            &self.stake_account,
+            &self.authority,
+            &self.vote_account,
+        )])
+    }
+}
+
+#[async_trait]
+impl SolTransferOperation for DelegateStakeOpt {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.authority)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
+
+// deactivate a delegated stake account, the stake can be withdrawn after the cooldown
+pub struct DeactivateStakeOpt {
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub stake_account: solana_sdk::pubkey::Pubkey,
+}
+
+impl DeactivateStakeOpt {
+    pub fn new(authority: &str, stake_account: &str) -> crate::Result<Self> {
+        Ok(Self {
+            authority: address::parse_sol_address(authority)?,
+            stake_account: address::parse_sol_address(stake_account)?,
+        })
+    }
+}
+
+#[async_trait]
+impl SolInstructionOperation for DeactivateStakeOpt {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        Ok(vec![stake_instruction::deactivate_stake(
+            &self.stake_account,
+            &self.authority,
+        )])
+    }
+}
+
+#[async_trait]
+impl SolTransferOperation for DeactivateStakeOpt {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.authority)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,29 @@
+pub mod account;
+pub use account::*;
+
+pub mod create;
+pub use create::*;
+
+pub mod delegate;
+pub use delegate::*;
+
+pub mod split;
+pub use split::*;
+
+pub mod withdraw;
+pub use withdraw::*;
+
+use solana_sdk::pubkey::Pubkey;
+
+//  stake program id
+pub const STAKE_PRAMS_ID: &str = "Stake11111111111111111111111111111111111111";
+
+// offset of the staker / withdrawer authority in the stake account data
+pub const STAKER_OFFSET: usize = 12;
+pub const WITHDRAWER_OFFSET: usize = 44;
+
+// stake accounts are derived from the owner with a seed,so no extra keypair needs to be kept
+pub fn stake_address_with_seed(base: &Pubkey, seed: &str) -> crate::Result<Pubkey> {
+    Pubkey::create_with_seed(base, seed, &solana_sdk::stake::program::id())
+        .map_err(|e| crate::Error::Other(format!("create stake address with seed error:{}", e)))
+}
@@ -0,0 +1,121 @@
+use crate::sol::{
+    operations::{SolInstructionOperation, SolTransferOperation},
+    provider::Provider,
+};
+use async_trait::async_trait;
+use solana_sdk::stake::{instruction as stake_instruction, state::StakeStateV2};
+use wallet_utils::address;
+
+// split part of a stake account into a new stake account derived with a seed
+pub struct SplitStakeOpt<'a> {
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub stake_account: solana_sdk::pubkey::Pubkey,
+    pub split_account: solana_sdk::pubkey::Pubkey,
+    pub seed: String,
+    // unit lamports
+    pub lamports: u64,
+    pub provider: &'a Provider,
+}
+
+impl<'a> SplitStakeOpt<'a> {
+    pub fn new(
+        authority: &str,
+        stake_account: &str,
+        value: &str,
+        seed: &str,
+        provider: &'a Provider,
+    ) -> crate::Result<Self> {
+        let authority = address::parse_sol_address(authority)?;
+        let lamports = wallet_utils::unit::convert_to_u256(value, crate::sol::consts::SOL_DECIMAL)?;
+
+        Ok(Self {
+            authority,
+            stake_account: address::parse_sol_address(stake_account)?,
+            split_account: super::stake_address_with_seed(&authority, seed)?,
+            seed: seed.to_string(),
+            lamports: lamports.to::<u64>(),
+            provider,
+        })
+    }
+}
+
+#[async_trait]
+impl SolInstructionOperation for SplitStakeOpt<'_> {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        // the split account must be prefunded with its rent exempt reserve
+        let rent = self
+            .provider
+            .get_minimum_balance_for_rent(StakeStateV2::size_of() as u64)
+            .await?;
+
+        let mut instructions = vec![solana_sdk::system_instruction::transfer(
+            &self.authority,
+            &self.split_account,
+            rent,
+        )];
+        instructions.extend(stake_instruction::split_with_seed(
+            &self.stake_account,
+            &self.authority,
+            self.lamports,
+            &self.split_account,
+            &self.authority,
+            &self.seed,
+        ));
+
+        Ok(instructions)
+    }
+}
+
+#[async_trait]
+impl SolTransferOperation for SplitStakeOpt<'_> {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.authority)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        let rent = self
+            .provider
+            .get_minimum_balance_for_rent(StakeStateV2::size_of() as u64)
+            .await?;
+        Ok(Some(rent))
+    }
+}
+
+// merge the source stake account into the destination, the source account is closed
+pub struct MergeStakeOpt {
+    pub authority: solana_sdk::pubkey::Pubkey,
+    pub destination: solana_sdk::pubkey::Pubkey,
+    pub source: solana_sdk::pubkey::Pubkey,
+}
+
+impl MergeStakeOpt {
+    pub fn new(authority: &str, destination: &str, source: &str) -> crate::Result<Self> {
+        Ok(Self {
+            authority: address::parse_sol_address(authority)?,
+            destination: address::parse_sol_address(destination)?,
+            source: address::parse_sol_address(source)?,
+        })
+    }
+}
+
+#[async_trait]
+impl SolInstructionOperation for MergeStakeOpt {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        Ok(stake_instruction::merge(
+            &self.destination,
+            &self.source,
+            &self.authority,
+        ))
+    }
+}
+
+#[async_trait]
+impl SolTransferOperation for MergeStakeOpt {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.authority)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::sol::operations::{SolInstructionOperation, SolTransferOperation};
+use async_trait::async_trait;
+use solana_sdk::stake::instruction as stake_instruction;
+use wallet_utils::address;
+
+// withdraw lamports from an inactive (or the undelegated part of a) stake account
+pub struct WithdrawStakeOpt {
+    pub withdrawer: solana_sdk::pubkey::Pubkey,
+    pub stake_account: solana_sdk::pubkey::Pubkey,
+    pub to: solana_sdk::pubkey::Pubkey,
+    // unit lamports
+    pub lamports: u64,
+}
+
+impl WithdrawStakeOpt {
+    pub fn new(
+        withdrawer: &str,
+        stake_account: &str,
+        to: &str,
+        lamports: u64,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            withdrawer: address::parse_sol_address(withdrawer)?,
+            stake_account: address::parse_sol_address(stake_account)?,
+            to: address::parse_sol_address(to)?,
+            lamports,
+        })
+    }
+}
+
+#[async_trait]
+impl SolInstructionOperation for WithdrawStakeOpt {
+    async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
+        Ok(vec![stake_instruction::withdraw(
+            &self.stake_account,
+            &self.withdrawer,
+            &self.to,
+            self.lamports,
+            None,
+        )])
+    }
+}
+
+#[async_trait]
+impl SolTransferOperation for WithdrawStakeOpt {
+    fn payer(&self) -> crate::Result<solana_sdk::pubkey::Pubkey> {
+        Ok(self.withdrawer)
+    }
+
+    async fn extra_fee(&self) -> crate::Result<Option<u64>> {
+        Ok(None)
+    }
+}
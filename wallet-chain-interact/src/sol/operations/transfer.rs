@@ -1,7 +1,9 @@
-use crate::sol::{operations::contract::TokenTransferBuild, provider::Provider};
+use crate::sol::{
+    operations::{contract::TokenTransferBuild, extension::MintExtension},
+    provider::Provider,
+};
 use async_trait::async_trait;
-use solana_sdk::program_pack::Pack;
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 use wallet_utils::address;
 
 pub struct TransferOpt<'a> {
@@ -11,6 +13,8 @@ pub struct TransferOpt<'a> {
     pub token: Option<solana_sdk::pubkey::Pubkey>,
     pub decimal: u8,
     pub provider: &'a Provider,
+    // memo attached before token transfers, required by some token-2022 accounts
+    pub memo: Option<String>,
 }
 
 impl<'a> TransferOpt<'a> {
@@ -36,15 +40,21 @@ impl<'a> TransferOpt<'a> {
             token,
             decimal,
             provider,
+            memo: None,
         })
     }
+
+    pub fn with_memo(mut self, memo: String) -> Self {
+        self.memo = Some(memo);
+        self
+    }
 }
 
 #[async_trait]
 impl super::SolInstructionOperation for TransferOpt<'_> {
     async fn instructions(&self) -> crate::Result<Vec<solana_sdk::instruction::Instruction>> {
         let instructions = if let Some(token) = self.token {
-            let token_build = TokenTransferBuild::new(self, token)?;
+            let token_build = TokenTransferBuild::new(self, token);
             token_build.transfer_instruction().await?
         } else {
             vec![solana_sdk::system_instruction::transfer(
@@ -64,13 +74,16 @@ impl super::SolTransferOperation for TransferOpt<'_> {
     /// In an SPL token transfer, if the recipient’s token address does not exist, additional fees will be incurred to create the account.
     async fn extra_fee(&self) -> crate::Result<Option<u64>> {
         if let Some(token) = self.token {
-            let destination_pubkey = get_associated_token_address(&self.to, &token);
+            let mint = MintExtension::fetch(self.provider, &token).await?;
+            let destination_pubkey =
+                get_associated_token_address_with_program_id(&self.to, &token, &mint.program_id);
 
             // Check whether the address has a token account.
             let to_account = self.provider.account_info(destination_pubkey).await?;
 
             if to_account.value.is_none() {
-                let data_len = spl_token_2022::state::Account::LEN;
+                // token-2022 accounts are larger when the mint requires account extensions
+                let data_len = mint.account_len()?;
                 let value = self
                     .provider
                     .get_minimum_balance_for_rent(data_len as u64)
@@ -10,6 +10,15 @@ pub struct AccountInfo {
     pub rent_epoch: u64,
     pub space: u64,
 }
+impl AccountInfo {
+    // decode the base64 encoded account data
+    pub fn data_bytes(&self) -> crate::Result<Vec<u8>> {
+        match self.data.first() {
+            Some(data) => Ok(wallet_utils::base64_to_bytes(data)?),
+            None => Ok(vec![]),
+        }
+    }
+}
 
 #[derive(Debug, Deserialize)]
 pub struct Balance {
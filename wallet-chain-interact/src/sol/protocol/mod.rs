@@ -2,6 +2,7 @@ use serde::Deserialize;
 pub mod account;
 pub mod block;
 pub mod contract;
+pub mod stake;
 pub mod transaction;
 
 #[derive(Debug, Deserialize)]
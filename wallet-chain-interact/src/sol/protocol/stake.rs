@@ -0,0 +1,41 @@
+use super::account::AccountInfo;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct VoteAccounts {
+    pub current: Vec<VoteAccount>,
+    pub delinquent: Vec<VoteAccount>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteAccount {
+    pub vote_pubkey: String,
+    pub node_pubkey: String,
+    // unit lamports
+    pub activated_stake: u64,
+    pub epoch_vote_account: bool,
+    // percentage of rewards payout owed to the vote account
+    pub commission: u8,
+    pub last_vote: u64,
+    // (epoch, credits, previous credits)
+    pub epoch_credits: Vec<(u64, u64, u64)>,
+    pub root_slot: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InflationReward {
+    pub epoch: u64,
+    pub effective_slot: u64,
+    // unit lamports
+    pub amount: u64,
+    pub post_balance: u64,
+    pub commission: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProgramAccount {
+    pub pubkey: String,
+    pub account: AccountInfo,
+}
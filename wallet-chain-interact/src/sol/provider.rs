@@ -1,9 +1,13 @@
 use super::{
-    operations::multisig::program::{MultisigArgs, ProgramConfig},
+    operations::multisig::{
+        program::{MultisigArgs, ProgramConfig},
+        state::{ConfigTransaction, Multisig, Proposal, VaultTransaction},
+    },
     protocol::{
         account::{AccountInfo, Balance, TokenAccount},
-        block::Prioritization,
+        block::{EpochInfo, Prioritization},
         contract::TotalSupply,
+        stake::{InflationReward, ProgramAccount, VoteAccounts},
         transaction::{CommitmentConfig, Status},
     },
 };
@@ -155,6 +159,40 @@ impl Provider {
         Ok(program_config)
     }
 
+    // raw data of an existing account
+    async fn account_bytes(&self, addr: &Pubkey, name: &str) -> crate::Result<Vec<u8>> {
+        let account = self
+            .account_info(*addr)
+            .await?
+            .value
+            .ok_or(crate::Error::Other(format!("not found {} account", name)))?;
+
+        account.data_bytes()
+    }
+
+    pub async fn get_multisig(&self, multisig_pda: &Pubkey) -> crate::Result<Multisig> {
+        let data = self.account_bytes(multisig_pda, "multisig").await?;
+        Multisig::from_bytes(&data)
+    }
+
+    pub async fn get_proposal(&self, proposal_pda: &Pubkey) -> crate::Result<Proposal> {
+        let data = self.account_bytes(proposal_pda, "proposal").await?;
+        Proposal::from_bytes(&data)
+    }
+
+    pub async fn get_vault_transaction(&self, tx_pda: &Pubkey) -> crate::Result<VaultTransaction> {
+        let data = self.account_bytes(tx_pda, "vault transaction").await?;
+        VaultTransaction::from_bytes(&data)
+    }
+
+    pub async fn get_config_transaction(
+        &self,
+        tx_pda: &Pubkey,
+    ) -> crate::Result<ConfigTransaction> {
+        let data = self.account_bytes(tx_pda, "config transaction").await?;
+        ConfigTransaction::from_bytes(&data)
+    }
+
     pub async fn latest_block(
         &self,
         commitment: CommitmentConfig,
@@ -437,6 +475,14 @@ impl Provider {
         Ok(self.client.invoke_request::<_, u64>(params).await?)
     }
 
+    pub async fn epoch_info(&self) -> crate::Result<EpochInfo> {
+        let params: JsonRpcParams<()> = JsonRpcParams::default()
+            .method("getEpochInfo")
+            .no_params();
+
+        Ok(self.client.invoke_request::<_, EpochInfo>(params).await?)
+    }
+
     pub async fn total_supply(&self, token_addr: &str) -> crate::Result<Response<TotalSupply>> {
         let params = JsonRpcParams::default()
             .method("getTokenSupply")
@@ -466,4 +512,74 @@ impl Provider {
 
         Ok(self.client.invoke_request(params).await?)
     }
+
+    pub async fn program_accounts(
+        &self,
+        program_id: &str,
+        filters: Vec<serde_json::Value>,
+    ) -> crate::Result<Vec<ProgramAccount>> {
+        let params = JsonRpcParams::default()
+            .method("getProgramAccounts")
+            .params(vec![
+                program_id.into(),
+                json!({
+                    "encoding": "base64",
+                    "filters": filters,
+                }),
+            ]);
+
+        Ok(self
+            .client
+            .invoke_request::<_, Vec<ProgramAccount>>(params)
+            .await?)
+    }
+
+    pub async fn vote_accounts(&self) -> crate::Result<VoteAccounts> {
+        let params = JsonRpcParams::default()
+            .method("getVoteAccounts")
+            .params(vec![json!({
+                "commitment": CommitmentConfig::Finalized.to_string()
+            })]);
+
+        Ok(self
+            .client
+            .invoke_request::<_, VoteAccounts>(params)
+            .await?)
+    }
+
+    // the epoch is None to query the last completed epoch
+    pub async fn inflation_reward(
+        &self,
+        addresses: &[String],
+        epoch: Option<u64>,
+    ) -> crate::Result<Vec<Option<InflationReward>>> {
+        let mut config = json!({
+            "commitment": CommitmentConfig::Finalized.to_string()
+        });
+        if let Some(epoch) = epoch {
+            config["epoch"] = epoch.into();
+        }
+
+        let params = JsonRpcParams::default()
+            .method("getInflationReward")
+            .params(json!([addresses, config]));
+
+        Ok(self
+            .client
+            .invoke_request::<_, Vec<Option<InflationReward>>>(params)
+            .await?)
+    }
+
+    // unit lamports
+    pub async fn stake_minimum_delegation(&self) -> crate::Result<u64> {
+        let params: JsonRpcParams<()> = JsonRpcParams::default()
+            .method("getStakeMinimumDelegation")
+            .no_params();
+
+        let res = self
+            .client
+            .invoke_request::<_, Response<u64>>(params)
+            .await?;
+        Ok(res.value)
+    }
 }
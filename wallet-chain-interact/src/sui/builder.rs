@@ -1,11 +1,33 @@
-use super::{Provider, consts::SUI_NATIVE_COIN};
+use super::{
+    Provider,
+    consts::{MAX_COMMAND_ARGUMENTS, MAX_GAS_OBJECTS, MAX_INPUT_OBJECTS, SUI_NATIVE_COIN},
+};
 use sui_json_rpc_types::Coin;
 use sui_types::{
-    base_types::ObjectRef,
+    Identifier, TypeTag,
+    base_types::{ObjectID, ObjectRef},
     programmable_transaction_builder::ProgrammableTransactionBuilder,
     transaction::{Argument, Command, ObjectArg},
 };
 
+// move call command,module and function are given by name
+pub fn move_call(
+    builder: &mut ProgrammableTransactionBuilder,
+    package: ObjectID,
+    module: &str,
+    function: &str,
+    type_arguments: Vec<TypeTag>,
+    arguments: Vec<Argument>,
+) -> crate::Result<Argument> {
+    let module = Identifier::new(module)
+        .map_err(|e| crate::sui::error::SuiError::MoveError(e.to_string()))?;
+    let function = Identifier::new(function)
+        .map_err(|e| crate::sui::error::SuiError::MoveError(e.to_string()))?;
+
+    Ok(builder.programmable_move_call(package, module, function, type_arguments, arguments))
+}
+
+#[derive(Default)]
 pub struct SelectCoinHelper {
     // 选择的coin
     pub select_coins: Vec<Coin>,
@@ -14,6 +36,60 @@ pub struct SelectCoinHelper {
 }
 
 impl SelectCoinHelper {
+    // 按余额从大到小选择coin,尽量减少交易的输入对象
+    pub async fn select(
+        provider: &Provider,
+        owner: &str,
+        coin_type: &str,
+        amount: u64,
+    ) -> crate::Result<Self> {
+        let all_coin = provider.get_all_coins_by_owner(owner, coin_type).await?;
+        Self::select_from(all_coin, coin_type, amount)
+    }
+
+    // 从持有的coin中按余额从大到小选择
+    pub fn select_from(
+        mut all_coin: Vec<Coin>,
+        coin_type: &str,
+        amount: u64,
+    ) -> crate::Result<Self> {
+        all_coin.sort_by(|a, b| b.balance.cmp(&a.balance));
+
+        let mut select_coins = vec![];
+        let mut sum = 0u64;
+        for coin in all_coin {
+            sum += coin.balance;
+            select_coins.push(coin);
+            if sum >= amount {
+                break;
+            }
+        }
+
+        // 验证金额是否满足
+        if sum < amount {
+            return Err(crate::sui::error::SuiError::InsufficientBalance(sum, amount).into());
+        }
+
+        if select_coins.len() > MAX_INPUT_OBJECTS {
+            return Err(crate::sui::error::SuiError::TooManyCoins(
+                select_coins.len(),
+                MAX_INPUT_OBJECTS,
+            )
+            .into());
+        }
+
+        let gas_coin = if coin_type == SUI_NATIVE_COIN {
+            select_coins.clone()
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            select_coins,
+            gas_coin,
+        })
+    }
+
     // 选择代币的总金额
     pub fn select_total_amount(&self) -> u64 {
         self.select_coins.iter().map(|c| c.balance).sum()
@@ -80,8 +156,36 @@ impl SelectCoinHelper {
         Ok(gas_coin)
     }
 
+    // gas 支付对象最多 MAX_GAS_OBJECTS 个,超出的部分通过 merge_excess_gas_coin 合并到gas coin
     pub fn gas_obj_ref(&self) -> Vec<ObjectRef> {
-        self.gas_coin.iter().map(|c| c.object_ref()).collect()
+        self.gas_coin
+            .iter()
+            .take(MAX_GAS_OBJECTS)
+            .map(|c| c.object_ref())
+            .collect()
+    }
+
+    // 超出gas支付对象上限的sui coin 作为输入合并到gas coin,需要在其他命令之前调用
+    pub fn merge_excess_gas_coin(
+        &self,
+        builder: &mut ProgrammableTransactionBuilder,
+    ) -> crate::Result<()> {
+        if self.gas_coin.len() <= MAX_GAS_OBJECTS {
+            return Ok(());
+        }
+
+        let mut coin_inputs = vec![];
+        for coin in self.gas_coin.iter().skip(MAX_GAS_OBJECTS) {
+            let coin_arg = builder
+                .obj(ObjectArg::ImmOrOwnedObject(coin.object_ref()))
+                .map_err(|e| crate::sui::error::SuiError::MoveError(e.to_string()))?;
+            coin_inputs.push(coin_arg);
+        }
+
+        for chunk in coin_inputs.chunks(MAX_COMMAND_ARGUMENTS) {
+            builder.command(Command::MergeCoins(Argument::GasCoin, chunk.to_vec()));
+        }
+        Ok(())
     }
 
     pub fn build_main_coin_arg(
@@ -110,6 +214,31 @@ impl SelectCoinHelper {
         }
     }
 
+    // 从gas coin 中拆分出指定金额(move call 不能直接使用gas coin)
+    pub fn build_split_gas_arg(
+        &mut self,
+        builder: &mut ProgrammableTransactionBuilder,
+        amount: u64,
+    ) -> crate::Result<Argument> {
+        let pure_amount = builder
+            .pure(amount)
+            .map_err(|e| crate::sui::error::SuiError::MoveError(e.to_string()))?;
+
+        Ok(builder.command(Command::SplitCoins(Argument::GasCoin, vec![pure_amount])))
+    }
+
+    // 合并多个coin到第一个coin,超过单个命令参数上限时分批合并
+    pub fn merge_coin_args(
+        builder: &mut ProgrammableTransactionBuilder,
+        coin_inputs: &[Argument],
+    ) -> Argument {
+        let primary = coin_inputs[0];
+        for chunk in coin_inputs[1..].chunks(MAX_COMMAND_ARGUMENTS) {
+            builder.command(Command::MergeCoins(primary, chunk.to_vec()));
+        }
+        primary
+    }
+
     // 代币转账构建转账的arg
     pub fn build_token_coin_arg(
         &mut self,
@@ -126,11 +255,7 @@ impl SelectCoinHelper {
         }
 
         // merge_coin
-        let primary = coin_inputs[0];
-        if coin_inputs.len() > 1 {
-            // tracing::warn!("merge coin");
-            builder.command(Command::MergeCoins(primary, coin_inputs[1..].to_vec()));
-        }
+        let primary = Self::merge_coin_args(builder, &coin_inputs);
 
         //
         let transfer_amount = if self.select_total_amount() > amount {
@@ -147,3 +272,131 @@ impl SelectCoinHelper {
         Ok(transfer_amount)
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use sui_types::{
+        base_types::SequenceNumber,
+        digests::{ObjectDigest, TransactionDigest},
+    };
+
+    pub(crate) const TOKEN: &str =
+        "0x1b9e65276fbeab5569a0afb074bb090b9eb867082417b0470a1a04f4be6d2f3a::qtoken::QTOKEN";
+
+    pub(crate) fn coin(coin_type: &str, balance: u64) -> Coin {
+        Coin {
+            coin_type: coin_type.to_string(),
+            coin_object_id: ObjectID::random(),
+            version: SequenceNumber::from_u64(1),
+            digest: ObjectDigest::random(),
+            balance,
+            previous_transaction: TransactionDigest::random(),
+        }
+    }
+
+    // the coins are given, so the provider is never requested
+    pub(crate) fn provider() -> Provider {
+        let client =
+            wallet_transport::client::RpcClient::new("http://127.0.0.1:9000", None, None).unwrap();
+        Provider::new(client)
+    }
+
+    pub(crate) fn coins(coin_type: &str, count: usize, balance: u64) -> Vec<Coin> {
+        (0..count).map(|_| coin(coin_type, balance)).collect()
+    }
+
+    // the MergeCoins commands as (destination, number of merged coins)
+    pub(crate) fn merges(
+        pt: &sui_types::transaction::ProgrammableTransaction,
+    ) -> Vec<(Argument, usize)> {
+        pt.commands
+            .iter()
+            .filter_map(|c| match c {
+                Command::MergeCoins(primary, coins) => Some((*primary, coins.len())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select_largest_first() {
+        let all_coin = [5, 30, 10, 20]
+            .into_iter()
+            .map(|b| coin(SUI_NATIVE_COIN, b))
+            .collect::<Vec<_>>();
+
+        let helper = SelectCoinHelper::select_from(all_coin.clone(), SUI_NATIVE_COIN, 45).unwrap();
+        let selected = helper
+            .select_coins
+            .iter()
+            .map(|c| c.balance)
+            .collect::<Vec<_>>();
+        assert_eq!(selected, vec![30, 20]);
+        // sui coins also pay the gas
+        assert_eq!(helper.gas_amount(), 50);
+
+        let helper = SelectCoinHelper::select_from(all_coin.clone(), TOKEN, 30).unwrap();
+        assert_eq!(helper.select_coins.len(), 1);
+        assert!(helper.gas_coin.is_empty());
+
+        assert!(SelectCoinHelper::select_from(all_coin, SUI_NATIVE_COIN, 66).is_err());
+
+        // more inputs than a transaction can take
+        let all_coin = coins(TOKEN, MAX_INPUT_OBJECTS + 1, 1);
+        let amount = all_coin.len() as u64;
+        assert!(SelectCoinHelper::select_from(all_coin, TOKEN, amount).is_err());
+    }
+
+    #[test]
+    fn test_merge_excess_gas_coin() {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let helper = SelectCoinHelper {
+            select_coins: vec![],
+            gas_coin: coins(SUI_NATIVE_COIN, MAX_GAS_OBJECTS, 1),
+        };
+        helper.merge_excess_gas_coin(&mut builder).unwrap();
+        assert!(builder.finish().commands.is_empty());
+
+        // the coins over the gas payment limit are merged into the gas coin in chunks
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let helper = SelectCoinHelper {
+            select_coins: vec![],
+            gas_coin: coins(SUI_NATIVE_COIN, MAX_GAS_OBJECTS + 600, 1),
+        };
+        helper.merge_excess_gas_coin(&mut builder).unwrap();
+        let pt = builder.finish();
+
+        assert_eq!(pt.inputs.len(), 600);
+        assert_eq!(
+            merges(&pt),
+            vec![
+                (Argument::GasCoin, MAX_COMMAND_ARGUMENTS),
+                (Argument::GasCoin, 600 - MAX_COMMAND_ARGUMENTS)
+            ]
+        );
+        assert_eq!(helper.gas_obj_ref().len(), MAX_GAS_OBJECTS);
+    }
+
+    #[test]
+    fn test_merge_coin_args() {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let inputs = (0..=1001).map(Argument::Input).collect::<Vec<_>>();
+
+        let primary = SelectCoinHelper::merge_coin_args(&mut builder, &inputs);
+        assert_eq!(primary, Argument::Input(0));
+        assert_eq!(
+            merges(&builder.finish()),
+            vec![
+                (Argument::Input(0), MAX_COMMAND_ARGUMENTS),
+                (Argument::Input(0), MAX_COMMAND_ARGUMENTS),
+                (Argument::Input(0), 1)
+            ]
+        );
+
+        // a single coin needs no merge
+        let mut builder = ProgrammableTransactionBuilder::new();
+        SelectCoinHelper::merge_coin_args(&mut builder, &inputs[..1]);
+        assert!(builder.finish().commands.is_empty());
+    }
+}
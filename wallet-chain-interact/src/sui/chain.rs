@@ -1,18 +1,20 @@
 use super::TransRespOpt;
 use super::consts::{self, DEFAULT_GAS_BUDGET, SUI_VALUE};
 use super::error::SuiError;
-use super::protocol::EstimateFeeResp;
+use super::move_call::MoveCallOpt;
+use super::multisig::SuiMultisigCollector;
+use super::protocol::{EstimateFeeResp, GasPreview, StakedSuiInfo, ValidatorInfo};
 use super::provider::Provider;
 use crate::types::ChainPrivateKey;
 use crate::{BillResourceConsume, QueryTransactionResult};
 use alloy::primitives::U256;
 use shared_crypto::intent::{Intent, IntentMessage};
 use sui_json_rpc_types::{
-    SuiTransactionBlockDataAPI, SuiTransactionBlockEffects, SuiTransactionBlockEffectsAPI as _,
-    SuiTransactionBlockResponse,
+    DevInspectResults, SuiTransactionBlockDataAPI, SuiTransactionBlockEffects,
+    SuiTransactionBlockEffectsAPI as _, SuiTransactionBlockResponse,
 };
 use sui_types::crypto::{AccountKeyPair, AccountPrivateKey, Signature};
-use sui_types::transaction::{ProgrammableTransaction, TransactionData};
+use sui_types::transaction::{ProgrammableTransaction, TransactionData, TransactionDataAPI};
 
 pub struct SuiChain {
     pub provider: Provider,
@@ -85,6 +87,56 @@ impl SuiChain {
         Ok(meta_data.name)
     }
 
+    // 当前活跃的验证节点以及年化收益
+    pub async fn validators(&self) -> crate::Result<Vec<ValidatorInfo>> {
+        let system_state = self.provider.get_latest_sui_system_state().await?;
+        let apys = self.provider.get_validators_apy().await?;
+
+        let mut validators = system_state
+            .active_validators
+            .into_iter()
+            .map(|v| {
+                let apy = apys
+                    .apys
+                    .iter()
+                    .find(|a| a.address == v.sui_address)
+                    .map(|a| a.apy);
+
+                ValidatorInfo {
+                    address: v.sui_address.to_string(),
+                    name: v.name,
+                    image_url: v.image_url,
+                    staking_pool_id: v.staking_pool_id.to_string(),
+                    total_stake: v.staking_pool_sui_balance,
+                    commission_rate: v.commission_rate,
+                    gas_price: v.gas_price,
+                    voting_power: v.voting_power,
+                    apy,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        validators.sort_by(|a, b| b.total_stake.cmp(&a.total_stake));
+        Ok(validators)
+    }
+
+    // 用户持有的质押对象(StakedSui)及预估收益
+    pub async fn staked_sui(&self, owner: &str) -> crate::Result<Vec<StakedSuiInfo>> {
+        let delegated = self.provider.get_stakes(owner).await?;
+
+        let mut result = vec![];
+        for item in delegated {
+            for stake in item.stakes {
+                result.push(StakedSuiInfo::new(
+                    &item.validator_address,
+                    &item.staking_pool,
+                    stake,
+                ));
+            }
+        }
+        Ok(result)
+    }
+
     pub async fn estimate_fee(
         &self,
         sender: &str,
@@ -112,6 +164,26 @@ impl SuiChain {
         Ok(EstimateFeeResp::new(gas_used, gas_price))
     }
 
+    // 用完整的交易数据 dry run,预览实际扣除的gas
+    pub async fn dry_run_gas(&self, tx_data: &TransactionData) -> crate::Result<GasPreview> {
+        let result = self.provider.dry_run_transaction(tx_data).await?;
+
+        if result.effects.status().is_err() {
+            return Err(SuiError::GasError(result.effects.status().to_string()))?;
+        }
+
+        let summary = result.effects.gas_cost_summary();
+        Ok(GasPreview {
+            gas_budget: tx_data.gas_budget(),
+            gas_price: tx_data.gas_price(),
+            computation_cost: summary.computation_cost,
+            storage_cost: summary.storage_cost,
+            storage_rebate: summary.storage_rebate,
+            non_refundable_storage_fee: summary.non_refundable_storage_fee,
+            net_gas: summary.net_gas_usage(),
+        })
+    }
+
     pub async fn exec(
         &self,
         tx_data: TransactionData,
@@ -174,6 +246,70 @@ impl SuiChain {
     }
 }
 
+// about move call
+impl SuiChain {
+    // dev inspect the chained commands before signing, the results carry the return values
+    pub async fn preview_move_call(&self, opt: &MoveCallOpt) -> crate::Result<DevInspectResults> {
+        let mut helper = opt.select_coin(&self.provider).await?;
+        let pt = opt.build_pt(&self.provider, &mut helper, None).await?;
+
+        let gas_price = self.provider.get_reference_gas_price().await?;
+        let result = self
+            .provider
+            .dev_inspect_transaction(&opt.sender.to_string(), pt, gas_price)
+            .await?;
+
+        if let Some(err) = &result.error {
+            return Err(SuiError::MoveError(err.clone()))?;
+        }
+        Ok(result)
+    }
+}
+
+// about multisig
+impl SuiChain {
+    // the transaction is built with the multisig address as sender
+    pub async fn exec_multisig(&self, collector: &SuiMultisigCollector) -> crate::Result<String> {
+        let signature = collector.combine()?;
+
+        let tx_bytes = wallet_utils::serde_func::bcs_to_bytes(&collector.tx_data)?;
+        let tx_data_base64 = wallet_utils::bytes_to_base64(&tx_bytes);
+        let sig_b64 = wallet_utils::bytes_to_base64(signature.as_ref());
+
+        let tx_hash = self
+            .provider
+            .send_transaction(tx_data_base64, vec![sig_b64])
+            .await?;
+        Ok(tx_hash.digest.to_string())
+    }
+}
+
+// about suins
+impl SuiChain {
+    pub async fn resolve_name(
+        &self,
+        name: &str,
+    ) -> crate::Result<Option<sui_types::base_types::SuiAddress>> {
+        let name = crate::name::normalize(name)?;
+
+        match self.provider.resolve_name_service_address(&name).await? {
+            Some(addr) => Ok(Some(wallet_utils::address::parse_sui_address(&addr)?)),
+            None => Ok(None),
+        }
+    }
+
+    // default name of the address
+    pub async fn lookup_address(&self, addr: &str) -> crate::Result<Option<String>> {
+        let addr = wallet_utils::address::parse_sui_address(addr)?;
+
+        let page = self
+            .provider
+            .resolve_name_service_names(&addr.to_string(), 1)
+            .await?;
+        Ok(page.data.into_iter().next())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
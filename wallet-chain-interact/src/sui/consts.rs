@@ -4,3 +4,13 @@ pub const SUI_VALUE: f64 = 1_000_000_000.0;
 
 // 默认的手续费预算，在预估手续费为0的时候
 pub const DEFAULT_GAS_BUDGET: u64 = 2_000_000;
+
+// 最少质押 1 SUI
+pub const MIN_STAKING_THRESHOLD: u64 = 1_000_000_000;
+
+// 交易中gas支付对象的最大数量(协议限制 256)
+pub const MAX_GAS_OBJECTS: usize = 256;
+// 单个 MergeCoins/SplitCoins 命令的最大参数数量(协议限制 511)
+pub const MAX_COMMAND_ARGUMENTS: usize = 500;
+// 单笔交易输入对象的最大数量(协议限制 2048,预留部分给其他输入)
+pub const MAX_INPUT_OBJECTS: usize = 2000;
@@ -10,4 +10,6 @@ pub enum SuiError {
     GasError(String),
     #[error("Insufficient fee: only {0} available, but {1} needed")]
     InsufficientFee(u64, u64),
+    #[error("Too many coin objects: {0} selected, at most {1} per transaction, merge coins first")]
+    TooManyCoins(usize, usize),
 }
@@ -0,0 +1,375 @@
+use crate::sui::{
+    Provider,
+    builder::SelectCoinHelper,
+    consts::{MAX_COMMAND_ARGUMENTS, MAX_INPUT_OBJECTS, SUI_NATIVE_COIN},
+    error::SuiError,
+    protocol::EstimateFeeResp,
+};
+use alloy::primitives::U256;
+use sui_json_rpc_types::Coin;
+use sui_types::{
+    base_types::SuiAddress,
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{Argument, Command, ObjectArg, ProgrammableTransaction, TransactionData},
+};
+use wallet_utils::address;
+
+// 合并某种币的所有coin对象
+pub struct MergeCoinOpt {
+    pub owner: SuiAddress,
+    pub coin_type: String,
+}
+
+impl MergeCoinOpt {
+    pub fn new(owner: &str, coin_type: Option<String>) -> crate::Result<Self> {
+        Ok(Self {
+            owner: address::parse_sui_address(owner)?,
+            coin_type: coin_type.unwrap_or(SUI_NATIVE_COIN.to_string()),
+        })
+    }
+
+    fn is_native(&self) -> bool {
+        self.coin_type == SUI_NATIVE_COIN
+    }
+
+    // 单笔交易最多合并 MAX_INPUT_OBJECTS 个coin,剩余的需要再次合并
+    pub async fn select_coin(&self, provider: &Provider) -> crate::Result<SelectCoinHelper> {
+        let all_coin = provider
+            .get_all_coins_by_owner(&self.owner.to_string(), &self.coin_type)
+            .await?;
+        self.helper(all_coin)
+    }
+
+    fn helper(&self, mut all_coin: Vec<Coin>) -> crate::Result<SelectCoinHelper> {
+        if all_coin.len() < 2 {
+            return Err(crate::Error::Other("no coins need to merge".to_string()));
+        }
+
+        all_coin.sort_by(|a, b| b.balance.cmp(&a.balance));
+        all_coin.truncate(MAX_INPUT_OBJECTS);
+
+        // sui 的coin全部作为gas支付对象,执行时由节点自动合并
+        let helper = if self.is_native() {
+            SelectCoinHelper {
+                select_coins: vec![],
+                gas_coin: all_coin,
+            }
+        } else {
+            SelectCoinHelper {
+                select_coins: all_coin,
+                gas_coin: vec![],
+            }
+        };
+        Ok(helper)
+    }
+
+    pub async fn build_pt(
+        &self,
+        provider: &Provider,
+        helper: &mut SelectCoinHelper,
+        gas_budget: Option<u64>,
+    ) -> crate::Result<ProgrammableTransaction> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let gas_fee = gas_budget.unwrap_or(0);
+
+        if self.is_native() {
+            helper.merge_excess_gas_coin(&mut builder)?;
+
+            // 合并后的gas coin 转给自己
+            let receipt = builder
+                .pure(self.owner)
+                .map_err(|e| SuiError::MoveError(e.to_string()))?;
+            builder.command(Command::TransferObjects(vec![Argument::GasCoin], receipt));
+        } else {
+            if helper.need_extra_coin_pay_gas(&self.coin_type, gas_fee, 0) {
+                let gas_coin = helper
+                    .select_gas_coin(gas_fee, provider, &self.owner.to_string())
+                    .await?;
+                helper.add_gas_coin(gas_coin);
+            }
+
+            let mut coin_inputs = vec![];
+            for coin in helper.select_coins.iter() {
+                let coin_arg = builder
+                    .obj(ObjectArg::ImmOrOwnedObject(coin.object_ref()))
+                    .map_err(|e| SuiError::MoveError(e.to_string()))?;
+                coin_inputs.push(coin_arg);
+            }
+            SelectCoinHelper::merge_coin_args(&mut builder, &coin_inputs);
+        }
+
+        Ok(builder.finish())
+    }
+
+    pub async fn build_data(
+        &self,
+        provider: &Provider,
+        mut helper: SelectCoinHelper,
+        gas: EstimateFeeResp,
+    ) -> crate::Result<TransactionData> {
+        let gas_budget = gas.get_fee();
+
+        let pt = self
+            .build_pt(provider, &mut helper, Some(gas_budget))
+            .await?;
+
+        Ok(TransactionData::new_programmable(
+            self.owner,
+            helper.gas_obj_ref(),
+            pt,
+            gas_budget,
+            gas.gas_price,
+        ))
+    }
+}
+
+// 拆分出 count 个金额为 amount 的coin对象
+pub struct SplitCoinOpt {
+    pub owner: SuiAddress,
+    pub coin_type: String,
+    pub amount: u64,
+    pub count: usize,
+}
+
+impl SplitCoinOpt {
+    pub fn new(
+        owner: &str,
+        coin_type: Option<String>,
+        amount: U256,
+        count: usize,
+    ) -> crate::Result<Self> {
+        if count == 0 || count > MAX_COMMAND_ARGUMENTS {
+            return Err(crate::Error::Other(format!(
+                "split count must be between 1 and {}",
+                MAX_COMMAND_ARGUMENTS
+            )));
+        }
+
+        let amount = amount.to::<u64>();
+        if amount.checked_mul(count as u64).is_none() {
+            return Err(crate::Error::Other(format!(
+                "split amount {} * {} overflows",
+                amount, count
+            )));
+        }
+
+        Ok(Self {
+            owner: address::parse_sui_address(owner)?,
+            coin_type: coin_type.unwrap_or(SUI_NATIVE_COIN.to_string()),
+            amount,
+            count,
+        })
+    }
+
+    fn is_native(&self) -> bool {
+        self.coin_type == SUI_NATIVE_COIN
+    }
+
+    // checked in new
+    fn total_amount(&self) -> u64 {
+        self.amount * self.count as u64
+    }
+
+    pub async fn select_coin(&self, provider: &Provider) -> crate::Result<SelectCoinHelper> {
+        SelectCoinHelper::select(
+            provider,
+            &self.owner.to_string(),
+            &self.coin_type,
+            self.total_amount(),
+        )
+        .await
+    }
+
+    pub async fn build_pt(
+        &self,
+        provider: &Provider,
+        helper: &mut SelectCoinHelper,
+        gas_budget: Option<u64>,
+    ) -> crate::Result<ProgrammableTransaction> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let gas_fee = gas_budget.unwrap_or(0);
+
+        if helper.need_extra_coin_pay_gas(&self.coin_type, gas_fee, self.total_amount()) {
+            let need = if self.is_native() {
+                gas_fee + self.total_amount()
+            } else {
+                gas_fee
+            };
+            let gas_coin = helper
+                .select_gas_coin(need, provider, &self.owner.to_string())
+                .await?;
+            helper.add_gas_coin(gas_coin);
+        }
+        helper.merge_excess_gas_coin(&mut builder)?;
+
+        let source = if self.is_native() {
+            Argument::GasCoin
+        } else {
+            let mut coin_inputs = vec![];
+            for coin in helper.select_coins.iter() {
+                let coin_arg = builder
+                    .obj(ObjectArg::ImmOrOwnedObject(coin.object_ref()))
+                    .map_err(|e| SuiError::MoveError(e.to_string()))?;
+                coin_inputs.push(coin_arg);
+            }
+            SelectCoinHelper::merge_coin_args(&mut builder, &coin_inputs)
+        };
+
+        let mut amounts = vec![];
+        for _ in 0..self.count {
+            let amount = builder
+                .pure(self.amount)
+                .map_err(|e| SuiError::MoveError(e.to_string()))?;
+            amounts.push(amount);
+        }
+
+        let Argument::Result(index) = builder.command(Command::SplitCoins(source, amounts)) else {
+            return Err(SuiError::MoveError("split coins result".to_string()).into());
+        };
+        let new_coins = (0..self.count)
+            .map(|i| Argument::NestedResult(index, i as u16))
+            .collect::<Vec<_>>();
+
+        let receipt = builder
+            .pure(self.owner)
+            .map_err(|e| SuiError::MoveError(e.to_string()))?;
+        builder.command(Command::TransferObjects(new_coins, receipt));
+
+        Ok(builder.finish())
+    }
+
+    pub async fn build_data(
+        &self,
+        provider: &Provider,
+        mut helper: SelectCoinHelper,
+        gas: EstimateFeeResp,
+    ) -> crate::Result<TransactionData> {
+        let gas_budget = gas.get_fee();
+
+        let pt = self
+            .build_pt(provider, &mut helper, Some(gas_budget))
+            .await?;
+
+        Ok(TransactionData::new_programmable(
+            self.owner,
+            helper.gas_obj_ref(),
+            pt,
+            gas_budget,
+            gas.gas_price,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui::builder::tests::{TOKEN, coin, coins, merges, provider};
+
+    const OWNER: &str = "0x885f29a4f1b4d63822728a1b1811d0278c4e25f27d3754ddd387cd34f9482d0f";
+
+    #[tokio::test]
+    async fn test_merge_native() {
+        let opt = MergeCoinOpt::new(OWNER, None).unwrap();
+        assert!(opt.helper(coins(SUI_NATIVE_COIN, 1, 1)).is_err());
+
+        // the first 256 coins pay the gas, the rest are merged into the gas coin
+        let mut helper = opt.helper(coins(SUI_NATIVE_COIN, 300, 1)).unwrap();
+        assert!(helper.select_coins.is_empty());
+
+        let pt = opt.build_pt(&provider(), &mut helper, None).await.unwrap();
+        assert_eq!(merges(&pt), vec![(Argument::GasCoin, 300 - 256)]);
+        assert_eq!(
+            pt.commands.last(),
+            Some(&Command::TransferObjects(
+                vec![Argument::GasCoin],
+                Argument::Input(300 - 256)
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_token() {
+        let opt = MergeCoinOpt::new(OWNER, Some(TOKEN.to_string())).unwrap();
+
+        let helper = opt.helper(coins(TOKEN, MAX_INPUT_OBJECTS + 10, 1)).unwrap();
+        assert_eq!(helper.select_coins.len(), MAX_INPUT_OBJECTS);
+
+        // merged into the first coin, 500 coins per command
+        let mut helper = opt.helper(coins(TOKEN, 600, 1)).unwrap();
+        let pt = opt.build_pt(&provider(), &mut helper, None).await.unwrap();
+        assert_eq!(pt.inputs.len(), 600);
+        assert_eq!(
+            merges(&pt),
+            vec![
+                (Argument::Input(0), MAX_COMMAND_ARGUMENTS),
+                (Argument::Input(0), 599 - MAX_COMMAND_ARGUMENTS)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_amount() {
+        let amount = U256::from(u64::MAX / 2);
+        assert!(SplitCoinOpt::new(OWNER, None, amount, 3).is_err());
+        assert!(SplitCoinOpt::new(OWNER, None, U256::from(1), 0).is_err());
+        assert!(SplitCoinOpt::new(OWNER, None, U256::from(1), MAX_COMMAND_ARGUMENTS + 1).is_err());
+
+        let opt = SplitCoinOpt::new(OWNER, None, amount, 2).unwrap();
+        assert_eq!(opt.total_amount(), u64::MAX - 1);
+    }
+
+    // the split coins and the transfer of all of them to the owner
+    fn assert_split(pt: &ProgrammableTransaction, source: Argument, count: usize) {
+        let len = pt.commands.len();
+        let Command::SplitCoins(from, amounts) = &pt.commands[len - 2] else {
+            panic!("expect split coins");
+        };
+        assert_eq!(*from, source);
+        assert_eq!(amounts.len(), count);
+
+        let Command::TransferObjects(new_coins, _) = &pt.commands[len - 1] else {
+            panic!("expect transfer objects");
+        };
+        let index = (len - 2) as u16;
+        let expected = (0..count as u16)
+            .map(|i| Argument::NestedResult(index, i))
+            .collect::<Vec<_>>();
+        assert_eq!(new_coins, &expected);
+    }
+
+    #[tokio::test]
+    async fn test_split_native() {
+        let opt = SplitCoinOpt::new(OWNER, None, U256::from(1_000_000_000u64), 3).unwrap();
+
+        let coins = coins(SUI_NATIVE_COIN, 300, 100_000_000);
+        let mut helper = SelectCoinHelper {
+            select_coins: coins.clone(),
+            gas_coin: coins,
+        };
+        let pt = opt
+            .build_pt(&provider(), &mut helper, Some(2_000_000))
+            .await
+            .unwrap();
+
+        // excess gas coins are merged before splitting from the gas coin
+        assert_eq!(merges(&pt), vec![(Argument::GasCoin, 300 - 256)]);
+        assert_split(&pt, Argument::GasCoin, 3);
+    }
+
+    #[tokio::test]
+    async fn test_split_token() {
+        let opt = SplitCoinOpt::new(OWNER, Some(TOKEN.to_string()), U256::from(10), 2).unwrap();
+
+        let mut helper = SelectCoinHelper {
+            select_coins: vec![coin(TOKEN, 15), coin(TOKEN, 5)],
+            gas_coin: vec![coin(SUI_NATIVE_COIN, 1_000_000_000)],
+        };
+        let pt = opt
+            .build_pt(&provider(), &mut helper, Some(2_000_000))
+            .await
+            .unwrap();
+
+        assert_eq!(merges(&pt), vec![(Argument::Input(0), 1)]);
+        assert_split(&pt, Argument::Input(0), 2);
+    }
+}
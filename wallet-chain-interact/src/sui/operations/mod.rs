@@ -1 +1,5 @@
+pub mod coin;
+pub mod move_call;
+pub mod multisig;
+pub mod stake;
 pub mod transfer;
@@ -0,0 +1,780 @@
+// 通用 move call: 根据 normalized module 的函数签名解析参数,支持多个命令串联
+use crate::sui::{
+    Provider,
+    builder::{self, SelectCoinHelper},
+    consts::SUI_NATIVE_COIN,
+    error::SuiError,
+    protocol::EstimateFeeResp,
+};
+use std::collections::BTreeMap;
+use sui_json_rpc_types::{SuiMoveNormalizedModule, SuiMoveNormalizedType};
+use sui_types::{
+    TypeTag,
+    base_types::{ObjectID, SuiAddress},
+    object::Owner,
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{Argument, Command, ObjectArg, ProgrammableTransaction, TransactionData},
+};
+use wallet_utils::address;
+
+/// Argument of a command, `Result` and `NestedResult` index the commands of the same builder.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PtbArg {
+    // object id or a pure value, the kind comes from the function signature
+    Value(serde_json::Value),
+    GasCoin,
+    Result(u16),
+    NestedResult(u16, u16),
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PtbCommand {
+    MoveCall {
+        package: String,
+        module: String,
+        function: String,
+        type_arguments: Vec<String>,
+        arguments: Vec<PtbArg>,
+    },
+    SplitCoins {
+        coin: PtbArg,
+        amounts: Vec<u64>,
+    },
+    MergeCoins {
+        destination: PtbArg,
+        sources: Vec<PtbArg>,
+    },
+    TransferObjects {
+        objects: Vec<PtbArg>,
+        recipient: String,
+    },
+}
+
+/// Move types of a function signature, addresses are normalized to object ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<MoveType>),
+    Struct {
+        address: ObjectID,
+        module: String,
+        name: String,
+        type_arguments: Vec<MoveType>,
+    },
+    TypeParameter(u16),
+    Reference(Box<MoveType>),
+    MutableReference(Box<MoveType>),
+}
+
+fn parse_object_id(id: &str) -> crate::Result<ObjectID> {
+    Ok(ObjectID::from_hex_literal(id)
+        .map_err(|e| crate::ParseErr::AddressPraseErr(e.to_string()))?)
+}
+
+impl MoveType {
+    pub fn from_normalized(ty: &SuiMoveNormalizedType) -> crate::Result<Self> {
+        let res = match ty {
+            SuiMoveNormalizedType::Bool => MoveType::Bool,
+            SuiMoveNormalizedType::U8 => MoveType::U8,
+            SuiMoveNormalizedType::U16 => MoveType::U16,
+            SuiMoveNormalizedType::U32 => MoveType::U32,
+            SuiMoveNormalizedType::U64 => MoveType::U64,
+            SuiMoveNormalizedType::U128 => MoveType::U128,
+            SuiMoveNormalizedType::U256 => MoveType::U256,
+            SuiMoveNormalizedType::Address => MoveType::Address,
+            SuiMoveNormalizedType::Signer => MoveType::Signer,
+            SuiMoveNormalizedType::Struct { inner } => MoveType::Struct {
+                address: parse_object_id(&inner.address)?,
+                module: inner.module.clone(),
+                name: inner.name.clone(),
+                type_arguments: inner
+                    .type_arguments
+                    .iter()
+                    .map(Self::from_normalized)
+                    .collect::<crate::Result<_>>()?,
+            },
+            SuiMoveNormalizedType::Vector(t) => {
+                MoveType::Vector(Box::new(Self::from_normalized(t)?))
+            }
+            SuiMoveNormalizedType::TypeParameter(i) => MoveType::TypeParameter(*i),
+            SuiMoveNormalizedType::Reference(t) => {
+                MoveType::Reference(Box::new(Self::from_normalized(t)?))
+            }
+            SuiMoveNormalizedType::MutableReference(t) => {
+                MoveType::MutableReference(Box::new(Self::from_normalized(t)?))
+            }
+        };
+        Ok(res)
+    }
+
+    pub fn from_type_tag(tag: &TypeTag) -> Self {
+        match tag {
+            TypeTag::Bool => MoveType::Bool,
+            TypeTag::U8 => MoveType::U8,
+            TypeTag::U16 => MoveType::U16,
+            TypeTag::U32 => MoveType::U32,
+            TypeTag::U64 => MoveType::U64,
+            TypeTag::U128 => MoveType::U128,
+            TypeTag::U256 => MoveType::U256,
+            TypeTag::Address => MoveType::Address,
+            TypeTag::Signer => MoveType::Signer,
+            TypeTag::Vector(t) => MoveType::Vector(Box::new(Self::from_type_tag(t))),
+            TypeTag::Struct(s) => MoveType::Struct {
+                address: ObjectID::from(s.address),
+                module: s.module.to_string(),
+                name: s.name.to_string(),
+                type_arguments: s.type_params.iter().map(Self::from_type_tag).collect(),
+            },
+        }
+    }
+
+    // replace the type parameters with the type arguments of the call
+    pub fn substitute(&self, type_arguments: &[TypeTag]) -> crate::Result<Self> {
+        let res = match self {
+            MoveType::TypeParameter(i) => {
+                let tag = type_arguments
+                    .get(*i as usize)
+                    .ok_or(SuiError::MoveError(format!("missing type argument {}", i)))?;
+                Self::from_type_tag(tag)
+            }
+            MoveType::Vector(t) => MoveType::Vector(Box::new(t.substitute(type_arguments)?)),
+            MoveType::Reference(t) => MoveType::Reference(Box::new(t.substitute(type_arguments)?)),
+            MoveType::MutableReference(t) => {
+                MoveType::MutableReference(Box::new(t.substitute(type_arguments)?))
+            }
+            MoveType::Struct {
+                address,
+                module,
+                name,
+                type_arguments: args,
+            } => MoveType::Struct {
+                address: *address,
+                module: module.clone(),
+                name: name.clone(),
+                type_arguments: args
+                    .iter()
+                    .map(|t| t.substitute(type_arguments))
+                    .collect::<crate::Result<_>>()?,
+            },
+            other => other.clone(),
+        };
+        Ok(res)
+    }
+
+    fn is_struct(&self, addr: u8, module_name: &str, struct_name: &str) -> bool {
+        match self {
+            MoveType::Struct {
+                address,
+                module,
+                name,
+                ..
+            } => {
+                let mut expected = [0u8; ObjectID::LENGTH];
+                expected[ObjectID::LENGTH - 1] = addr;
+                address.into_bytes() == expected && module == module_name && name == struct_name
+            }
+            _ => false,
+        }
+    }
+
+    // the tx context is filled in by the runtime
+    pub fn is_tx_context(&self) -> bool {
+        match self {
+            MoveType::Reference(t) | MoveType::MutableReference(t) => {
+                t.is_struct(2, "tx_context", "TxContext")
+            }
+            _ => false,
+        }
+    }
+
+    // structs passed as pure bytes
+    fn is_pure_struct(&self) -> bool {
+        self.is_struct(1, "string", "String")
+            || self.is_struct(1, "ascii", "String")
+            || self.is_struct(2, "object", "ID")
+            || self.is_struct(1, "option", "Option")
+    }
+
+    pub fn kind(&self) -> ParamKind {
+        match self {
+            MoveType::Reference(_) => ParamKind::Object { mutable: false },
+            MoveType::MutableReference(_) => ParamKind::Object { mutable: true },
+            MoveType::Struct { .. } if self.is_struct(2, "transfer", "Receiving") => {
+                ParamKind::Receiving
+            }
+            MoveType::Struct { .. } if !self.is_pure_struct() => {
+                ParamKind::Object { mutable: true }
+            }
+            MoveType::Vector(t) if matches!(t.kind(), ParamKind::Object { .. }) => {
+                ParamKind::ObjectVector
+            }
+            _ => ParamKind::Pure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    Pure,
+    // objects taken by value are treated as mutable
+    Object { mutable: bool },
+    ObjectVector,
+    Receiving,
+}
+
+fn write_uleb128(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn json_number<T: std::str::FromStr>(value: &serde_json::Value) -> crate::Result<T> {
+    let text = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        _ => String::new(),
+    };
+    text.parse::<T>()
+        .map_err(|_| crate::ParseErr::ValueErr(format!("expected a number, got {}", value)).into())
+}
+
+fn json_str(value: &serde_json::Value) -> crate::Result<&str> {
+    value
+        .as_str()
+        .ok_or(crate::ParseErr::ValueErr(format!("expected a string, got {}", value)).into())
+}
+
+// bcs bytes of a json value, numbers may be given as strings
+pub fn pure_bytes(
+    ty: &MoveType,
+    value: &serde_json::Value,
+    out: &mut Vec<u8>,
+) -> crate::Result<()> {
+    match ty {
+        MoveType::Bool => {
+            let value = value.as_bool().ok_or(crate::ParseErr::ValueErr(format!(
+                "expected a bool, got {}",
+                value
+            )))?;
+            out.push(value as u8);
+        }
+        MoveType::U8 => out.push(json_number::<u8>(value)?),
+        MoveType::U16 => out.extend(json_number::<u16>(value)?.to_le_bytes()),
+        MoveType::U32 => out.extend(json_number::<u32>(value)?.to_le_bytes()),
+        MoveType::U64 => out.extend(json_number::<u64>(value)?.to_le_bytes()),
+        MoveType::U128 => out.extend(json_number::<u128>(value)?.to_le_bytes()),
+        MoveType::U256 => {
+            let value = json_number::<alloy::primitives::U256>(value)?;
+            out.extend(value.to_le_bytes::<32>());
+        }
+        MoveType::Address => {
+            let addr = address::parse_sui_address(json_str(value)?)?;
+            out.extend(addr.to_inner());
+        }
+        MoveType::Vector(t) => match value {
+            // vector<u8> from a hex or utf8 string
+            serde_json::Value::String(s) if **t == MoveType::U8 => {
+                let bytes = match s.strip_prefix("0x") {
+                    Some(hex) => wallet_utils::hex_func::hex_decode(hex)?,
+                    None => s.as_bytes().to_vec(),
+                };
+                write_uleb128(out, bytes.len());
+                out.extend(bytes);
+            }
+            serde_json::Value::Array(items) => {
+                write_uleb128(out, items.len());
+                for item in items {
+                    pure_bytes(t, item, out)?;
+                }
+            }
+            _ => {
+                return Err(crate::ParseErr::ValueErr(format!(
+                    "expected an array, got {}",
+                    value
+                )))?;
+            }
+        },
+        MoveType::Struct { type_arguments, .. } if ty.is_struct(1, "option", "Option") => {
+            let inner = type_arguments
+                .first()
+                .ok_or(SuiError::MoveError("option without type".to_string()))?;
+            if value.is_null() {
+                out.push(0);
+            } else {
+                out.push(1);
+                pure_bytes(inner, value, out)?;
+            }
+        }
+        MoveType::Struct { .. } if ty.is_struct(2, "object", "ID") => {
+            out.extend(parse_object_id(json_str(value)?)?.into_bytes());
+        }
+        MoveType::Struct { .. } if ty.is_pure_struct() => {
+            let text = json_str(value)?;
+            write_uleb128(out, text.len());
+            out.extend(text.as_bytes());
+        }
+        _ => {
+            return Err(SuiError::MoveError(format!("{:?} is not a pure type", ty)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Programmable transaction of chained commands, object and pure arguments are
+/// resolved from the normalized signature of the called functions.
+pub struct MoveCallOpt {
+    pub sender: SuiAddress,
+    pub commands: Vec<PtbCommand>,
+}
+
+impl MoveCallOpt {
+    pub fn new(sender: &str) -> crate::Result<Self> {
+        Ok(Self {
+            sender: address::parse_sui_address(sender)?,
+            commands: vec![],
+        })
+    }
+
+    pub fn move_call(
+        mut self,
+        package: &str,
+        module: &str,
+        function: &str,
+        type_arguments: Vec<String>,
+        arguments: Vec<PtbArg>,
+    ) -> Self {
+        self.commands.push(PtbCommand::MoveCall {
+            package: package.to_string(),
+            module: module.to_string(),
+            function: function.to_string(),
+            type_arguments,
+            arguments,
+        });
+        self
+    }
+
+    pub fn split_coins(mut self, coin: PtbArg, amounts: Vec<u64>) -> Self {
+        self.commands.push(PtbCommand::SplitCoins { coin, amounts });
+        self
+    }
+
+    pub fn merge_coins(mut self, destination: PtbArg, sources: Vec<PtbArg>) -> Self {
+        self.commands.push(PtbCommand::MergeCoins {
+            destination,
+            sources,
+        });
+        self
+    }
+
+    pub fn transfer_objects(mut self, objects: Vec<PtbArg>, recipient: &str) -> Self {
+        self.commands.push(PtbCommand::TransferObjects {
+            objects,
+            recipient: recipient.to_string(),
+        });
+        self
+    }
+
+    // sui split from the gas coin, the gas coins have to cover it besides the fee
+    pub fn gas_coin_spend(&self) -> u64 {
+        self.commands
+            .iter()
+            .filter_map(|c| match c {
+                PtbCommand::SplitCoins {
+                    coin: PtbArg::GasCoin,
+                    amounts,
+                } => Some(amounts.iter().sum::<u64>()),
+                _ => None,
+            })
+            .sum()
+    }
+
+    pub async fn select_coin(&self, provider: &Provider) -> crate::Result<SelectCoinHelper> {
+        SelectCoinHelper::select(
+            provider,
+            &self.sender.to_string(),
+            SUI_NATIVE_COIN,
+            self.gas_coin_spend(),
+        )
+        .await
+    }
+
+    pub async fn build_pt(
+        &self,
+        provider: &Provider,
+        helper: &mut SelectCoinHelper,
+        gas_budget: Option<u64>,
+    ) -> crate::Result<ProgrammableTransaction> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let gas_fee = gas_budget.unwrap_or(0);
+        let spend = self.gas_coin_spend();
+
+        if helper.need_extra_coin_pay_gas(SUI_NATIVE_COIN, gas_fee, spend) {
+            let gas_coin = helper
+                .select_gas_coin(gas_fee + spend, provider, &self.sender.to_string())
+                .await?;
+            helper.add_gas_coin(gas_coin);
+        }
+        helper.merge_excess_gas_coin(&mut builder)?;
+
+        let mut resolver = ArgResolver::new(provider);
+        for command in self.commands.iter() {
+            let result = resolver.command(&mut builder, command).await?;
+            resolver.results.push(result);
+        }
+
+        Ok(builder.finish())
+    }
+
+    pub async fn build_data(
+        &self,
+        provider: &Provider,
+        mut helper: SelectCoinHelper,
+        gas: EstimateFeeResp,
+    ) -> crate::Result<TransactionData> {
+        let gas_budget = gas.get_fee();
+
+        let pt = self
+            .build_pt(provider, &mut helper, Some(gas_budget))
+            .await?;
+
+        Ok(TransactionData::new_programmable(
+            self.sender,
+            helper.gas_obj_ref(),
+            pt,
+            gas_budget,
+            gas.gas_price,
+        ))
+    }
+}
+
+struct ArgResolver<'a> {
+    provider: &'a Provider,
+    // normalized modules by package
+    packages: BTreeMap<ObjectID, BTreeMap<String, SuiMoveNormalizedModule>>,
+    // result of each command
+    results: Vec<Argument>,
+}
+
+impl<'a> ArgResolver<'a> {
+    fn new(provider: &'a Provider) -> Self {
+        Self {
+            provider,
+            packages: BTreeMap::new(),
+            results: vec![],
+        }
+    }
+
+    // the gas coin and arguments produced by earlier commands
+    fn chained(&self, arg: &PtbArg) -> crate::Result<Argument> {
+        let missing = |i: &u16| SuiError::MoveError(format!("command {} has no result yet", i));
+
+        match arg {
+            PtbArg::Value(value) => Err(SuiError::MoveError(format!(
+                "{} is not a command result",
+                value
+            )))?,
+            PtbArg::GasCoin => Ok(Argument::GasCoin),
+            PtbArg::Result(i) => Ok(*self.results.get(*i as usize).ok_or(missing(i))?),
+            PtbArg::NestedResult(i, j) => match self.results.get(*i as usize) {
+                Some(Argument::Result(index)) => Ok(Argument::NestedResult(*index, *j)),
+                _ => Err(missing(i))?,
+            },
+        }
+    }
+
+    async fn object_arg(&self, id: &str, kind: ParamKind) -> crate::Result<ObjectArg> {
+        let object = self
+            .provider
+            .get_object_with_owner(id)
+            .await?
+            .data
+            .ok_or(crate::Error::Other(format!("object {} not found", id)))?;
+
+        if kind == ParamKind::Receiving {
+            return Ok(ObjectArg::Receiving(object.object_ref()));
+        }
+
+        match object.owner {
+            Some(Owner::Shared {
+                initial_shared_version,
+            }) => Ok(ObjectArg::SharedObject {
+                id: object.object_id,
+                initial_shared_version,
+                mutable: kind == ParamKind::Object { mutable: true },
+            }),
+            Some(Owner::AddressOwner(_)) | Some(Owner::Immutable) => {
+                Ok(ObjectArg::ImmOrOwnedObject(object.object_ref()))
+            }
+            owner => Err(crate::Error::Other(format!(
+                "object {} owned by {:?} can not be an input",
+                id, owner
+            ))),
+        }
+    }
+
+    async fn object(
+        &self,
+        builder: &mut ProgrammableTransactionBuilder,
+        arg: &PtbArg,
+    ) -> crate::Result<Argument> {
+        let value = match arg {
+            PtbArg::Value(value) => value,
+            chained => return self.chained(chained),
+        };
+
+        let object = self
+            .object_arg(json_str(value)?, ParamKind::Object { mutable: true })
+            .await?;
+        Ok(builder
+            .obj(object)
+            .map_err(|e| SuiError::MoveError(e.to_string()))?)
+    }
+
+    async fn argument(
+        &self,
+        builder: &mut ProgrammableTransactionBuilder,
+        ty: &MoveType,
+        arg: &PtbArg,
+    ) -> crate::Result<Argument> {
+        let value = match arg {
+            PtbArg::Value(value) => value,
+            chained => return self.chained(chained),
+        };
+
+        let argument = match ty.kind() {
+            ParamKind::Pure => {
+                let mut bytes = vec![];
+                pure_bytes(ty, value, &mut bytes)?;
+                builder.pure_bytes(bytes, false)
+            }
+            kind @ (ParamKind::Object { .. } | ParamKind::Receiving) => {
+                let object = self.object_arg(json_str(value)?, kind).await?;
+                builder
+                    .obj(object)
+                    .map_err(|e| SuiError::MoveError(e.to_string()))?
+            }
+            ParamKind::ObjectVector => {
+                let ids = value.as_array().ok_or(crate::ParseErr::ValueErr(format!(
+                    "expected an array of object ids, got {}",
+                    value
+                )))?;
+                let mut objects = vec![];
+                for id in ids {
+                    objects.push(
+                        self.object_arg(json_str(id)?, ParamKind::Object { mutable: true })
+                            .await?,
+                    );
+                }
+                builder
+                    .make_obj_vec(objects)
+                    .map_err(|e| SuiError::MoveError(e.to_string()))?
+            }
+        };
+        Ok(argument)
+    }
+
+    async fn parameters(
+        &mut self,
+        package: ObjectID,
+        module: &str,
+        function: &str,
+    ) -> crate::Result<Vec<MoveType>> {
+        if !self.packages.contains_key(&package) {
+            let modules = self
+                .provider
+                .get_normalized_move_modules_by_package_id(&package.to_hex_literal())
+                .await?;
+            self.packages.insert(package, modules);
+        }
+
+        let function = self
+            .packages
+            .get(&package)
+            .and_then(|m| m.get(module))
+            .and_then(|m| m.exposed_functions.get(function))
+            .ok_or(SuiError::MoveError(format!(
+                "function {}::{}::{} not found",
+                package, module, function
+            )))?;
+
+        let mut parameters = function
+            .parameters
+            .iter()
+            .map(MoveType::from_normalized)
+            .collect::<crate::Result<Vec<_>>>()?;
+        if parameters.last().is_some_and(|p| p.is_tx_context()) {
+            parameters.pop();
+        }
+        Ok(parameters)
+    }
+
+    async fn command(
+        &mut self,
+        builder: &mut ProgrammableTransactionBuilder,
+        command: &PtbCommand,
+    ) -> crate::Result<Argument> {
+        let res = match command {
+            PtbCommand::MoveCall {
+                package,
+                module,
+                function,
+                type_arguments,
+                arguments,
+            } => {
+                let package = parse_object_id(package)?;
+                let type_arguments = type_arguments
+                    .iter()
+                    .map(|t| {
+                        sui_types::parse_sui_type_tag(t)
+                            .map_err(|e| SuiError::MoveError(format!("type {}: {}", t, e)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let parameters = self.parameters(package, module, function).await?;
+                if parameters.len() != arguments.len() {
+                    return Err(SuiError::MoveError(format!(
+                        "{}::{} takes {} arguments, got {}",
+                        module,
+                        function,
+                        parameters.len(),
+                        arguments.len()
+                    )))?;
+                }
+
+                let mut args = vec![];
+                for (ty, arg) in parameters.iter().zip(arguments.iter()) {
+                    let ty = ty.substitute(&type_arguments)?;
+                    args.push(self.argument(builder, &ty, arg).await?);
+                }
+
+                builder::move_call(builder, package, module, function, type_arguments, args)?
+            }
+            PtbCommand::SplitCoins { coin, amounts } => {
+                let coin = self.object(builder, coin).await?;
+                let mut args = vec![];
+                for amount in amounts {
+                    args.push(
+                        builder
+                            .pure(*amount)
+                            .map_err(|e| SuiError::MoveError(e.to_string()))?,
+                    );
+                }
+                builder.command(Command::SplitCoins(coin, args))
+            }
+            PtbCommand::MergeCoins {
+                destination,
+                sources,
+            } => {
+                let destination = self.object(builder, destination).await?;
+                let mut args = vec![];
+                for source in sources {
+                    args.push(self.object(builder, source).await?);
+                }
+                builder.command(Command::MergeCoins(destination, args))
+            }
+            PtbCommand::TransferObjects { objects, recipient } => {
+                let mut args = vec![];
+                for object in objects {
+                    args.push(self.object(builder, object).await?);
+                }
+                let recipient = builder
+                    .pure(address::parse_sui_address(recipient)?)
+                    .map_err(|e| SuiError::MoveError(e.to_string()))?;
+                builder.command(Command::TransferObjects(args, recipient))
+            }
+        };
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn std_struct(addr: &str, module: &str, name: &str, args: Vec<MoveType>) -> MoveType {
+        MoveType::Struct {
+            address: parse_object_id(addr).unwrap(),
+            module: module.to_string(),
+            name: name.to_string(),
+            type_arguments: args,
+        }
+    }
+
+    #[test]
+    fn test_param_kind() {
+        let coin = std_struct("0x2", "coin", "Coin", vec![MoveType::TypeParameter(0)]);
+        assert_eq!(coin.kind(), ParamKind::Object { mutable: true });
+        assert_eq!(
+            MoveType::Reference(Box::new(coin.clone())).kind(),
+            ParamKind::Object { mutable: false }
+        );
+        assert_eq!(
+            MoveType::Vector(Box::new(coin)).kind(),
+            ParamKind::ObjectVector
+        );
+
+        let string = std_struct("0x1", "string", "String", vec![]);
+        assert_eq!(string.kind(), ParamKind::Pure);
+        assert_eq!(
+            std_struct("0x2", "transfer", "Receiving", vec![]).kind(),
+            ParamKind::Receiving
+        );
+
+        let ctx = std_struct("0x2", "tx_context", "TxContext", vec![]);
+        assert!(MoveType::MutableReference(Box::new(ctx)).is_tx_context());
+
+        // vector<T> with T = u64
+        let tag = sui_types::parse_sui_type_tag("u64").unwrap();
+        let ty = MoveType::Vector(Box::new(MoveType::TypeParameter(0)))
+            .substitute(&[tag])
+            .unwrap();
+        assert_eq!(ty, MoveType::Vector(Box::new(MoveType::U64)));
+    }
+
+    #[test]
+    fn test_pure_bytes() {
+        let encode = |ty: &MoveType, value: serde_json::Value| {
+            let mut out = vec![];
+            pure_bytes(ty, &value, &mut out).unwrap();
+            out
+        };
+
+        assert_eq!(
+            encode(&MoveType::U64, serde_json::json!("258")),
+            vec![2, 1, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(encode(&MoveType::Bool, serde_json::json!(true)), vec![1]);
+
+        let bytes = MoveType::Vector(Box::new(MoveType::U8));
+        assert_eq!(encode(&bytes, serde_json::json!("0x0102")), vec![2, 1, 2]);
+        assert_eq!(encode(&bytes, serde_json::json!([1, 2])), vec![2, 1, 2]);
+
+        let string = std_struct("0x1", "string", "String", vec![]);
+        assert_eq!(
+            encode(&string, serde_json::json!("hi")),
+            vec![2, b'h', b'i']
+        );
+
+        let option = std_struct("0x1", "option", "Option", vec![MoveType::U8]);
+        assert_eq!(encode(&option, serde_json::Value::Null), vec![0]);
+        assert_eq!(encode(&option, serde_json::json!(7)), vec![1, 7]);
+
+        let mut out = vec![];
+        write_uleb128(&mut out, 300);
+        assert_eq!(out, vec![0xac, 0x02]);
+
+        assert!(pure_bytes(&MoveType::U8, &serde_json::json!(256), &mut vec![]).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+// sui 原生多签: 成员公钥 + 权重 + 阈值派生多签地址,收集成员签名后合并为 MultiSig
+use crate::types::ChainPrivateKey;
+use shared_crypto::intent::{Intent, IntentMessage};
+use sui_types::{
+    base_types::SuiAddress,
+    crypto::{PublicKey, Signature, SignatureScheme, SuiKeyPair, SuiSignature as _},
+    multisig::{MultiSig, MultiSigPublicKey},
+    signature::GenericSignature,
+    transaction::TransactionData,
+};
+
+// protocol limits of a multisig public key
+pub const MAX_SIGNER_IN_MULTISIG: usize = 10;
+
+fn sign_err(e: impl std::fmt::Display) -> crate::Error {
+    crate::Error::SignError(e.to_string())
+}
+
+// member key from its scheme and the hex of the raw public key
+pub fn member_public_key(scheme: SignatureScheme, public_key: &str) -> crate::Result<PublicKey> {
+    let bytes = wallet_utils::hex_func::hex_decode(public_key.trim_start_matches("0x"))?;
+    PublicKey::try_from_bytes(scheme, &bytes).map_err(sign_err)
+}
+
+/// Multisig account, the address is derived from the keys, weights and threshold.
+#[derive(Debug, Clone)]
+pub struct SuiMultisigAccount {
+    pub public_key: MultiSigPublicKey,
+}
+
+impl SuiMultisigAccount {
+    pub fn new(members: Vec<(PublicKey, u8)>, threshold: u16) -> crate::Result<Self> {
+        if members.is_empty() || members.len() > MAX_SIGNER_IN_MULTISIG {
+            return Err(crate::Error::SignError(format!(
+                "multisig members must be between 1 and {}",
+                MAX_SIGNER_IN_MULTISIG
+            )));
+        }
+
+        let total = members.iter().map(|(_, w)| *w as u16).sum::<u16>();
+        if threshold == 0 || total < threshold {
+            return Err(crate::Error::SignError(format!(
+                "threshold {} is not reachable with total weight {}",
+                threshold, total
+            )));
+        }
+
+        let (keys, weights) = members.into_iter().unzip();
+        let public_key = MultiSigPublicKey::new(keys, weights, threshold).map_err(sign_err)?;
+
+        Ok(Self { public_key })
+    }
+
+    pub fn address(&self) -> SuiAddress {
+        SuiAddress::from(&self.public_key)
+    }
+
+    pub fn threshold(&self) -> u16 {
+        *self.public_key.threshold()
+    }
+
+    // weight of the member, None when the key is not in the multisig
+    pub fn weight_of(&self, public_key: &PublicKey) -> Option<u8> {
+        self.public_key
+            .pubkeys()
+            .iter()
+            .find(|(pk, _)| pk == public_key)
+            .map(|(_, w)| *w)
+    }
+}
+
+/// Partial signature of a member, `scheme` is the type of the member key.
+pub fn sign_partial(
+    tx_data: &TransactionData,
+    private_key: ChainPrivateKey,
+    scheme: SignatureScheme,
+) -> crate::Result<Signature> {
+    let mut bytes = vec![scheme.flag()];
+    bytes.extend(private_key.to_bytes()?);
+    let keypair = SuiKeyPair::from_bytes(&bytes).map_err(sign_err)?;
+
+    let intent_msg = IntentMessage::new(Intent::sui_transaction(), tx_data.clone());
+    Ok(Signature::new_secure(&intent_msg, &keypair))
+}
+
+/// Collects the member signatures of a transaction sent by the multisig address.
+pub struct SuiMultisigCollector {
+    pub account: SuiMultisigAccount,
+    pub tx_data: TransactionData,
+    pub signatures: Vec<Signature>,
+}
+
+impl SuiMultisigCollector {
+    pub fn new(account: SuiMultisigAccount, tx_data: TransactionData) -> crate::Result<Self> {
+        use sui_types::transaction::TransactionDataAPI as _;
+
+        if tx_data.sender() != account.address() {
+            return Err(crate::Error::SignError(format!(
+                "transaction sender {} is not the multisig {}",
+                tx_data.sender(),
+                account.address()
+            )));
+        }
+
+        Ok(Self {
+            account,
+            tx_data,
+            signatures: vec![],
+        })
+    }
+
+    // rejects signatures of other keys, other transactions and duplicates
+    pub fn add_signature(&mut self, signature: Signature) -> crate::Result<()> {
+        let public_key = signature.to_public_key().map_err(sign_err)?;
+        if self.account.weight_of(&public_key).is_none() {
+            return Err(crate::Error::SignError(
+                "signer is not a member of the multisig".to_string(),
+            ));
+        }
+
+        let signed = self
+            .signatures
+            .iter()
+            .any(|s| s.to_public_key().ok().as_ref() == Some(&public_key));
+        if signed {
+            return Err(crate::Error::SignError(
+                "member has already signed".to_string(),
+            ));
+        }
+
+        let intent_msg = IntentMessage::new(Intent::sui_transaction(), self.tx_data.clone());
+        signature
+            .verify_secure(
+                &intent_msg,
+                SuiAddress::from(&public_key),
+                signature.scheme(),
+            )
+            .map_err(sign_err)?;
+
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    pub fn weight(&self) -> u16 {
+        self.signatures
+            .iter()
+            .filter_map(|s| s.to_public_key().ok())
+            .filter_map(|pk| self.account.weight_of(&pk))
+            .map(|w| w as u16)
+            .sum()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.weight() >= self.account.threshold()
+    }
+
+    pub fn combine(&self) -> crate::Result<GenericSignature> {
+        if !self.is_ready() {
+            return Err(crate::Error::SignError(format!(
+                "signed weight {} is below the threshold {}",
+                self.weight(),
+                self.account.threshold()
+            )));
+        }
+
+        let multisig = MultiSig::combine(self.signatures.clone(), self.account.public_key.clone())
+            .map_err(sign_err)?;
+        Ok(GenericSignature::MultiSig(multisig))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+
+    fn member(seed: u8) -> (ChainPrivateKey, PublicKey) {
+        let key = ChainPrivateKey::from(hex::encode([seed; 32]).as_str());
+        let mut bytes = vec![SignatureScheme::ED25519.flag()];
+        bytes.extend([seed; 32]);
+        let public = SuiKeyPair::from_bytes(&bytes).unwrap().public();
+        (key, public)
+    }
+
+    #[test]
+    fn test_multisig_collect() {
+        let members = (1..=3).map(member).collect::<Vec<_>>();
+        let account =
+            SuiMultisigAccount::new(members.iter().map(|(_, pk)| (pk.clone(), 1)).collect(), 2)
+                .unwrap();
+        assert!(SuiMultisigAccount::new(vec![(members[0].1.clone(), 1)], 2).is_err());
+
+        let tx_data = TransactionData::new_programmable(
+            account.address(),
+            vec![],
+            ProgrammableTransactionBuilder::new().finish(),
+            2_000_000,
+            1000,
+        );
+        let mut collector = SuiMultisigCollector::new(account, tx_data.clone()).unwrap();
+
+        let sign = |i: usize| {
+            sign_partial(&tx_data, members[i].0.clone(), SignatureScheme::ED25519).unwrap()
+        };
+        collector.add_signature(sign(0)).unwrap();
+        assert!(collector.add_signature(sign(0)).is_err());
+        assert!(!collector.is_ready());
+        assert!(collector.combine().is_err());
+
+        collector.add_signature(sign(2)).unwrap();
+        assert_eq!(collector.weight(), 2);
+        let signature = collector.combine().unwrap();
+        assert!(matches!(signature, GenericSignature::MultiSig(_)));
+
+        // a key outside the multisig
+        let (other, _) = member(9);
+        let signature = sign_partial(&tx_data, other, SignatureScheme::ED25519).unwrap();
+        assert!(collector.add_signature(signature).is_err());
+    }
+}
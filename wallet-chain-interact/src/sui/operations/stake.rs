@@ -0,0 +1,253 @@
+use crate::sui::{
+    Provider,
+    builder::{self, SelectCoinHelper},
+    consts::{MIN_STAKING_THRESHOLD, SUI_NATIVE_COIN},
+    error::SuiError,
+    protocol::EstimateFeeResp,
+};
+use alloy::primitives::U256;
+use sui_types::{
+    SUI_SYSTEM_PACKAGE_ID,
+    base_types::{ObjectID, SuiAddress},
+    programmable_transaction_builder::ProgrammableTransactionBuilder,
+    transaction::{ObjectArg, ProgrammableTransaction, TransactionData},
+};
+use wallet_utils::address;
+
+const SUI_SYSTEM_MODULE: &str = "sui_system";
+
+// 质押到验证节点 0x3::sui_system::request_add_stake
+pub struct StakeOpt {
+    pub owner: SuiAddress,
+    pub validator: SuiAddress,
+    pub amount: u64,
+}
+
+impl StakeOpt {
+    pub fn new(owner: &str, validator: &str, amount: U256) -> crate::Result<Self> {
+        let amount = amount.to::<u64>();
+        if amount < MIN_STAKING_THRESHOLD {
+            return Err(crate::Error::Other(format!(
+                "stake amount must be at least {} mist",
+                MIN_STAKING_THRESHOLD
+            )));
+        }
+
+        Ok(Self {
+            owner: address::parse_sui_address(owner)?,
+            validator: address::parse_sui_address(validator)?,
+            amount,
+        })
+    }
+
+    // 选择质押使用的sui coin
+    pub async fn select_coin(&self, provider: &Provider) -> crate::Result<SelectCoinHelper> {
+        SelectCoinHelper::select(
+            provider,
+            &self.owner.to_string(),
+            SUI_NATIVE_COIN,
+            self.amount,
+        )
+        .await
+    }
+
+    pub async fn build_pt(
+        &self,
+        provider: &Provider,
+        helper: &mut SelectCoinHelper,
+        gas_budget: Option<u64>,
+    ) -> crate::Result<ProgrammableTransaction> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let gas_fee = gas_budget.unwrap_or(0);
+
+        if helper.need_extra_coin_pay_gas(SUI_NATIVE_COIN, gas_fee, self.amount) {
+            let gas_coin = helper
+                .select_gas_coin(gas_fee + self.amount, provider, &self.owner.to_string())
+                .await?;
+            helper.add_gas_coin(gas_coin);
+        }
+        helper.merge_excess_gas_coin(&mut builder)?;
+
+        let system_state = builder
+            .obj(ObjectArg::SUI_SYSTEM_MUT)
+            .map_err(|e| SuiError::MoveError(e.to_string()))?;
+        let stake_coin = helper.build_split_gas_arg(&mut builder, self.amount)?;
+        let validator = builder
+            .pure(self.validator)
+            .map_err(|e| SuiError::MoveError(e.to_string()))?;
+
+        builder::move_call(
+            &mut builder,
+            SUI_SYSTEM_PACKAGE_ID,
+            SUI_SYSTEM_MODULE,
+            "request_add_stake",
+            vec![],
+            vec![system_state, stake_coin, validator],
+        )?;
+
+        Ok(builder.finish())
+    }
+
+    pub async fn build_data(
+        &self,
+        provider: &Provider,
+        mut helper: SelectCoinHelper,
+        gas: EstimateFeeResp,
+    ) -> crate::Result<TransactionData> {
+        let gas_budget = gas.get_fee();
+
+        let pt = self
+            .build_pt(provider, &mut helper, Some(gas_budget))
+            .await?;
+
+        Ok(TransactionData::new_programmable(
+            self.owner,
+            helper.gas_obj_ref(),
+            pt,
+            gas_budget,
+            gas.gas_price,
+        ))
+    }
+}
+
+// 取回质押 0x3::sui_system::request_withdraw_stake
+pub struct WithdrawStakeOpt {
+    pub owner: SuiAddress,
+    pub staked_sui_id: ObjectID,
+}
+
+impl WithdrawStakeOpt {
+    pub fn new(owner: &str, staked_sui_id: &str) -> crate::Result<Self> {
+        let staked_sui_id = ObjectID::from_hex_literal(staked_sui_id)
+            .map_err(|e| crate::ParseErr::AddressPraseErr(e.to_string()))?;
+
+        Ok(Self {
+            owner: address::parse_sui_address(owner)?,
+            staked_sui_id,
+        })
+    }
+
+    pub async fn build_pt(
+        &self,
+        provider: &Provider,
+        helper: &mut SelectCoinHelper,
+        gas_budget: Option<u64>,
+    ) -> crate::Result<ProgrammableTransaction> {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let gas_fee = gas_budget.unwrap_or(0);
+
+        // 只需要支付gas
+        if helper.need_extra_coin_pay_gas(SUI_NATIVE_COIN, gas_fee, 0) {
+            let gas_coin = helper
+                .select_gas_coin(gas_fee, provider, &self.owner.to_string())
+                .await?;
+            helper.add_gas_coin(gas_coin);
+        }
+
+        let staked_sui = provider
+            .get_object_by_id(&self.staked_sui_id.to_string())
+            .await?
+            .object_ref_if_exists()
+            .ok_or(crate::Error::Other(format!(
+                "staked sui {} not found",
+                self.staked_sui_id
+            )))?;
+
+        let system_state = builder
+            .obj(ObjectArg::SUI_SYSTEM_MUT)
+            .map_err(|e| SuiError::MoveError(e.to_string()))?;
+        let staked_sui = builder
+            .obj(ObjectArg::ImmOrOwnedObject(staked_sui))
+            .map_err(|e| SuiError::MoveError(e.to_string()))?;
+
+        builder::move_call(
+            &mut builder,
+            SUI_SYSTEM_PACKAGE_ID,
+            SUI_SYSTEM_MODULE,
+            "request_withdraw_stake",
+            vec![],
+            vec![system_state, staked_sui],
+        )?;
+
+        Ok(builder.finish())
+    }
+
+    pub async fn build_data(
+        &self,
+        provider: &Provider,
+        mut helper: SelectCoinHelper,
+        gas: EstimateFeeResp,
+    ) -> crate::Result<TransactionData> {
+        let gas_budget = gas.get_fee();
+
+        let pt = self
+            .build_pt(provider, &mut helper, Some(gas_budget))
+            .await?;
+
+        Ok(TransactionData::new_programmable(
+            self.owner,
+            helper.gas_obj_ref(),
+            pt,
+            gas_budget,
+            gas.gas_price,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sui::builder::tests::{coin, provider};
+    use sui_types::transaction::{Argument, CallArg, Command};
+
+    const OWNER: &str = "0x885f29a4f1b4d63822728a1b1811d0278c4e25f27d3754ddd387cd34f9482d0f";
+    const VALIDATOR: &str = "0x4c1cd48f7f203870be350d7a18c5a827131cecc7322b1571b9a69aeae7dda5f2";
+
+    #[test]
+    fn test_min_stake() {
+        let amount = U256::from(MIN_STAKING_THRESHOLD - 1);
+        assert!(StakeOpt::new(OWNER, VALIDATOR, amount).is_err());
+
+        let amount = U256::from(MIN_STAKING_THRESHOLD);
+        assert_eq!(
+            StakeOpt::new(OWNER, VALIDATOR, amount).unwrap().amount,
+            MIN_STAKING_THRESHOLD
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stake_pt() {
+        let opt = StakeOpt::new(OWNER, VALIDATOR, U256::from(MIN_STAKING_THRESHOLD)).unwrap();
+        let coins = vec![coin(SUI_NATIVE_COIN, 2_000_000_000)];
+        let mut helper = SelectCoinHelper {
+            select_coins: coins.clone(),
+            gas_coin: coins,
+        };
+
+        let pt = opt
+            .build_pt(&provider(), &mut helper, Some(2_000_000))
+            .await
+            .unwrap();
+
+        // system state, stake amount and validator
+        assert_eq!(pt.inputs.len(), 3);
+        assert_eq!(pt.inputs[0], CallArg::Object(ObjectArg::SUI_SYSTEM_MUT));
+        assert_eq!(pt.commands.len(), 2);
+
+        // the stake is split from the gas coin, then passed to request_add_stake
+        assert_eq!(
+            pt.commands[0],
+            Command::SplitCoins(Argument::GasCoin, vec![Argument::Input(1)])
+        );
+        let Command::MoveCall(call) = &pt.commands[1] else {
+            panic!("expect move call");
+        };
+        assert_eq!(call.package, SUI_SYSTEM_PACKAGE_ID);
+        assert_eq!(call.module.as_str(), SUI_SYSTEM_MODULE);
+        assert_eq!(call.function.as_str(), "request_add_stake");
+        assert_eq!(
+            call.arguments,
+            vec![Argument::Input(0), Argument::Result(0), Argument::Input(2)]
+        );
+    }
+}
@@ -34,36 +34,7 @@ impl TransferOpt {
     pub async fn select_coin(&self, provider: &Provider) -> crate::Result<SelectCoinHelper> {
         let coin_type = self.get_coin_type();
 
-        let all_coin = provider
-            .get_all_coins_by_owner(&self.from.to_string(), &coin_type)
-            .await?;
-
-        let mut select_coins = vec![];
-        let mut sum = 0u64;
-
-        for coin in &all_coin {
-            sum += coin.balance;
-            select_coins.push(coin.clone());
-            if sum >= self.amount {
-                break;
-            }
-        }
-
-        // 验证金额是否满足
-        if sum < self.amount {
-            return Err(crate::sui::error::SuiError::InsufficientBalance(sum, self.amount).into());
-        }
-
-        let gas_coin = if coin_type == SUI_NATIVE_COIN {
-            select_coins.clone()
-        } else {
-            vec![]
-        };
-
-        Ok(SelectCoinHelper {
-            select_coins,
-            gas_coin,
-        })
+        SelectCoinHelper::select(provider, &self.from.to_string(), &coin_type, self.amount).await
     }
 
     pub async fn build_pt(
@@ -83,6 +54,7 @@ impl TransferOpt {
                 .await?;
             helper.add_gas_coin(gas_coin);
         };
+        helper.merge_excess_gas_coin(&mut builder)?;
 
         // 主币
         let trans_arg = if coin_type == SUI_NATIVE_COIN {
@@ -1,5 +1,6 @@
 use super::consts::SUI_VALUE;
-use sui_json_rpc_types::Coin;
+use sui_json_rpc_types::{Coin, Stake, StakeStatus};
+use sui_types::base_types::{ObjectID, SuiAddress};
 
 /// Checkpoint 详情
 #[derive(Debug, serde::Deserialize)]
@@ -57,3 +58,118 @@ impl GasObject {
         Self { coin }
     }
 }
+
+/// 验证节点信息
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfo {
+    pub address: String,
+    pub name: String,
+    pub image_url: String,
+    pub staking_pool_id: String,
+    // unit is mist
+    pub total_stake: u64,
+    // 佣金 unit is basis point
+    pub commission_rate: u64,
+    pub gas_price: u64,
+    pub voting_power: u64,
+    // 年化收益率 0.05 = 5%,None 节点未返回该验证节点的收益率
+    pub apy: Option<f64>,
+}
+
+/// 质押对象(StakedSui)
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakedSuiInfo {
+    pub staked_sui_id: String,
+    pub validator_address: String,
+    pub staking_pool: String,
+    // unit is mist
+    pub principal: u64,
+    pub estimated_reward: u64,
+    pub stake_request_epoch: u64,
+    pub stake_active_epoch: u64,
+    // Pending Active Unstaked
+    pub status: String,
+}
+
+impl StakedSuiInfo {
+    pub fn new(validator_address: &SuiAddress, staking_pool: &ObjectID, stake: Stake) -> Self {
+        // 只有生效中的质押才有预估收益
+        let (status, estimated_reward) = match stake.status {
+            StakeStatus::Pending => ("Pending", 0),
+            StakeStatus::Active { estimated_reward } => ("Active", estimated_reward),
+            StakeStatus::Unstaked => ("Unstaked", 0),
+        };
+
+        Self {
+            staked_sui_id: stake.staked_sui_id.to_string(),
+            validator_address: validator_address.to_string(),
+            staking_pool: staking_pool.to_string(),
+            principal: stake.principal,
+            estimated_reward,
+            stake_request_epoch: stake.stake_request_epoch,
+            stake_active_epoch: stake.stake_active_epoch,
+            status: status.to_string(),
+        }
+    }
+}
+
+/// dry run 的gas预览
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPreview {
+    pub gas_budget: u64,
+    pub gas_price: u64,
+    pub computation_cost: u64,
+    pub storage_cost: u64,
+    pub storage_rebate: u64,
+    pub non_refundable_storage_fee: u64,
+    // computation + storage - rebate, unit is mist
+    pub net_gas: i64,
+}
+
+impl GasPreview {
+    pub fn net_gas_f64(&self) -> f64 {
+        self.net_gas as f64 / SUI_VALUE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stake(status: StakeStatus) -> Stake {
+        Stake {
+            staked_sui_id: ObjectID::from_single_byte(1),
+            stake_request_epoch: 10,
+            stake_active_epoch: 11,
+            principal: 2_000_000_000,
+            status,
+        }
+    }
+
+    #[test]
+    fn test_staked_sui_status() {
+        let validator = SuiAddress::ZERO;
+        let pool = ObjectID::from_single_byte(2);
+
+        let info = StakedSuiInfo::new(&validator, &pool, stake(StakeStatus::Pending));
+        assert_eq!(info.status, "Pending");
+        assert_eq!(info.estimated_reward, 0);
+        assert_eq!(info.principal, 2_000_000_000);
+        assert_eq!(info.stake_active_epoch, 11);
+
+        let status = StakeStatus::Active {
+            estimated_reward: 1_500_000,
+        };
+        let info = StakedSuiInfo::new(&validator, &pool, stake(status));
+        assert_eq!(info.status, "Active");
+        assert_eq!(info.estimated_reward, 1_500_000);
+
+        let info = StakedSuiInfo::new(&validator, &pool, stake(StakeStatus::Unstaked));
+        assert_eq!(info.status, "Unstaked");
+        assert_eq!(info.estimated_reward, 0);
+        assert_eq!(info.staking_pool, pool.to_string());
+    }
+}
@@ -1,10 +1,15 @@
 use super::{TransRespOpt, protocol::CheckpointResult};
 use serde_json::json;
 use sui_json_rpc_types::{
-    Balance, Coin, CoinPage, DevInspectResults, DryRunTransactionBlockResponse, ObjectsPage,
-    SuiCoinMetadata, SuiMoveNormalizedModule, SuiObjectResponse, SuiTransactionBlockResponse,
+    Balance, Coin, CoinPage, DelegatedStake, DevInspectResults, DryRunTransactionBlockResponse,
+    ObjectsPage, Page, SuiCoinMetadata, SuiMoveNormalizedModule, SuiObjectResponse,
+    SuiTransactionBlockResponse, ValidatorApys,
+};
+use sui_types::{
+    base_types::ObjectID,
+    sui_system_state::sui_system_state_summary::SuiSystemStateSummary,
+    transaction::{ProgrammableTransaction, TransactionData, TransactionKind},
 };
-use sui_types::transaction::{ProgrammableTransaction, TransactionData, TransactionKind};
 use wallet_transport::{client::RpcClient, types::JsonRpcParams};
 
 pub struct Provider {
@@ -97,6 +102,15 @@ impl Provider {
         Ok(res)
     }
 
+    // owner decides between an owned, shared or receiving input
+    pub async fn get_object_with_owner(&self, id: &str) -> crate::Result<SuiObjectResponse> {
+        let params = JsonRpcParams::default()
+            .method("sui_getObject")
+            .params(json!([id, { "showOwner": true, "showType": true }]));
+        let res = self.client.invoke_request(params).await?;
+        Ok(res)
+    }
+
     pub async fn get_all_coins_by_owner(
         &self,
         addr: &str,
@@ -194,6 +208,30 @@ impl Provider {
         Ok(self.client.invoke_request(params).await?)
     }
 
+    pub async fn get_latest_sui_system_state(&self) -> crate::Result<SuiSystemStateSummary> {
+        let params: JsonRpcParams<()> = JsonRpcParams::default()
+            .method("suix_getLatestSuiSystemState")
+            .no_params();
+
+        Ok(self.client.invoke_request(params).await?)
+    }
+
+    pub async fn get_validators_apy(&self) -> crate::Result<ValidatorApys> {
+        let params: JsonRpcParams<()> = JsonRpcParams::default()
+            .method("suix_getValidatorsApy")
+            .no_params();
+
+        Ok(self.client.invoke_request(params).await?)
+    }
+
+    pub async fn get_stakes(&self, owner: &str) -> crate::Result<Vec<DelegatedStake>> {
+        let params = JsonRpcParams::default()
+            .method("suix_getStakes")
+            .params(json!([owner]));
+
+        Ok(self.client.invoke_request(params).await?)
+    }
+
     pub async fn get_normalized_move_modules_by_package_id(
         &self,
         package_id: &str,
@@ -204,6 +242,27 @@ impl Provider {
 
         Ok(self.client.invoke_request(params).await?)
     }
+
+    // suins
+    pub async fn resolve_name_service_address(&self, name: &str) -> crate::Result<Option<String>> {
+        let params = JsonRpcParams::default()
+            .method("suix_resolveNameServiceAddress")
+            .params(json!([name]));
+
+        Ok(self.client.invoke_request(params).await?)
+    }
+
+    pub async fn resolve_name_service_names(
+        &self,
+        addr: &str,
+        limit: usize,
+    ) -> crate::Result<Page<String, ObjectID>> {
+        let params = JsonRpcParams::default()
+            .method("suix_resolveNameServiceNames")
+            .params(json!([addr, null, limit]));
+
+        Ok(self.client.invoke_request(params).await?)
+    }
 }
 
 #[cfg(test)]
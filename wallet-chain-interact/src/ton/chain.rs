@@ -1,17 +1,28 @@
 use super::{
+    address::parse_addr_from_bs64_url,
     consts::DEFAULT_SIGN_KEY,
     errors::TonError,
     get_keypair,
-    params::EstimateFeeParams,
+    operations::payload::EncryptedComment,
+    params::{EstimateFeeParams, QueryTransParams},
     protocol::{
-        jettons::{JettonMasterResp, JettonWalletAddress, JettonWalletResp},
+        account::AddressInformation,
+        common::GasConfig,
+        jettons::{
+            JettonFee, JettonHolding, JettonMasterResp, JettonWalletAddress, JettonWalletResp,
+        },
+        staking::{
+            NominatorData, NominatorPoolData, SingleNominatorRoles, TonstakersPool, TonstakersStake,
+        },
         transaction::EstimateFeeResp,
     },
     provider::Provider,
 };
 use crate::{QueryTransactionResult, types::ChainPrivateKey};
 use alloy::primitives::U256;
+use std::str::FromStr as _;
 use tonlib_core::{
+    TonAddress,
     cell::{BagOfCells, Cell},
     tlb_types::tlb::TLB as _,
     wallet::{
@@ -92,6 +103,18 @@ impl TonChain {
         msg_cell: Cell,
         key: ChainPrivateKey,
         address_type: TonAddressType,
+    ) -> crate::Result<String> {
+        self.exec_with_state_init(msg_cell, key, address_type, None)
+            .await
+    }
+
+    // `state_init` None deploys the wallet when it is not initialized yet
+    pub async fn exec_with_state_init(
+        &self,
+        msg_cell: Cell,
+        key: ChainPrivateKey,
+        address_type: TonAddressType,
+        state_init: Option<bool>,
     ) -> crate::Result<String> {
         let key_pair = get_keypair(key)?;
 
@@ -102,14 +125,19 @@ impl TonChain {
         let wallet = TonWallet::new(version, key_pair).map_err(TonError::CellBuild)?;
 
         // 知道钱包的状态,决定是否部署钱包
-        let address = wallet.address.to_base64_url();
-        let account_info = self.provider.address_information(&address).await?;
+        let state_init = match state_init {
+            Some(state_init) => state_init,
+            None => {
+                let address = wallet.address.to_base64_url();
+                !self.provider.address_information(&address).await?.is_init()
+            }
+        };
 
         let signed_body = wallet
             .sign_external_body(&msg_cell)
             .map_err(TonError::CellBuild)?;
         let wrapped = wallet
-            .wrap_signed_body(signed_body, !account_info.is_init())
+            .wrap_signed_body(signed_body, state_init)
             .map_err(TonError::TonMsg)?;
 
         let boc = BagOfCells::from_root(wrapped);
@@ -119,6 +147,27 @@ impl TonChain {
         self.provider.send_boc_return(boc_str).await
     }
 
+    // encrypted comment for the receiver, its wallet has to be deployed to expose the public key
+    pub async fn encrypted_comment(
+        &self,
+        text: &str,
+        key: ChainPrivateKey,
+        sender: &str,
+        receiver: &str,
+    ) -> crate::Result<Cell> {
+        let sender = parse_addr_from_bs64_url(sender)?;
+        let receiver = parse_addr_from_bs64_url(receiver)?;
+
+        let receiver_public_key = AddressInformation::public_key(&receiver, &self.provider).await?;
+
+        let secret: [u8; 32] = key
+            .to_bytes()?
+            .try_into()
+            .map_err(|_| crate::Error::SignError("invalid ton private key".to_string()))?;
+
+        EncryptedComment::cell(text, &secret, &sender, &receiver_public_key)
+    }
+
     pub async fn decimals(&self, address: &str) -> crate::Result<u8> {
         let result = self
             .provider
@@ -171,3 +220,201 @@ impl TonChain {
         Ok(block.consensus_block)
     }
 }
+
+// about jetton
+impl TonChain {
+    // balances of the given jettons, jetton wallets not deployed yet count as 0
+    pub async fn jetton_balances(
+        &self,
+        owner: &str,
+        jettons: &[String],
+    ) -> crate::Result<Vec<JettonHolding>> {
+        let mut holdings = vec![];
+        for jetton in jettons {
+            let wallet = JettonWalletAddress::wallet_address(jetton, owner, &self.provider)
+                .await?
+                .to_base64_url();
+
+            let balance = match self.provider.token_data::<JettonWalletResp>(&wallet).await {
+                Ok(result) => result.balance.to_string(),
+                Err(e) => {
+                    let info = self.provider.address_information(&wallet).await?;
+                    if info.is_deployed() {
+                        return Err(e);
+                    }
+                    "0".to_string()
+                }
+            };
+
+            holdings.push(JettonHolding {
+                jetton: jetton.clone(),
+                wallet,
+                balance: wallet_utils::unit::u256_from_str(&balance)?,
+            });
+        }
+        Ok(holdings)
+    }
+
+    // jetton wallets seen in the recent transactions of the owner: transfer notifications
+    // come from them, transfers and burns go to them
+    pub async fn discover_jettons(
+        &self,
+        owner: &str,
+        limit: i32,
+    ) -> crate::Result<Vec<JettonHolding>> {
+        const TRANSFER_NOTIFICATION: u32 = 0x7362d09c;
+        const TRANSFER: u32 = 0x0f8a7ea5;
+
+        let params = QueryTransParams::new_with_limit(owner, limit);
+        let transactions = self.provider.get_transaction(&params).await?;
+
+        let mut candidates = vec![];
+        for tx in transactions.0.iter() {
+            if tx.in_msg.op_code().ok().flatten() == Some(TRANSFER_NOTIFICATION) {
+                candidates.push(tx.in_msg.source.clone());
+            }
+            for out in tx.out_msgs.iter() {
+                let op = out.op_code().ok().flatten();
+                if op == Some(TRANSFER)
+                    || op == Some(super::operations::jetton_burn::JETTON_BURN_OP)
+                {
+                    candidates.push(out.destination.clone());
+                }
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let mut holdings = vec![];
+        for candidate in candidates {
+            let Ok(address) = TonAddress::from_str(&candidate) else {
+                continue;
+            };
+            if !seen.insert(address.to_hex()) {
+                continue;
+            }
+            let Ok(wallet) = self
+                .provider
+                .token_data::<JettonWalletResp>(&address.to_base64_url())
+                .await
+            else {
+                continue;
+            };
+
+            // anyone can send a notification, the wallet has to be the one the master derives.
+            // a fake jetton master may not answer, skip it instead of failing the discovery
+            let Ok(expected) =
+                JettonWalletAddress::wallet_address(&wallet.jetton, owner, &self.provider).await
+            else {
+                continue;
+            };
+            if expected != address {
+                continue;
+            }
+
+            holdings.push(JettonHolding {
+                jetton: wallet.jetton.clone(),
+                wallet: address.to_base64_url(),
+                balance: wallet_utils::unit::u256_from_str(&wallet.balance.to_string())?,
+            });
+        }
+        Ok(holdings)
+    }
+
+    // basechain gas prices
+    async fn gas_config(&self) -> crate::Result<GasConfig> {
+        let config = self.provider.config_params(21).await?;
+        Ok(config.parse_gas_price()?)
+    }
+
+    // msg_cell is the jetton transfer built with the wanted forward amount
+    pub async fn jetton_transfer_fee(
+        &self,
+        msg_cell: Cell,
+        address: &str,
+        address_type: TonAddressType,
+        forward_ton_amount: u64,
+    ) -> crate::Result<JettonFee> {
+        let estimate = self.estimate_fee(msg_cell, address, address_type).await?;
+        let gas = self.gas_config().await?;
+
+        Ok(JettonFee::transfer(
+            &gas,
+            estimate.get_fee(),
+            estimate.source_fees.fwd_fee,
+            forward_ton_amount,
+        ))
+    }
+
+    pub async fn jetton_burn_fee(
+        &self,
+        msg_cell: Cell,
+        address: &str,
+        address_type: TonAddressType,
+    ) -> crate::Result<JettonFee> {
+        let estimate = self.estimate_fee(msg_cell, address, address_type).await?;
+        let gas = self.gas_config().await?;
+
+        Ok(JettonFee::burn(
+            &gas,
+            estimate.get_fee(),
+            estimate.source_fees.fwd_fee,
+        ))
+    }
+}
+
+// about ton dns
+impl TonChain {
+    // wallet record of the `.ton` / `.t.me` name, ton dns has no reverse records
+    pub async fn resolve_name(&self, name: &str) -> crate::Result<Option<tonlib_core::TonAddress>> {
+        let name = crate::name::normalize(name)?;
+        super::protocol::dns::DnsResolver::resolve(&self.provider, &name).await
+    }
+}
+
+// about stake
+impl TonChain {
+    pub async fn nominator_pool(&self, pool: &str) -> crate::Result<NominatorPoolData> {
+        let pool = parse_addr_from_bs64_url(pool)?;
+        NominatorPoolData::query(&pool, &self.provider).await
+    }
+
+    // stake, pending deposit and withdraw request of the nominator
+    pub async fn nominator_stake(
+        &self,
+        pool: &str,
+        owner: &str,
+    ) -> crate::Result<Option<NominatorData>> {
+        let pool = parse_addr_from_bs64_url(pool)?;
+        let owner = parse_addr_from_bs64_url(owner)?;
+        NominatorData::query(&pool, &owner, &self.provider).await
+    }
+
+    pub async fn single_nominator_roles(&self, pool: &str) -> crate::Result<SingleNominatorRoles> {
+        let pool = parse_addr_from_bs64_url(pool)?;
+        SingleNominatorRoles::query(&pool, &self.provider).await
+    }
+
+    pub async fn tonstakers_pool(&self, pool: &str) -> crate::Result<TonstakersPool> {
+        let pool = parse_addr_from_bs64_url(pool)?;
+        TonstakersPool::query(&pool, &self.provider).await
+    }
+
+    // tsTON of the owner valued at the pool rate
+    pub async fn tonstakers_stake(
+        &self,
+        pool: &str,
+        owner: &str,
+    ) -> crate::Result<TonstakersStake> {
+        let pool = self.tonstakers_pool(pool).await?;
+
+        let balance = self
+            .balance(owner, Some(pool.jetton_minter.to_base64_url()))
+            .await?;
+        let ts_ton = balance.to::<u128>();
+
+        Ok(TonstakersStake {
+            ts_ton,
+            ton_value: pool.to_ton(ts_ton),
+        })
+    }
+}
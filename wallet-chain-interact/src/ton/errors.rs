@@ -15,4 +15,6 @@ pub enum TonError {
     TonNodeError(#[from] wallet_transport::errors::TransportError),
     #[error("{0}")]
     NotTokenParse(String),
+    #[error("config param {0}")]
+    ConfigParam(String),
 }
@@ -0,0 +1,103 @@
+use super::BuildInternalMsg;
+use crate::ton::{
+    address::parse_addr_from_bs64_url,
+    errors::TonError,
+    protocol::{account::AddressInformation, jettons::JettonWalletAddress},
+    provider::Provider,
+};
+use alloy::primitives::U256;
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use std::str::FromStr;
+use tonlib_core::{
+    TonAddress,
+    cell::{Cell, CellBuilder},
+    message::{CommonMsgInfo, InternalMessage, TransferMessage},
+};
+use wallet_types::chain::address::r#type::TonAddressType;
+
+// burn#595f07bc query_id:uint64 amount:Coins response_destination:MsgAddress custom_payload:(Maybe ^Cell)
+pub const JETTON_BURN_OP: u32 = 0x595f07bc;
+
+pub struct JettonBurnOpt {
+    pub from: TonAddress,
+    pub token: String,
+    pub value: BigUint,
+    // ton attached to the jetton wallet, see JettonFee
+    pub attach_amount: BigUint,
+}
+
+impl JettonBurnOpt {
+    pub fn new(from: &str, token: &str, value: U256) -> crate::Result<Self> {
+        let value = BigUint::from_str(&value.to_string())
+            .map_err(|e| crate::Error::ParseError(crate::ParseErr::ValueErr(e.to_string())))?;
+
+        Ok(Self {
+            from: parse_addr_from_bs64_url(from)?,
+            token: token.to_string(),
+            value,
+            attach_amount: BigUint::from(50000000u64),
+        })
+    }
+
+    pub fn with_attach_amount(mut self, attach_amount: u64) -> Self {
+        self.attach_amount = BigUint::from(attach_amount);
+        self
+    }
+
+    // the excess comes back to the sender
+    fn burn_body(&self) -> Result<Cell, TonError> {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u32(32, JETTON_BURN_OP)?
+            .store_u64(64, wallet_utils::time::now().timestamp() as u64)?
+            .store_coins(&self.value)?
+            .store_address(&self.from)?
+            // no custom payload
+            .store_bit(false)?;
+
+        Ok(builder.build()?)
+    }
+}
+
+#[async_trait]
+impl BuildInternalMsg for JettonBurnOpt {
+    async fn build_trans(
+        &self,
+        address_type: TonAddressType,
+        provider: &Provider,
+    ) -> crate::Result<Cell> {
+        let now_time = wallet_utils::time::now().timestamp() as u32;
+        let body = self.burn_body()?;
+
+        let jetton_wallet =
+            JettonWalletAddress::wallet_address(&self.token, &self.from.to_base64_url(), provider)
+                .await?;
+
+        // bounce so a failed burn returns the ton
+        let internal = InternalMessage {
+            ihr_disabled: true,
+            bounce: true,
+            bounced: false,
+            src: self.from.clone(),
+            dest: jetton_wallet,
+            value: self.attach_amount.clone(),
+            ihr_fee: 0u32.into(),
+            fwd_fee: 0u32.into(),
+            created_lt: 0,
+            created_at: now_time,
+        };
+
+        let trans = TransferMessage::new(CommonMsgInfo::InternalMessage(internal))
+            .with_data(body.into())
+            .to_owned();
+
+        let seqno = AddressInformation::seqno(self.from.clone(), provider).await?;
+
+        self.build_ext_msg(trans, address_type, now_time, seqno, false)
+    }
+
+    fn get_src(&self) -> TonAddress {
+        self.from.clone()
+    }
+}
@@ -12,6 +12,9 @@ use tonlib_core::{
     },
 };
 use wallet_types::chain::address::r#type::TonAddressType;
+pub mod jetton_burn;
+pub mod payload;
+pub mod stake;
 pub mod token_transfer;
 pub mod transfer;
 
@@ -32,13 +35,35 @@ pub trait BuildInternalMsg {
         now_time: u32,
         seqno: u32,
         spend_all: bool,
+    ) -> crate::Result<Cell> {
+        let msg_mode = if spend_all { 144 } else { 3 };
+        self.build_ext_msgs(vec![trans], address_type, now_time, seqno, msg_mode)
+    }
+
+    // several internal messages in one external message, all with the same mode
+    fn build_ext_msgs(
+        &self,
+        trans: Vec<TransferMessage>,
+        address_type: TonAddressType,
+        now_time: u32,
+        seqno: u32,
+        msg_mode: u8,
     ) -> crate::Result<Cell> {
         let version = address_type.to_version();
 
-        let trans = trans.build().map_err(TonError::TonMsg)?;
-        let msgs_refs = vec![Arc::new(trans)];
+        let max = max_messages(&version);
+        if trans.is_empty() || trans.len() > max {
+            return Err(crate::Error::Other(format!(
+                "{version:?} wallet sends 1 to {max} messages, got {}",
+                trans.len()
+            )));
+        }
+
+        let mut msgs_refs = vec![];
+        for item in trans.iter() {
+            msgs_refs.push(Arc::new(item.build().map_err(TonError::TonMsg)?));
+        }
 
-        let msg_mode = if spend_all { 144 } else { 3 };
         let ext_msg = build_ext_msg(
             version,
             now_time + 60,
@@ -53,6 +78,15 @@ pub trait BuildInternalMsg {
     }
 }
 
+// internal messages a wallet accepts in one external message
+pub fn max_messages(version: &WalletVersion) -> usize {
+    match version {
+        WalletVersion::V4R1 | WalletVersion::V4R2 => 4,
+        WalletVersion::V5R1 => 255,
+        _ => 1,
+    }
+}
+
 fn build_ext_msg<T: AsRef<[ArcCell]>>(
     version: WalletVersion,
     valid_until: u32,
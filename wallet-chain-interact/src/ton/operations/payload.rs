@@ -0,0 +1,204 @@
+// 消息体: 文本备注、加密备注
+use crate::ton::errors::TonError;
+use aes::cipher::{
+    BlockDecryptMut as _, BlockEncryptMut as _, KeyIvInit as _, block_padding::NoPadding,
+};
+use tonlib_core::{
+    TonAddress,
+    cell::{Cell, CellBuilder},
+};
+
+// a cell holds 1023 bits, the first one also carries the 32 bit op
+const CELL_BYTES: usize = 127;
+const FIRST_CELL_BYTES: usize = CELL_BYTES - 4;
+
+pub const COMMENT_OP: u32 = 0;
+pub const ENCRYPTED_COMMENT_OP: u32 = 0x2167da4b;
+
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+// op then the bytes, longer data continues in a chain of refs (snake format)
+pub fn snake_cell(op: u32, bytes: &[u8]) -> Result<Cell, TonError> {
+    let first = bytes.len().min(FIRST_CELL_BYTES);
+
+    let mut tail: Option<Cell> = None;
+    for chunk in bytes[first..].chunks(CELL_BYTES).rev() {
+        let mut builder = CellBuilder::new();
+        builder.store_slice(chunk)?;
+        if let Some(next) = tail.take() {
+            builder.store_child(next)?;
+        }
+        tail = Some(builder.build()?);
+    }
+
+    let mut builder = CellBuilder::new();
+    builder.store_u32(32, op)?.store_slice(&bytes[..first])?;
+    if let Some(next) = tail {
+        builder.store_child(next)?;
+    }
+    Ok(builder.build()?)
+}
+
+pub fn comment_cell(text: &str) -> Result<Cell, TonError> {
+    snake_cell(COMMENT_OP, text.as_bytes())
+}
+
+/// Encrypted comment as wallets implement it: the data is encrypted with the x25519 secret
+/// shared by the sender and receiver wallet keys.
+pub struct EncryptedComment;
+
+impl EncryptedComment {
+    fn shared_secret(secret: &[u8; 32], public_key: &[u8; 32]) -> crate::Result<[u8; 32]> {
+        let signing = ed25519_dalek::SigningKey::from_bytes(secret);
+        let public = ed25519_dalek::VerifyingKey::from_bytes(public_key)
+            .map_err(|e| crate::Error::SignError(format!("invalid ton public key: {}", e)))?;
+
+        Ok(public
+            .to_montgomery()
+            .mul_clamped(signing.to_scalar_bytes())
+            .to_bytes())
+    }
+
+    fn cipher_params(shared: &[u8; 32], msg_key: &[u8]) -> crate::Result<([u8; 32], [u8; 16])> {
+        let x = wallet_utils::parse_func::hmac_sha512(shared, msg_key)?;
+        Ok((x[..32].try_into().unwrap(), x[32..48].try_into().unwrap()))
+    }
+
+    // pub_xor(32) | msg_key(16) | aes-256-cbc(prefix | text), salt is the sender address
+    pub fn encrypt(
+        text: &str,
+        secret: &[u8; 32],
+        sender: &TonAddress,
+        receiver_public_key: &[u8; 32],
+    ) -> crate::Result<Vec<u8>> {
+        let data = text.as_bytes();
+
+        // 16..=31 random bytes, the first one is the prefix length
+        let prefix_len = 16 + (16 - data.len() % 16) % 16;
+        let mut prefix = vec![0u8; prefix_len];
+        rand::Rng::fill(&mut rand::thread_rng(), prefix.as_mut_slice());
+        prefix[0] = prefix_len as u8;
+
+        let mut plain = prefix;
+        plain.extend_from_slice(data);
+
+        let salt = sender.to_base64_url();
+        let mac = wallet_utils::parse_func::hmac_sha512(salt.as_bytes(), &plain)?;
+        let msg_key = &mac[..16];
+
+        let shared = Self::shared_secret(secret, receiver_public_key)?;
+        let (key, iv) = Self::cipher_params(&shared, msg_key)?;
+
+        let len = plain.len();
+        Aes256CbcEnc::new(&key.into(), &iv.into())
+            .encrypt_padded_mut::<NoPadding>(&mut plain, len)
+            .map_err(|e| crate::Error::Other(format!("encrypt comment: {:?}", e)))?;
+
+        let sender_public = ed25519_dalek::SigningKey::from_bytes(secret).verifying_key();
+        let mut result = sender_public
+            .as_bytes()
+            .iter()
+            .zip(receiver_public_key.iter())
+            .map(|(a, b)| a ^ b)
+            .collect::<Vec<u8>>();
+        result.extend_from_slice(msg_key);
+        result.extend_from_slice(&plain);
+
+        Ok(result)
+    }
+
+    // works for both sides, the other public key is recovered from pub_xor
+    pub fn decrypt(
+        payload: &[u8],
+        secret: &[u8; 32],
+        sender: &TonAddress,
+    ) -> crate::Result<String> {
+        let invalid = || crate::Error::Other("invalid encrypted comment".to_string());
+        if payload.len() < 64 || (payload.len() - 48) % 16 != 0 {
+            return Err(invalid());
+        }
+
+        let own_public = ed25519_dalek::SigningKey::from_bytes(secret).verifying_key();
+        let other_public: [u8; 32] = payload[..32]
+            .iter()
+            .zip(own_public.as_bytes().iter())
+            .map(|(a, b)| a ^ b)
+            .collect::<Vec<u8>>()
+            .try_into()
+            .unwrap();
+        let msg_key = &payload[32..48];
+
+        let shared = Self::shared_secret(secret, &other_public)?;
+        let (key, iv) = Self::cipher_params(&shared, msg_key)?;
+
+        let mut plain = payload[48..].to_vec();
+        Aes256CbcDec::new(&key.into(), &iv.into())
+            .decrypt_padded_mut::<NoPadding>(&mut plain)
+            .map_err(|_| invalid())?;
+
+        let salt = sender.to_base64_url();
+        let mac = wallet_utils::parse_func::hmac_sha512(salt.as_bytes(), &plain)?;
+        if &mac[..16] != msg_key {
+            return Err(invalid());
+        }
+
+        let prefix_len = plain[0] as usize;
+        if !(16..=31).contains(&prefix_len) || prefix_len > plain.len() {
+            return Err(invalid());
+        }
+        String::from_utf8(plain[prefix_len..].to_vec()).map_err(|_| invalid())
+    }
+
+    pub fn cell(
+        text: &str,
+        secret: &[u8; 32],
+        sender: &TonAddress,
+        receiver_public_key: &[u8; 32],
+    ) -> crate::Result<Cell> {
+        let payload = Self::encrypt(text, secret, sender, receiver_public_key)?;
+        Ok(snake_cell(ENCRYPTED_COMMENT_OP, &payload)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_cell() {
+        let cell = comment_cell("hello").unwrap();
+        assert_eq!(cell.bit_len(), 32 + 5 * 8);
+        assert!(cell.references().is_empty());
+
+        let text = "a".repeat(FIRST_CELL_BYTES + CELL_BYTES + 1);
+        let cell = comment_cell(&text).unwrap();
+        assert_eq!(cell.bit_len(), 1016);
+        let second = &cell.references()[0];
+        assert_eq!(second.bit_len(), 1016);
+        assert_eq!(second.references()[0].bit_len(), 8);
+    }
+
+    #[test]
+    fn test_encrypted_comment() {
+        let sender_secret = [1u8; 32];
+        let receiver_secret = [2u8; 32];
+        let receiver_public = ed25519_dalek::SigningKey::from_bytes(&receiver_secret)
+            .verifying_key()
+            .to_bytes();
+        let sender = TonAddress::NULL;
+
+        let payload =
+            EncryptedComment::encrypt("deposit 42", &sender_secret, &sender, &receiver_public)
+                .unwrap();
+        assert_eq!(payload.len(), 32 + 16 + 32);
+
+        // both the receiver and the sender can read it
+        let text = EncryptedComment::decrypt(&payload, &receiver_secret, &sender).unwrap();
+        assert_eq!(text, "deposit 42");
+        let text = EncryptedComment::decrypt(&payload, &sender_secret, &sender).unwrap();
+        assert_eq!(text, "deposit 42");
+
+        assert!(EncryptedComment::decrypt(&payload, &[3u8; 32], &sender).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// 质押: nominator pool、single nominator pool 以及 tonstakers 的存入和取回
+use super::{BuildInternalMsg, jetton_burn::JETTON_BURN_OP, payload::comment_cell};
+use crate::ton::{
+    address::parse_addr_from_bs64_url,
+    consts::TON_DECIMAL,
+    errors::TonError,
+    protocol::{
+        account::AddressInformation, jettons::JettonWalletAddress, staking::TonstakersPool,
+    },
+    provider::Provider,
+};
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use tonlib_core::{
+    TonAddress,
+    cell::{Cell, CellBuilder},
+    message::{CommonMsgInfo, InternalMessage, TransferMessage},
+};
+use wallet_types::chain::address::r#type::TonAddressType;
+
+// nominator pool: the pool keeps 1 ton of each deposit, withdraw requests carry 1 ton
+pub const NOMINATOR_DEPOSIT_COMMENT: &str = "d";
+pub const NOMINATOR_WITHDRAW_COMMENT: &str = "w";
+pub const NOMINATOR_WITHDRAW_AMOUNT: u64 = 1_000_000_000;
+
+// single nominator pool: withdraw#1000 query_id:uint64 amount:Coins
+pub const SINGLE_NOMINATOR_WITHDRAW_OP: u32 = 0x1000;
+pub const SINGLE_NOMINATOR_WITHDRAW_AMOUNT: u64 = 100_000_000;
+
+// tonstakers: deposit#47d54391 query_id:uint64, unstake burns tsTON
+pub const TONSTAKERS_DEPOSIT_OP: u32 = 0x47d54391;
+pub const TONSTAKERS_DEPOSIT_FEE: u64 = 1_000_000_000;
+pub const TONSTAKERS_UNSTAKE_AMOUNT: u64 = 1_050_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakePool {
+    Nominator,
+    // the owner stakes alone, deposits are plain transfers
+    SingleNominator,
+    Tonstakers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakeAction {
+    Deposit,
+    // nominator pools return the whole stake and ignore the amount,
+    // tonstakers burns the tsTON amount
+    Withdraw,
+}
+
+pub struct StakeOpt {
+    pub from: TonAddress,
+    pub pool: TonAddress,
+    pub kind: StakePool,
+    pub action: StakeAction,
+    // unit is nanoton, tsTON for a tonstakers withdraw
+    pub amount: u64,
+    // tonstakers unstake: wait for the round end for the best rate,
+    // otherwise the pool pays at once when it has liquidity
+    pub wait_till_round_end: bool,
+}
+
+impl StakeOpt {
+    pub fn new(
+        from: &str,
+        pool: &str,
+        kind: StakePool,
+        action: StakeAction,
+        amount: &str,
+    ) -> crate::Result<Self> {
+        let amount = wallet_utils::unit::convert_to_u256(amount, TON_DECIMAL)?.to::<u64>();
+
+        Ok(Self {
+            from: parse_addr_from_bs64_url(from)?,
+            pool: parse_addr_from_bs64_url(pool)?,
+            kind,
+            action,
+            amount,
+            wait_till_round_end: false,
+        })
+    }
+
+    pub fn with_wait_till_round_end(mut self, wait: bool) -> Self {
+        self.wait_till_round_end = wait;
+        self
+    }
+
+    fn query_id() -> u64 {
+        wallet_utils::time::now().timestamp() as u64
+    }
+
+    fn op_body(op: u32, amount: Option<u64>) -> Result<Cell, TonError> {
+        let mut builder = CellBuilder::new();
+        builder.store_u32(32, op)?.store_u64(64, Self::query_id())?;
+        if let Some(amount) = amount {
+            builder.store_coins(&BigUint::from(amount))?;
+        }
+        Ok(builder.build()?)
+    }
+
+    // burn with the tonstakers custom payload: wait_till_round_end:Bool fill_or_kill:Bool
+    fn unstake_body(&self) -> Result<Cell, TonError> {
+        let mut payload = CellBuilder::new();
+        payload
+            .store_bit(self.wait_till_round_end)?
+            .store_bit(false)?;
+
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u32(32, JETTON_BURN_OP)?
+            .store_u64(64, Self::query_id())?
+            .store_coins(&BigUint::from(self.amount))?
+            .store_address(&self.from)?
+            .store_bit(true)?
+            .store_child(payload.build()?)?;
+
+        Ok(builder.build()?)
+    }
+
+    // destination, ton attached and body of the message
+    pub async fn message(
+        &self,
+        provider: &Provider,
+    ) -> crate::Result<(TonAddress, u64, Option<Cell>)> {
+        let res = match (self.kind, self.action) {
+            (StakePool::Nominator, StakeAction::Deposit) => (
+                self.pool.clone(),
+                self.amount,
+                Some(comment_cell(NOMINATOR_DEPOSIT_COMMENT)?),
+            ),
+            (StakePool::Nominator, StakeAction::Withdraw) => (
+                self.pool.clone(),
+                NOMINATOR_WITHDRAW_AMOUNT,
+                Some(comment_cell(NOMINATOR_WITHDRAW_COMMENT)?),
+            ),
+            (StakePool::SingleNominator, StakeAction::Deposit) => {
+                (self.pool.clone(), self.amount, None)
+            }
+            (StakePool::SingleNominator, StakeAction::Withdraw) => (
+                self.pool.clone(),
+                SINGLE_NOMINATOR_WITHDRAW_AMOUNT,
+                Some(Self::op_body(
+                    SINGLE_NOMINATOR_WITHDRAW_OP,
+                    Some(self.amount),
+                )?),
+            ),
+            (StakePool::Tonstakers, StakeAction::Deposit) => (
+                self.pool.clone(),
+                self.amount + TONSTAKERS_DEPOSIT_FEE,
+                Some(Self::op_body(TONSTAKERS_DEPOSIT_OP, None)?),
+            ),
+            (StakePool::Tonstakers, StakeAction::Withdraw) => {
+                let pool = TonstakersPool::query(&self.pool, provider).await?;
+                let wallet = JettonWalletAddress::wallet_address(
+                    &pool.jetton_minter.to_base64_url(),
+                    &self.from.to_base64_url(),
+                    provider,
+                )
+                .await?;
+                (
+                    wallet,
+                    TONSTAKERS_UNSTAKE_AMOUNT,
+                    Some(self.unstake_body()?),
+                )
+            }
+        };
+
+        Ok(res)
+    }
+}
+
+#[async_trait]
+impl BuildInternalMsg for StakeOpt {
+    async fn build_trans(
+        &self,
+        address_type: TonAddressType,
+        provider: &Provider,
+    ) -> crate::Result<Cell> {
+        let now_time = wallet_utils::time::now().timestamp() as u32;
+
+        let (dest, value, body) = self.message(provider).await?;
+
+        // pools are deployed contracts, bounce so a rejected message returns the ton
+        let internal = InternalMessage {
+            ihr_disabled: true,
+            bounce: true,
+            bounced: false,
+            src: self.from.clone(),
+            dest,
+            value: BigUint::from(value),
+            ihr_fee: 0u32.into(),
+            fwd_fee: 0u32.into(),
+            created_lt: 0,
+            created_at: now_time,
+        };
+
+        let mut trans = TransferMessage::new(CommonMsgInfo::InternalMessage(internal));
+        if let Some(body) = body {
+            trans.with_data(body.into());
+        }
+
+        let seqno = AddressInformation::seqno(self.from.clone(), provider).await?;
+
+        self.build_ext_msg(trans, address_type, now_time, seqno, false)
+    }
+
+    fn get_src(&self) -> TonAddress {
+        self.from.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stake_body() {
+        let body = StakeOpt::op_body(SINGLE_NOMINATOR_WITHDRAW_OP, Some(1_000_000_000)).unwrap();
+        let mut parser = body.parser();
+        assert_eq!(parser.load_u32(32).unwrap(), SINGLE_NOMINATOR_WITHDRAW_OP);
+        parser.load_u64(64).unwrap();
+        assert_eq!(
+            parser.load_coins().unwrap(),
+            BigUint::from(1_000_000_000u64)
+        );
+
+        let from = TonAddress::NULL.to_base64_url();
+        let opt = StakeOpt::new(
+            &from,
+            &from,
+            StakePool::Tonstakers,
+            StakeAction::Withdraw,
+            "2",
+        )
+        .unwrap()
+        .with_wait_till_round_end(true);
+
+        let body = opt.unstake_body().unwrap();
+        assert_eq!(body.parser().load_u32(32).unwrap(), JETTON_BURN_OP);
+        let payload = &body.references()[0];
+        assert_eq!(payload.bit_len(), 2);
+        assert!(payload.parser().load_bit().unwrap());
+    }
+}
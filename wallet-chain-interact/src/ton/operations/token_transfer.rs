@@ -17,7 +17,7 @@ use tonlib_core::{
 };
 use wallet_types::chain::address::r#type::TonAddressType;
 
-use super::BuildInternalMsg;
+use super::{BuildInternalMsg, payload};
 
 pub struct TokenTransferOpt {
     pub from: TonAddress,
@@ -25,6 +25,11 @@ pub struct TokenTransferOpt {
     pub token: String,
     pub value: BigUint,
     pub spend_all: bool,
+    // ton sent to the receiver with the transfer notification
+    pub forward_ton_amount: BigUint,
+    pub comment: Option<String>,
+    // ton attached to the jetton wallet, see JettonFee
+    pub attach_amount: BigUint,
 }
 
 impl TokenTransferOpt {
@@ -46,18 +51,38 @@ impl TokenTransferOpt {
             token: token.to_string(),
             value,
             spend_all,
+            forward_ton_amount: BigUint::from(1u64),
+            comment: None,
+            attach_amount: BigUint::from(10000000u64),
         })
     }
 
+    // the comment is shown by wallets only with a forward amount
+    pub fn with_forward(mut self, forward_ton_amount: u64, comment: Option<String>) -> Self {
+        self.forward_ton_amount = BigUint::from(forward_ton_amount);
+        self.comment = comment;
+        self
+    }
+
+    pub fn with_attach_amount(mut self, attach_amount: u64) -> Self {
+        self.attach_amount = BigUint::from(attach_amount);
+        self
+    }
+
     fn transfer_body(&self) -> Result<Cell, TonError> {
+        let forward_payload = match self.comment.as_deref() {
+            Some(comment) => payload::comment_cell(comment)?,
+            None => Cell::default(),
+        };
+
         let jetton_transfer = JettonTransferMessage {
             query_id: wallet_utils::time::now().timestamp() as u64,
             amount: self.value.clone(),
             destination: self.to.clone(),
             response_destination: self.from.clone(),
             custom_payload: None,
-            forward_ton_amount: BigUint::from(1u64),
-            forward_payload: Arc::new(Cell::default()),
+            forward_ton_amount: self.forward_ton_amount.clone(),
+            forward_payload: Arc::new(forward_payload),
             forward_payload_layout: EitherCellLayout::Native,
         }
         .build()?;
@@ -71,8 +96,7 @@ impl TokenTransferOpt {
         now_time: u32,
         src_jetton_address: TonAddress,
     ) -> InternalMessage {
-        let ton_amount = BigUint::from(10000000u64);
-        // let ton_amount = BigUint::ZERO;
+        let ton_amount = self.attach_amount.clone();
         InternalMessage {
             ihr_disabled: true,
             bounce,
@@ -1,4 +1,4 @@
-use super::BuildInternalMsg;
+use super::{BuildInternalMsg, max_messages, payload::comment_cell};
 use crate::ton::{
     address::parse_addr_from_bs64_url, consts::TON_DECIMAL, protocol::account::AddressInformation,
     provider::Provider,
@@ -17,6 +17,10 @@ pub struct TransferOpt {
     pub to: TonAddress,
     pub value: u64,
     pub spend_all: bool,
+    // None checks the receiver, an uninitialized account gets a non bounceable message
+    pub bounce: Option<bool>,
+    // comment, encrypted comment or any other body
+    pub payload: Option<Cell>,
 }
 
 impl TransferOpt {
@@ -28,9 +32,26 @@ impl TransferOpt {
             to: parse_addr_from_bs64_url(to)?,
             value,
             spend_all,
+            bounce: None,
+            payload: None,
         })
     }
 
+    pub fn with_comment(mut self, comment: &str) -> crate::Result<Self> {
+        self.payload = Some(comment_cell(comment)?);
+        Ok(self)
+    }
+
+    pub fn with_payload(mut self, payload: Cell) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    pub fn with_bounce(mut self, bounce: bool) -> Self {
+        self.bounce = Some(bounce);
+        self
+    }
+
     pub fn internal_msg(&self, bounce: bool, now_time: u32) -> InternalMessage {
         InternalMessage {
             ihr_disabled: true,
@@ -45,6 +66,33 @@ impl TransferOpt {
             created_at: now_time,
         }
     }
+
+    // a bounced message to an uninitialized account would return to the sender
+    async fn resolve_bounce(&self, provider: &Provider) -> crate::Result<bool> {
+        match self.bounce {
+            Some(bounce) => Ok(bounce),
+            None => Ok(provider
+                .address_information(&self.to.to_base64_url())
+                .await?
+                .is_init()),
+        }
+    }
+
+    pub async fn transfer_message(
+        &self,
+        now_time: u32,
+        provider: &Provider,
+    ) -> crate::Result<TransferMessage> {
+        let bounce = self.resolve_bounce(provider).await?;
+
+        let internal_msg = self.internal_msg(bounce, now_time);
+        let mut trans = TransferMessage::new(CommonMsgInfo::InternalMessage(internal_msg));
+        if let Some(payload) = &self.payload {
+            trans.with_data(payload.clone().into());
+        }
+
+        Ok(trans)
+    }
 }
 
 #[async_trait]
@@ -55,11 +103,8 @@ impl BuildInternalMsg for TransferOpt {
         provider: &Provider,
     ) -> crate::Result<Cell> {
         let now_time = wallet_utils::time::now().timestamp() as u32;
-        let bounce = false;
 
-        let internal_msg = self.internal_msg(bounce, now_time);
-        let common_msg_info = CommonMsgInfo::InternalMessage(internal_msg);
-        let trans = TransferMessage::new(common_msg_info);
+        let trans = self.transfer_message(now_time, provider).await?;
 
         let seqno = AddressInformation::seqno(self.from.clone(), provider).await?;
 
@@ -70,3 +115,90 @@ impl BuildInternalMsg for TransferOpt {
         self.from.clone()
     }
 }
+
+/// Several transfers signed once, v4r2 wallets take 4 messages and v5r1 wallets 255.
+pub struct MultiTransferOpt {
+    pub from: TonAddress,
+    pub messages: Vec<TransferOpt>,
+}
+
+impl MultiTransferOpt {
+    pub fn new(from: &str, messages: Vec<TransferOpt>) -> crate::Result<Self> {
+        let from = parse_addr_from_bs64_url(from)?;
+
+        if messages.is_empty() {
+            return Err(crate::Error::Other("no transfer messages".to_string()));
+        }
+        if messages.iter().any(|m| m.from != from || m.spend_all) {
+            return Err(crate::Error::Other(
+                "messages must be sent from the wallet and can not spend all".to_string(),
+            ));
+        }
+
+        Ok(Self { from, messages })
+    }
+
+    pub fn check_limit(&self, address_type: TonAddressType) -> crate::Result<()> {
+        let max = max_messages(&address_type.to_version());
+        if self.messages.len() > max {
+            return Err(crate::Error::Other(format!(
+                "{:?} wallet sends at most {} messages, got {}",
+                address_type,
+                max,
+                self.messages.len()
+            )));
+        }
+        Ok(())
+    }
+
+    // sum of the ton sent by all messages
+    pub fn total_value(&self) -> u64 {
+        self.messages.iter().map(|m| m.value).sum()
+    }
+}
+
+#[async_trait]
+impl BuildInternalMsg for MultiTransferOpt {
+    async fn build_trans(
+        &self,
+        address_type: TonAddressType,
+        provider: &Provider,
+    ) -> crate::Result<Cell> {
+        self.check_limit(address_type)?;
+
+        let now_time = wallet_utils::time::now().timestamp() as u32;
+
+        let mut trans = Vec::with_capacity(self.messages.len());
+        for message in self.messages.iter() {
+            trans.push(message.transfer_message(now_time, provider).await?);
+        }
+
+        let seqno = AddressInformation::seqno(self.from.clone(), provider).await?;
+
+        self.build_ext_msgs(trans, address_type, now_time, seqno, 3)
+    }
+
+    fn get_src(&self) -> TonAddress {
+        self.from.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_transfer_limit() {
+        let from = TonAddress::NULL.to_base64_url();
+        let message = || TransferOpt::new(&from, &from, "0.1", false).unwrap();
+
+        let opt = MultiTransferOpt::new(&from, (0..5).map(|_| message()).collect()).unwrap();
+        assert_eq!(opt.total_value(), 500_000_000);
+        assert!(opt.check_limit(TonAddressType::V4R2).is_err());
+        assert!(opt.check_limit(TonAddressType::V5R1).is_ok());
+
+        let all = TransferOpt::new(&from, &from, "0.1", true).unwrap();
+        assert!(MultiTransferOpt::new(&from, vec![all]).is_err());
+        assert!(MultiTransferOpt::new(&from, vec![]).is_err());
+    }
+}
@@ -22,6 +22,11 @@ pub struct AddressInformation {
     pub state: String,
 }
 impl AddressInformation {
+    // toncenter reports accounts without code as uninitialized
+    pub fn is_deployed(&self) -> bool {
+        !matches!(self.state.as_str(), "uninitialized" | "uninit")
+    }
+
     pub async fn seqno(address: TonAddress, provider: &Provider) -> crate::Result<u32> {
         let params = RunGetMethodParams::<()>::new(&address.to_base64_url(), "seqno", vec![]);
         let result = provider.run_get_method(params).await?;
@@ -44,6 +49,33 @@ impl AddressInformation {
         }
     }
 
+    // wallet public key through the get_public_key get method, v3 and later wallets
+    pub async fn public_key(address: &TonAddress, provider: &Provider) -> crate::Result<[u8; 32]> {
+        let params =
+            RunGetMethodParams::<()>::new(&address.to_base64_url(), "get_public_key", vec![]);
+        let result = provider.run_get_method(params).await?;
+
+        if result.exit_code != 0 {
+            return Err(TonError::RunGetMethodResp(format!(
+                "get_public_key exit code {}",
+                result.exit_code
+            )))?;
+        }
+
+        match result.stack.first() {
+            Some(super::common::StackItem::Num(_, r)) => {
+                let hex = format!("{:0>64}", r.trim_start_matches("0x"));
+                let bytes = wallet_utils::hex_func::hex_decode(&hex)?;
+                Ok(bytes.try_into().map_err(|_| {
+                    crate::errors::ParseErr::ValueErr("parse public key error".to_string())
+                })?)
+            }
+            _ => Err(TonError::RunGetMethodResp(
+                "get_public_key:not match response stack".to_string(),
+            ))?,
+        }
+    }
+
     pub fn is_init(&self) -> bool {
         self.state == "active"
     }
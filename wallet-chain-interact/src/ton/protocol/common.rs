@@ -1,6 +1,6 @@
 use super::{block::BlockIdExt, transaction::TransactionId};
 use crate::ton::errors::TonError;
-use tonlib_core::cell::BagOfCells;
+use tonlib_core::{TonAddress, cell::BagOfCells};
 
 #[derive(Debug, serde::Serialize)]
 pub struct RunGetMethodParams<T> {
@@ -37,6 +37,48 @@ pub struct RunGetMethodResp {
 pub enum StackItem {
     Num(String, String),
     Slice(String, SliceContent),
+    // null, tuple and list entries
+    Other(serde_json::Value),
+}
+
+impl StackItem {
+    // get method numbers are hex, negative ones (-0x..) are rejected
+    pub fn to_u128(&self) -> Result<u128, TonError> {
+        match self {
+            StackItem::Num(_, r) if r.starts_with('-') => Err(TonError::RunGetMethodResp(format!(
+                "negative stack number {}",
+                r
+            ))),
+            StackItem::Num(_, r) => u128::from_str_radix(r.trim_start_matches("0x"), 16)
+                .map_err(|_| TonError::RunGetMethodResp(format!("parse stack number {}", r))),
+            _ => Err(TonError::RunGetMethodResp(
+                "stack item is not a number".to_string(),
+            )),
+        }
+    }
+
+    pub fn to_address(&self) -> Result<TonAddress, TonError> {
+        match self {
+            StackItem::Slice(_, r) => {
+                let cell = BagOfCells::parse_base64(&r.bytes)?.single_root()?;
+                Ok(cell.parser().load_address()?)
+            }
+            _ => Err(TonError::RunGetMethodResp(
+                "stack item is not a slice".to_string(),
+            )),
+        }
+    }
+}
+
+impl RunGetMethodResp {
+    pub fn item(&self, index: usize) -> Result<&StackItem, TonError> {
+        self.stack
+            .get(index)
+            .ok_or(TonError::RunGetMethodResp(format!(
+                "stack has no item {}",
+                index
+            )))
+    }
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -65,24 +107,44 @@ pub struct ConfigParams {
     pub config: Config,
 }
 impl ConfigParams {
+    // config param 21, basechain gas prices:
+    // gas_flat_pfx#d1 flat_gas_limit:uint64 flat_gas_price:uint64 other:GasLimitsPrices
+    // gas_prices_ext#de gas_price gas_limit special_gas_limit gas_credit block_gas_limit freeze_due_limit delete_due_limit
+    // gas_prices#dd is gas_prices_ext without special_gas_limit
     pub fn parse_gas_price(&self) -> Result<GasConfig, TonError> {
         let bag = BagOfCells::parse_base64(&self.config.bytes)?.single_root()?;
 
         let mut parse = bag.parser();
-        // 标识
-        let _t = parse.load_u8(8)?;
+        let mut tag = parse.load_u8(8)?;
 
-        let special = parse.load_u64(64)?;
-        let flat_gas_limit = parse.load_u64(64)?;
+        let (flat_gas_limit, flat_gas_price) = if tag == 0xd1 {
+            let flat = (parse.load_u64(64)?, parse.load_u64(64)?);
+            tag = parse.load_u8(8)?;
+            flat
+        } else {
+            (0, 0)
+        };
+        if tag != 0xde && tag != 0xdd {
+            return Err(TonError::ConfigParam(format!(
+                "unknown gas prices tag {:#x}",
+                tag
+            )));
+        }
 
-        let _t = parse.load_u8(8)?;
+        let gas_price = parse.load_u64(64)?;
+        let gas_limit = parse.load_u64(64)?;
+        let special_gas_limit = if tag == 0xde {
+            parse.load_u64(64)?
+        } else {
+            gas_limit
+        };
 
         let res = GasConfig {
-            special_gas_limit: special,
             flat_gas_limit,
-            flat_gas_price: parse.load_u64(64)?,
-            gas_price: parse.load_u64(64)?,
-            gas_limit: parse.load_u64(64)?,
+            flat_gas_price,
+            gas_price,
+            gas_limit,
+            special_gas_limit,
             gas_credit: parse.load_u64(64)?,
             block_gas_limit: parse.load_u64(64)?,
             freeze_due_limit: parse.load_u64(64)?,
@@ -106,9 +168,101 @@ pub struct GasConfig {
     pub delete_due_limit: u64,
 }
 
+impl GasConfig {
+    // unit is nanoton, gas_price is in 1/65536 nanoton
+    pub fn gas_fee(&self, gas: u64) -> u64 {
+        if gas <= self.flat_gas_limit {
+            return self.flat_gas_price;
+        }
+        let extra = (gas - self.flat_gas_limit) as u128 * self.gas_price as u128;
+        self.flat_gas_price + extra.div_ceil(65536) as u64
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct Config {
     #[serde(rename = "@type")]
     pub type_field: String,
     pub bytes: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonlib_core::cell::CellBuilder;
+
+    fn config(cell: tonlib_core::cell::Cell) -> ConfigParams {
+        let boc = BagOfCells::from_root(cell).serialize(true).unwrap();
+        ConfigParams {
+            type_field: "configInfo".to_string(),
+            config: Config {
+                type_field: "tvm.cell".to_string(),
+                bytes: wallet_utils::bytes_to_base64(&boc),
+            },
+        }
+    }
+
+    #[test]
+    fn test_stack_num_to_u128() {
+        let num = |r: &str| StackItem::Num("num".to_string(), r.to_string());
+
+        assert_eq!(num("0x3e8").to_u128().unwrap(), 1000);
+        assert_eq!(num("0x0").to_u128().unwrap(), 0);
+        assert!(num("-0x1").to_u128().is_err());
+    }
+
+    #[test]
+    fn test_parse_gas_price() {
+        // basechain values of mainnet config 21
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u8(8, 0xd1)
+            .unwrap()
+            .store_u64(64, 100)
+            .unwrap()
+            .store_u64(64, 40_000)
+            .unwrap()
+            .store_u8(8, 0xde)
+            .unwrap();
+        for value in [
+            26_214_400,
+            1_000_000,
+            35_000_000,
+            10_000,
+            10_000_000,
+            100_000_000,
+            1_000_000_000,
+        ] {
+            builder.store_u64(64, value).unwrap();
+        }
+        let gas = config(builder.build().unwrap()).parse_gas_price().unwrap();
+
+        assert_eq!(gas.flat_gas_limit, 100);
+        assert_eq!(gas.flat_gas_price, 40_000);
+        assert_eq!(gas.gas_price, 26_214_400);
+        assert_eq!(gas.gas_limit, 1_000_000);
+        assert_eq!(gas.special_gas_limit, 35_000_000);
+        assert_eq!(gas.gas_credit, 10_000);
+        assert_eq!(gas.block_gas_limit, 10_000_000);
+        assert_eq!(gas.freeze_due_limit, 100_000_000);
+        assert_eq!(gas.delete_due_limit, 1_000_000_000);
+        // 400 nanoton per gas above the flat limit
+        assert_eq!(gas.gas_fee(100), 40_000);
+        assert_eq!(gas.gas_fee(1100), 440_000);
+
+        // without the flat prefix and special gas limit
+        let mut builder = CellBuilder::new();
+        builder.store_u8(8, 0xdd).unwrap();
+        for value in [65_536_000, 1_000_000, 10_000, 10_000_000, 1, 2] {
+            builder.store_u64(64, value).unwrap();
+        }
+        let gas = config(builder.build().unwrap()).parse_gas_price().unwrap();
+        assert_eq!(gas.flat_gas_limit, 0);
+        assert_eq!(gas.special_gas_limit, gas.gas_limit);
+        assert_eq!(gas.delete_due_limit, 2);
+
+        let mut builder = CellBuilder::new();
+        builder.store_u8(8, 0xaa).unwrap();
+        assert!(config(builder.build().unwrap()).parse_gas_price().is_err());
+    }
+}
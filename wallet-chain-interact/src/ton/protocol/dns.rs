@@ -0,0 +1,187 @@
+// ton dns 域名解析, 通过 dnsresolve get method 逐级解析
+use super::common::{RunGetMethodParams, StackItem};
+use crate::ton::{address::parse_addr_from_bs64_url, errors::TonError, provider::Provider};
+use tonlib_core::{
+    TonAddress,
+    cell::{ArcCell, BagOfCells, CellBuilder},
+};
+
+// nft collection of `.ton` domains
+pub const TON_DNS_ROOT: &str = "EQC3dNlesgVD8YbAazcauIrXBPfiVhMMr5YYk2in0Mtsz0Bz";
+// nft collection of telegram usernames `.t.me`
+pub const TME_DNS_ROOT: &str = "EQCA14o1-VWhS2efqoh_9M1b_A9DtKTuoqfmkn83AbJzwnPi";
+
+// dns_next_resolver#ba93 resolver:MsgAddressInt
+const DNS_NEXT_RESOLVER: u16 = 0xba93;
+// dns_smc_address#9fd3 smc_addr:MsgAddressInt
+const DNS_SMC_ADDRESS: u16 = 0x9fd3;
+const MAX_DNS_HOPS: usize = 4;
+// the domain has to fit in one cell
+const MAX_DOMAIN_LEN: usize = 126;
+
+// category of the wallet record, sha256("wallet") as a decimal int
+pub fn wallet_category() -> String {
+    num_bigint::BigUint::from_bytes_be(&wallet_utils::sha256(b"wallet")).to_string()
+}
+
+// root collection and the domain below it, labels reversed and each followed by a zero byte
+pub fn encode_domain(name: &str) -> crate::Result<(&'static str, Vec<u8>)> {
+    let (root, rest) = if let Some(rest) = name.strip_suffix(".ton") {
+        (TON_DNS_ROOT, rest)
+    } else if let Some(rest) = name.strip_suffix(".t.me") {
+        (TME_DNS_ROOT, rest)
+    } else {
+        return Err(crate::Error::Other(format!("not a ton dns name {}", name)));
+    };
+
+    let mut bytes = vec![];
+    for label in rest.split('.').rev() {
+        if label.is_empty() {
+            return Err(crate::Error::Other(format!(
+                "invalid ton dns name {}",
+                name
+            )));
+        }
+        bytes.extend_from_slice(label.as_bytes());
+        bytes.push(0);
+    }
+    if bytes.len() > MAX_DOMAIN_LEN {
+        return Err(crate::Error::Other(format!(
+            "ton dns name too long {}",
+            name
+        )));
+    }
+    Ok((root, bytes))
+}
+
+pub struct DnsResolver;
+
+impl DnsResolver {
+    // wallet record of the name, next resolver records are followed
+    pub async fn resolve(provider: &Provider, name: &str) -> crate::Result<Option<TonAddress>> {
+        let (root, domain) = encode_domain(name)?;
+        let category = wallet_category();
+
+        let mut resolver = parse_addr_from_bs64_url(root)?;
+        let mut rest = domain;
+        for _ in 0..MAX_DNS_HOPS {
+            let (len, cell) = Self::dnsresolve(provider, &resolver, &rest, &category).await?;
+            let Some(cell) = cell else {
+                return Ok(None);
+            };
+            if len == 0 {
+                return Ok(None);
+            }
+
+            let mut parser = cell.parser();
+            let tag = parser.load_u16(16).map_err(TonError::from)?;
+            match tag {
+                DNS_SMC_ADDRESS if len >= rest.len() => {
+                    return Ok(Some(parser.load_address().map_err(TonError::from)?));
+                }
+                DNS_NEXT_RESOLVER => {
+                    resolver = parser.load_address().map_err(TonError::from)?;
+                    // the whole domain was consumed, ask the next resolver about itself
+                    rest = if len >= rest.len() {
+                        vec![0]
+                    } else {
+                        rest[len..].to_vec()
+                    };
+                }
+                _ => {
+                    return Err(TonError::RunGetMethodResp(format!(
+                        "dnsresolve: unknown record {:x}",
+                        tag
+                    )))?;
+                }
+            }
+        }
+
+        Err(TonError::RunGetMethodResp(
+            "dnsresolve: too many resolvers".to_string(),
+        ))?
+    }
+
+    // resolved length in bytes and the record cell
+    async fn dnsresolve(
+        provider: &Provider,
+        resolver: &TonAddress,
+        domain: &[u8],
+        category: &str,
+    ) -> crate::Result<(usize, Option<ArcCell>)> {
+        let mut builder = CellBuilder::new();
+        let cell = builder
+            .store_slice(domain)
+            .map_err(TonError::from)?
+            .build()
+            .map_err(TonError::from)?;
+        let boc = BagOfCells::from_root(cell)
+            .serialize(false)
+            .map_err(TonError::from)?;
+
+        let stack = vec![
+            vec!["tvm.Slice".to_string(), wallet_utils::bytes_to_base64(&boc)],
+            vec!["num".to_string(), category.to_string()],
+        ];
+        let params = RunGetMethodParams::new(&resolver.to_base64_url(), "dnsresolve", stack);
+        let response = provider.run_get_method(params).await?;
+
+        if response.exit_code != 0 {
+            return Err(TonError::RunGetMethodResp(format!(
+                "dnsresolve exit code {}",
+                response.exit_code
+            )))?;
+        }
+
+        let bits = match response.stack.first() {
+            Some(StackItem::Num(_, bits)) => u64::from_str_radix(bits.trim_start_matches("0x"), 16)
+                .map_err(|_| {
+                    crate::errors::ParseErr::ValueErr(format!("parse dnsresolve bits {}", bits))
+                })?,
+            _ => {
+                return Err(TonError::RunGetMethodResp(
+                    "dnsresolve: not match response stack".to_string(),
+                ))?;
+            }
+        };
+        if bits % 8 != 0 {
+            return Err(TonError::RunGetMethodResp(format!(
+                "dnsresolve: resolved bits {} not byte aligned",
+                bits
+            )))?;
+        }
+
+        let cell = match response.stack.get(1) {
+            Some(StackItem::Slice(_, content)) => Some(
+                BagOfCells::parse_base64(&content.bytes)
+                    .and_then(|b| b.single_root())
+                    .map_err(TonError::from)?,
+            ),
+            _ => None,
+        };
+
+        Ok(((bits / 8) as usize, cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_domain() {
+        let (root, bytes) = encode_domain("alice.ton").unwrap();
+        assert_eq!(root, TON_DNS_ROOT);
+        assert_eq!(bytes, b"alice\0");
+
+        let (root, bytes) = encode_domain("wallet.alice.t.me").unwrap();
+        assert_eq!(root, TME_DNS_ROOT);
+        assert_eq!(bytes, b"alice\0wallet\0");
+
+        assert!(encode_domain("alice.eth").is_err());
+        assert_eq!(
+            wallet_category(),
+            "105311596331855300602201538317979276640056460191511695660591596829410056223515"
+        );
+    }
+}
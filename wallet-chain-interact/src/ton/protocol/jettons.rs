@@ -1,4 +1,4 @@
-use super::common::RunGetMethodParams;
+use super::common::{GasConfig, RunGetMethodParams};
 use crate::ton::{
     address::parse_addr_from_bs64_url, consts::DEFAULT_WORKCHAIN, errors::TonError,
     provider::Provider,
@@ -57,6 +57,72 @@ pub struct JettonWalletResp {
     pub contract_type: String,
 }
 
+/// A jetton held by the owner.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JettonHolding {
+    // jetton master
+    pub jetton: String,
+    pub wallet: String,
+    pub balance: alloy::primitives::U256,
+}
+
+// gas of the reference jetton wallet, see jetton-wallet.fc
+pub const SEND_TRANSFER_GAS: u64 = 10_065;
+pub const RECEIVE_TRANSFER_GAS: u64 = 10_435;
+pub const SEND_BURN_GAS: u64 = 5_891;
+pub const RECEIVE_BURN_GAS: u64 = 6_757;
+// storage reserved by a new jetton wallet, unit is nanoton
+pub const JETTON_MIN_STORAGE: u64 = 10_000_000;
+
+/// Ton spent outside the sender wallet by a jetton transfer or burn, unit is nanoton.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JettonFee {
+    // fee of the sender wallet transaction
+    pub wallet_fee: u64,
+    // gas and forwarding of the jetton wallets
+    pub jetton_fee: u64,
+    // ton to attach to the jetton wallet, the excess comes back to the sender
+    pub attach_amount: u64,
+}
+
+impl JettonFee {
+    // fwd_fee is the forward fee of one jetton message
+    pub fn transfer(
+        gas: &GasConfig,
+        wallet_fee: u64,
+        fwd_fee: u64,
+        forward_ton_amount: u64,
+    ) -> Self {
+        // the notification to the receiver is only sent with a forward amount
+        let fwd_count = if forward_ton_amount > 0 { 2 } else { 1 };
+        let jetton_fee = gas.gas_fee(SEND_TRANSFER_GAS)
+            + gas.gas_fee(RECEIVE_TRANSFER_GAS)
+            + fwd_count * fwd_fee;
+
+        Self {
+            wallet_fee,
+            jetton_fee,
+            attach_amount: jetton_fee + forward_ton_amount + JETTON_MIN_STORAGE,
+        }
+    }
+
+    pub fn burn(gas: &GasConfig, wallet_fee: u64, fwd_fee: u64) -> Self {
+        let jetton_fee = gas.gas_fee(SEND_BURN_GAS) + gas.gas_fee(RECEIVE_BURN_GAS) + fwd_fee;
+
+        Self {
+            wallet_fee,
+            jetton_fee,
+            attach_amount: jetton_fee,
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.wallet_fee + self.jetton_fee
+    }
+}
+
 pub struct JettonWalletAddress;
 
 impl JettonWalletAddress {
@@ -111,3 +177,34 @@ impl JettonWalletAddress {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jetton_fee() {
+        // basechain prices: 400 nanoton per gas
+        let gas = GasConfig {
+            special_gas_limit: 1_000_000,
+            flat_gas_limit: 100,
+            flat_gas_price: 40_000,
+            gas_price: 26_214_400,
+            gas_limit: 1_000_000,
+            gas_credit: 10_000,
+            block_gas_limit: 10_000_000,
+            freeze_due_limit: 100_000_000,
+            delete_due_limit: 1_000_000_000,
+        };
+        assert_eq!(gas.gas_fee(50), 40_000);
+        assert_eq!(gas.gas_fee(SEND_TRANSFER_GAS), 4_026_000);
+
+        let fee = JettonFee::transfer(&gas, 3_000_000, 1_000_000, 1);
+        assert_eq!(fee.jetton_fee, 4_026_000 + 4_174_000 + 2_000_000);
+        assert_eq!(fee.attach_amount, fee.jetton_fee + 1 + JETTON_MIN_STORAGE);
+
+        let fee = JettonFee::transfer(&gas, 3_000_000, 1_000_000, 0);
+        assert_eq!(fee.jetton_fee, 4_026_000 + 4_174_000 + 1_000_000);
+        assert_eq!(fee.total(), 3_000_000 + fee.jetton_fee);
+    }
+}
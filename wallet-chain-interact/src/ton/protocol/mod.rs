@@ -1,5 +1,7 @@
 pub mod account;
 pub mod block;
 pub mod common;
+pub mod dns;
 pub mod jettons;
+pub mod staking;
 pub mod transaction;
@@ -0,0 +1,208 @@
+// 质押池查询: nominator pool、single nominator pool 以及 tonstakers 流动性质押
+use super::common::{RunGetMethodParams, RunGetMethodResp, StackItem};
+use crate::ton::{errors::TonError, provider::Provider};
+use tonlib_core::TonAddress;
+
+async fn get_method(
+    pool: &TonAddress,
+    method: &str,
+    stack: Vec<Vec<String>>,
+    provider: &Provider,
+) -> crate::Result<RunGetMethodResp> {
+    let params = RunGetMethodParams::new(&pool.to_base64_url(), method, stack);
+    let result = provider.run_get_method(params).await?;
+
+    if result.exit_code != 0 {
+        return Err(TonError::RunGetMethodResp(format!(
+            "{} exit code {}",
+            method, result.exit_code
+        )))?;
+    }
+    Ok(result)
+}
+
+// tvm booleans are 0 and -1
+fn stack_bool(item: &StackItem) -> bool {
+    matches!(item, StackItem::Num(_, r) if r.trim_start_matches('-').trim_start_matches("0x") != "0")
+}
+
+/// get_pool_data of the nominator pool contract
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NominatorPoolData {
+    // 0 not staked, 1 stake request sent, 2 staked
+    pub state: u8,
+    pub nominators_count: u32,
+    // unit is nanoton
+    pub stake_amount_sent: u128,
+    pub validator_amount: u128,
+}
+
+impl NominatorPoolData {
+    pub async fn query(pool: &TonAddress, provider: &Provider) -> crate::Result<Self> {
+        let result = get_method(pool, "get_pool_data", vec![], provider).await?;
+
+        Ok(Self {
+            state: result.item(0)?.to_u128()? as u8,
+            nominators_count: result.item(1)?.to_u128()? as u32,
+            stake_amount_sent: result.item(2)?.to_u128()?,
+            validator_amount: result.item(3)?.to_u128()?,
+        })
+    }
+}
+
+/// get_nominator_data of the nominator pool contract
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NominatorData {
+    // unit is nanoton
+    pub amount: u128,
+    // deposits wait until the current round ends
+    pub pending_deposit: u128,
+    // the whole stake is returned after the round
+    pub withdraw_requested: bool,
+}
+
+impl NominatorData {
+    // None when the address is not a nominator of the pool
+    pub async fn query(
+        pool: &TonAddress,
+        nominator: &TonAddress,
+        provider: &Provider,
+    ) -> crate::Result<Option<Self>> {
+        // the nominator is keyed by the hash part of a basechain address
+        let hex = nominator.to_hex();
+        let hash = hex.split(':').next_back().unwrap_or_default();
+        let stack = vec![vec!["num".to_string(), format!("0x{}", hash)]];
+
+        let params = RunGetMethodParams::new(&pool.to_base64_url(), "get_nominator_data", stack);
+        let result = provider.run_get_method(params).await?;
+        if result.exit_code != 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            amount: result.item(0)?.to_u128()?,
+            pending_deposit: result.item(1)?.to_u128()?,
+            withdraw_requested: stack_bool(result.item(2)?),
+        }))
+    }
+}
+
+/// get_roles of the single nominator pool, only the owner can withdraw
+#[derive(Debug)]
+pub struct SingleNominatorRoles {
+    pub owner: TonAddress,
+    pub validator: TonAddress,
+}
+
+impl SingleNominatorRoles {
+    pub async fn query(pool: &TonAddress, provider: &Provider) -> crate::Result<Self> {
+        let result = get_method(pool, "get_roles", vec![], provider).await?;
+
+        Ok(Self {
+            owner: result.item(0)?.to_address()?,
+            validator: result.item(1)?.to_address()?,
+        })
+    }
+}
+
+/// get_pool_full_data of the tonstakers pool, the fields a wallet needs
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonstakersPool {
+    pub state: u8,
+    pub halted: bool,
+    // unit is nanoton
+    pub total_balance: u128,
+    // per round, in 1/2^24
+    pub interest_rate: u128,
+    pub deposits_open: bool,
+    #[serde(skip)]
+    pub jetton_minter: TonAddress,
+    // tsTON minted
+    pub supply: u128,
+    // ton waiting for the round to end before unstaking
+    pub requested_for_withdrawal: u128,
+    // balance and supply after the current round
+    pub projected_total_balance: u128,
+    pub projected_supply: u128,
+}
+
+impl TonstakersPool {
+    pub async fn query(pool: &TonAddress, provider: &Provider) -> crate::Result<Self> {
+        let result = get_method(pool, "get_pool_full_data", vec![], provider).await?;
+
+        // see get_pool_full_data in the tonstakers pool contract for the order
+        Ok(Self {
+            state: result.item(0)?.to_u128()? as u8,
+            halted: stack_bool(result.item(1)?),
+            total_balance: result.item(2)?.to_u128()?,
+            interest_rate: result.item(3)?.to_u128()?,
+            deposits_open: stack_bool(result.item(5)?),
+            jetton_minter: result.item(12)?.to_address()?,
+            supply: result.item(13)?.to_u128()?,
+            requested_for_withdrawal: result.item(17)?.to_u128()?,
+            projected_total_balance: result.item(28)?.to_u128()?,
+            projected_supply: result.item(29)?.to_u128()?,
+        })
+    }
+
+    // ton received for burning `ts_ton` at the current rate
+    pub fn to_ton(&self, ts_ton: u128) -> u128 {
+        if self.supply == 0 {
+            return ts_ton;
+        }
+        ts_ton * self.total_balance / self.supply
+    }
+
+    // tsTON minted for `ton`, deposits are minted at the projected rate
+    pub fn to_ts_ton(&self, ton: u128) -> u128 {
+        if self.projected_total_balance == 0 {
+            return ton;
+        }
+        ton * self.projected_supply / self.projected_total_balance
+    }
+}
+
+/// Staked position of an owner in the tonstakers pool.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonstakersStake {
+    pub ts_ton: u128,
+    // unit is nanoton
+    pub ton_value: u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tonstakers_rate() {
+        let pool = TonstakersPool {
+            state: 0,
+            halted: false,
+            total_balance: 1_050_000_000_000,
+            interest_rate: 0,
+            deposits_open: true,
+            jetton_minter: TonAddress::NULL,
+            supply: 1_000_000_000_000,
+            requested_for_withdrawal: 0,
+            projected_total_balance: 1_100_000_000_000,
+            projected_supply: 1_000_000_000_000,
+        };
+
+        assert_eq!(pool.to_ton(1_000_000_000), 1_050_000_000);
+        assert_eq!(pool.to_ts_ton(1_100_000_000), 1_000_000_000);
+
+        assert!(stack_bool(&StackItem::Num(
+            "num".to_string(),
+            "-0x1".to_string()
+        )));
+        assert!(!stack_bool(&StackItem::Num(
+            "num".to_string(),
+            "0x0".to_string()
+        )));
+    }
+}
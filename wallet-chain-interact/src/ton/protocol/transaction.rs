@@ -87,6 +87,22 @@ pub enum TxTypes {
 }
 
 impl<T: std::fmt::Debug> RawMessage<T> {
+    // first 32 bits of the body, None for an empty or text body
+    pub fn op_code(&self) -> crate::Result<Option<u32>> {
+        let MsgData::Raw { body, .. } = &self.msg_data else {
+            return Ok(None);
+        };
+
+        let bag = BagOfCells::parse_base64(body).map_err(TonError::CellBuild)?;
+        let cell = bag.single_root().map_err(TonError::CellBuild)?;
+        if cell.bit_len() < 32 {
+            return Ok(None);
+        }
+        Ok(Some(
+            cell.parser().load_u32(32).map_err(TonError::CellBuild)?,
+        ))
+    }
+
     // 简单验证是否是token交易:目前根据操作码进行判断的,后续估计需要加入地址类型
     pub fn is_token(&self) -> crate::Result<TxTypes> {
         match &self.msg_data {
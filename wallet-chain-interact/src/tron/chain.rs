@@ -1,5 +1,11 @@
 use super::operations::contract::TriggerContractParameter;
-use super::operations::{self, RawData, RawTransactionParams};
+use super::operations::permissions::PermissionUpdateArgs;
+use super::operations::stake::{
+    self, DelegateArgs, FreezeBalanceArgs, ResourceType, UnDelegateArgs,
+};
+use super::operations::{
+    self, RawData, RawTransactionParams, energy, permission_editor, tns, trc10,
+};
 use super::params::ResourceConsumer;
 use super::protocol::account::{AccountResourceDetail, TronAccount};
 use super::provider::Provider;
@@ -26,6 +32,11 @@ impl TronChain {
 impl TronChain {
     pub async fn balance(&self, addr: &str, token: Option<String>) -> crate::Result<U256> {
         if let Some(t) = token {
+            if trc10::is_trc10_id(&t) {
+                let account = self.provider.account_info(addr).await?;
+                return Ok(U256::from(account.asset_balance(&t)));
+            }
+
             let trigger = TriggerContractParameter::token_balance_trigger(&t, addr)?;
             let result = self.provider.trigger_constant_contract(trigger).await?;
             result.parse_u256()
@@ -41,6 +52,10 @@ impl TronChain {
     }
 
     pub async fn decimals(&self, token: &str) -> crate::Result<u8> {
+        if trc10::is_trc10_id(token) {
+            return Ok(self.asset_info(token).await?.precision);
+        }
+
         let trigger = TriggerContractParameter::decimal_trigger(token)?;
 
         let res = self.provider.trigger_constant_contract(trigger).await?;
@@ -50,6 +65,10 @@ impl TronChain {
     }
 
     pub async fn token_symbol(&self, token: &str) -> crate::Result<String> {
+        if trc10::is_trc10_id(token) {
+            return self.asset_info(token).await?.symbol();
+        }
+
         let trigger = TriggerContractParameter::symbol_trigger(token)?;
 
         let res = self.provider.trigger_constant_contract(trigger).await?;
@@ -58,6 +77,10 @@ impl TronChain {
     }
 
     pub async fn token_name(&self, token: &str) -> crate::Result<String> {
+        if trc10::is_trc10_id(token) {
+            return self.asset_info(token).await?.name();
+        }
+
         let trigger = TriggerContractParameter::name_trigger(token)?;
 
         let res = self.provider.trigger_constant_contract(trigger).await?;
@@ -66,6 +89,10 @@ impl TronChain {
         Ok(value.chars().filter(|c| c.is_alphanumeric()).collect())
     }
 
+    pub async fn asset_info(&self, asset_id: &str) -> crate::Result<trc10::AssetIssue> {
+        self.provider.asset_issue_by_id(asset_id).await
+    }
+
     pub async fn black_address(&self, token: &str, owner: &str) -> crate::Result<bool> {
         let trigger = TriggerContractParameter::black_address(token, owner)?;
 
@@ -130,6 +157,11 @@ impl TronChain {
 
         // expiration unit is ms
         let new_time = raw_data.expiration + expiration * 1000;
+        if new_time.saturating_sub(raw_data.timestamp) > operations::multisig::MAX_EXPIRATION_MS {
+            return Err(crate::Error::Other(
+                "multisig transaction expiration exceeds 24 hours".to_string(),
+            ));
+        }
         raw_data.expiration = new_time;
 
         let mut raw = Raw::from_str(&resp.raw_data_hex)?;
@@ -263,3 +295,316 @@ impl TronChain {
     //     Ok(rs)
     // }
 }
+
+// about multisig signatures
+impl TronChain {
+    // loads the permission the transaction uses from the owner account
+    pub async fn multisig_collector(
+        &self,
+        transaction: RawTransactionParams,
+    ) -> crate::Result<operations::multisig::MultisigCollector> {
+        let (owner, permission_id) =
+            operations::multisig::MultisigCollector::permission_id(&transaction.raw_data)?;
+        let account = self.provider.account_info(&owner).await?;
+
+        let permission = if permission_id == 0 {
+            account.owner_permission
+        } else {
+            account
+                .active_permission
+                .into_iter()
+                .find(|p| p.id == Some(permission_id))
+                .ok_or(crate::Error::Other(format!(
+                    "permission {} not found",
+                    permission_id
+                )))?
+        };
+
+        operations::multisig::MultisigCollector::new(transaction, permission)
+    }
+}
+
+// about stake dashboard
+impl TronChain {
+    // everything the staking screen shows, queried in one call
+    pub async fn stake_dashboard(&self, owner: &str) -> crate::Result<stake::StakeDashboard> {
+        let (account, resource, delegate_list, reward, withdrawable) = tokio::try_join!(
+            self.provider.account_info(owner),
+            self.provider.account_resource(owner),
+            self.provider.delegate_others_list(owner),
+            self.provider.get_reward(owner),
+            self.provider.can_withdraw_unfreeze_amount(owner),
+        )?;
+
+        let mut dashboard = stake::StakeDashboard::new(owner, &account, &resource);
+        dashboard.withdrawable = withdrawable.amount;
+        dashboard.reward = reward.reward;
+
+        for to in delegate_list.to_accounts.iter() {
+            let res = self.provider.delegated_resource(owner, to).await?;
+            dashboard.add_delegated_out(&res.delegated_resource);
+        }
+        for from in delegate_list.from_accounts.iter() {
+            let res = self.provider.delegated_resource(from, owner).await?;
+            dashboard.add_delegated_in(&res.delegated_resource);
+        }
+
+        if account.votes.is_empty() {
+            return Ok(dashboard);
+        }
+
+        let (witnesses, chain_params) =
+            tokio::try_join!(self.provider.list_witnesses(), self.provider.chain_params())?;
+        let estimator = stake::VoteRewardEstimator::new(&witnesses.witnesses, &chain_params);
+
+        for vote in account.votes.iter() {
+            // listwitnesses returns hex addresses
+            let address = wallet_utils::address::bs58_addr_to_hex(&vote.vote_address)?;
+            // votes for a witness missing from the list still count towards the tron power used
+            let Some(witness) = witnesses.witnesses.iter().find(|w| w.address == address) else {
+                dashboard.votes.push(stake::VotePosition {
+                    witness: vote.vote_address.clone(),
+                    url: String::new(),
+                    votes: vote.vote_count,
+                    brokerage: 0,
+                    apr: 0.0,
+                });
+                continue;
+            };
+            let brokerage = self.provider.get_brokerage(&address).await?.brokerage;
+
+            dashboard.votes.push(stake::VotePosition {
+                witness: vote.vote_address.clone(),
+                url: witness.url.clone(),
+                votes: vote.vote_count,
+                brokerage,
+                apr: estimator.apr(witness, brokerage),
+            });
+        }
+
+        Ok(dashboard)
+    }
+}
+
+// about permission
+impl TronChain {
+    pub async fn permission_editor(
+        &self,
+        account: &str,
+    ) -> crate::Result<permission_editor::PermissionEditor> {
+        let account = self.provider.account_info(account).await?;
+        permission_editor::PermissionEditor::try_from(&account)
+    }
+
+    // bandwidth of the update plus the permission update fee burned by the chain
+    pub async fn permission_update_fee(
+        &self,
+        account: &str,
+        signature_num: u8,
+        args: PermissionUpdateArgs,
+    ) -> crate::Result<ResourceConsumer> {
+        let mut consumer = self.simple_fee(account, signature_num, args).await?;
+
+        let chain_params = self.provider.chain_params().await?;
+        consumer.set_extra_fee(chain_params.update_account_fee());
+
+        Ok(consumer)
+    }
+}
+
+// about energy plan
+impl TronChain {
+    // compare burning, freezing and delegating for the energy of a contract transaction
+    pub async fn energy_plan(
+        &self,
+        sender: &str,
+        consumer: ResourceConsumer,
+        delegator: Option<&str>,
+    ) -> crate::Result<energy::EnergyPlan> {
+        let account = self.provider.account_info(sender).await?;
+        let resource = self.provider.account_resource(sender).await?;
+        let mut planner = energy::EnergyPlanner::new(sender, consumer, &account, &resource);
+
+        if let Some(delegator) = delegator {
+            let account = self.provider.account_info(delegator).await?;
+            let resource = self.provider.account_resource(delegator).await?;
+            let max_size = self
+                .provider
+                .can_delegate_resource(delegator, ResourceType::ENERGY)
+                .await?;
+
+            let delegator = energy::EnergyDelegator::new(delegator, &account, &resource)
+                .with_max_size(&max_size);
+            planner = planner.with_delegator(delegator);
+        }
+
+        Ok(planner.plan())
+    }
+
+    // executes the steps in order, each one waits for the previous to be in a block.
+    // when a step fails after the delegation, the delegation is taken back and the error
+    // is returned with the ids of the transactions already sent
+    pub async fn exec_energy_plan<T, R>(
+        &self,
+        option: &energy::EnergyPlanOption,
+        transfer: T,
+        key: ChainPrivateKey,
+        delegator_key: Option<ChainPrivateKey>,
+    ) -> crate::Result<Vec<String>>
+    where
+        T: operations::TronTxOperation<R>,
+        R: serde::Serialize + Debug,
+    {
+        let delegator = || {
+            delegator_key.clone().ok_or(crate::Error::Other(
+                "energy plan needs the delegator key".to_string(),
+            ))
+        };
+        let resource = ResourceType::ENERGY.to_string();
+
+        let mut transfer = Some(transfer);
+        let mut tx_ids = vec![];
+        // the delegation of this plan that is not taken back yet
+        let mut delegated = None;
+        for step in option.steps.iter() {
+            let sent = match step {
+                energy::EnergyPlanStep::Freeze { owner, amount } => {
+                    match FreezeBalanceArgs::new(owner, &resource, *amount, None) {
+                        Ok(args) => self.exec_transaction(args, key.clone()).await,
+                        Err(e) => Err(e),
+                    }
+                }
+                energy::EnergyPlanStep::Delegate { from, to, amount } => {
+                    match (DelegateArgs::new(from, to, *amount, &resource), delegator()) {
+                        (Ok(args), Ok(delegator_key)) => {
+                            self.exec_transaction(args, delegator_key).await
+                        }
+                        (Err(e), _) | (_, Err(e)) => Err(e),
+                    }
+                }
+                energy::EnergyPlanStep::Transfer { .. } => match transfer.take() {
+                    Some(transfer) => self.exec_transaction(transfer, key.clone()).await,
+                    None => Err(crate::Error::Other(
+                        "energy plan has more than one transfer".to_string(),
+                    )),
+                },
+                energy::EnergyPlanStep::UnDelegate { from, to, amount } => {
+                    match (
+                        UnDelegateArgs::new(from, to, *amount, &resource, None),
+                        delegator(),
+                    ) {
+                        (Ok(args), Ok(delegator_key)) => {
+                            self.exec_transaction(args, delegator_key).await
+                        }
+                        (Err(e), _) | (_, Err(e)) => Err(e),
+                    }
+                }
+            };
+
+            let res = match sent {
+                Ok(tx_id) => {
+                    tx_ids.push(tx_id.clone());
+                    match step {
+                        energy::EnergyPlanStep::Delegate { .. } => delegated = Some(step),
+                        // once sent the undelegate is not retried
+                        energy::EnergyPlanStep::UnDelegate { .. } => delegated = None,
+                        _ => {}
+                    }
+                    self.wait_confirmed(&tx_id).await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(err) = res {
+                if let Some(energy::EnergyPlanStep::Delegate { from, to, amount }) = delegated
+                    && let Ok(delegator_key) = delegator()
+                {
+                    match UnDelegateArgs::new(from, to, *amount, &resource, None) {
+                        Ok(args) => match self.exec_transaction(args, delegator_key).await {
+                            Ok(tx_id) => tx_ids.push(tx_id),
+                            Err(e) => tracing::warn!("energy plan undelegate failed: {}", e),
+                        },
+                        Err(e) => tracing::warn!("energy plan undelegate failed: {}", e),
+                    }
+                }
+
+                if tx_ids.is_empty() {
+                    return Err(err);
+                }
+                return Err(crate::Error::PartiallySent {
+                    source: Box::new(err),
+                    tx_ids,
+                });
+            }
+        }
+
+        Ok(tx_ids)
+    }
+
+    // wait until the transaction is in a block, the resources it changes apply from there
+    async fn wait_confirmed(&self, tx_id: &str) -> crate::Result<()> {
+        for _ in 0..20 {
+            tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+            if let Some(res) = self.query_tx_res(tx_id).await? {
+                if res.status != 2 {
+                    return Err(crate::Error::Other(format!("transaction {} failed", tx_id)));
+                }
+                return Ok(());
+            }
+        }
+
+        Err(crate::Error::Other(format!(
+            "transaction {} not confirmed",
+            tx_id
+        )))
+    }
+}
+
+// about tron name service, registry is the tns registry contract
+impl TronChain {
+    async fn tns_resolver(&self, registry: &str, name: &str) -> crate::Result<Option<String>> {
+        let res = self
+            .provider
+            .trigger_constant_contract(tns::resolver_trigger(registry, name)?)
+            .await?;
+        tns::parse_address(&res.constant_result)
+    }
+
+    pub async fn resolve_name(&self, registry: &str, name: &str) -> crate::Result<Option<String>> {
+        let name = crate::name::normalize(name)?;
+
+        let Some(resolver) = self.tns_resolver(registry, &name).await? else {
+            return Ok(None);
+        };
+        let res = self
+            .provider
+            .trigger_constant_contract(tns::addr_trigger(&resolver, &name)?)
+            .await?;
+        tns::parse_address(&res.constant_result)
+    }
+
+    // primary name of the address, only returned when it resolves back to the address
+    pub async fn lookup_address(
+        &self,
+        registry: &str,
+        addr: &str,
+    ) -> crate::Result<Option<String>> {
+        let reverse = tns::reverse_name(addr)?;
+
+        let Some(resolver) = self.tns_resolver(registry, &reverse).await? else {
+            return Ok(None);
+        };
+        let res = self
+            .provider
+            .trigger_constant_contract(tns::name_trigger(&resolver, &reverse)?)
+            .await?;
+        let Some(name) = tns::parse_name(&res.constant_result)? else {
+            return Ok(None);
+        };
+
+        match self.resolve_name(registry, &name).await? {
+            Some(forward) if forward == addr.trim() => Ok(Some(name)),
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+// 交易能量的获取方案: 直接燃烧 trx、质押 trx 获取能量、代理账户委托能量后再收回
+use super::stake::CanDelegatedMaxSize;
+use crate::tron::{
+    consts,
+    params::{Resource, ResourceConsumer},
+    protocol::account::{AccountResourceDetail, TronAccount},
+};
+
+// bandwidth of a freeze / delegate / undelegate transaction with one signature
+pub const STAKE_TX_BANDWIDTH: i64 = 280;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EnergyStrategy {
+    // pay the missing energy with trx
+    Burn,
+    // the sender stakes trx for energy before the transaction
+    Freeze,
+    // the delegator lends energy to the sender and takes it back afterwards
+    Delegate,
+}
+
+/// One transaction of a plan, amounts are in trx.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum EnergyPlanStep {
+    Freeze {
+        owner: String,
+        amount: i64,
+    },
+    Delegate {
+        from: String,
+        to: String,
+        amount: i64,
+    },
+    Transfer {
+        from: String,
+    },
+    UnDelegate {
+        from: String,
+        to: String,
+        amount: i64,
+    },
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnergyPlanOption {
+    pub strategy: EnergyStrategy,
+    // unit is sun, trx burned by every transaction of the plan
+    pub fee: i64,
+    // unit is sun, trx staked by the sender, returned 14 days after unfreezing
+    pub locked: i64,
+    // the sender or the delegator has enough trx / stake for the plan
+    pub available: bool,
+    pub steps: Vec<EnergyPlanStep>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnergyPlan {
+    // energy the sender lacks for the transaction
+    pub missing_energy: i64,
+    pub options: Vec<EnergyPlanOption>,
+    // cheapest available option, fewer transactions on a tie
+    pub recommended: Option<EnergyStrategy>,
+}
+
+impl EnergyPlan {
+    pub fn recommended_option(&self) -> Option<&EnergyPlanOption> {
+        let strategy = self.recommended?;
+        self.options.iter().find(|o| o.strategy == strategy)
+    }
+}
+
+/// Account that delegates its staked energy to the sender.
+#[derive(Debug, Clone)]
+pub struct EnergyDelegator {
+    pub address: String,
+    // unit is sun, stake for energy not delegated yet
+    pub delegatable: i64,
+    // bandwidth left for the delegate and undelegate transactions
+    pub available_bandwidth: i64,
+}
+
+impl EnergyDelegator {
+    pub fn new(address: &str, account: &TronAccount, resource: &AccountResourceDetail) -> Self {
+        let delegatable = account
+            .frozen_v2
+            .iter()
+            .filter(|item| item.types == "ENERGY")
+            .map(|item| item.amount)
+            .sum::<i64>();
+
+        Self {
+            address: address.to_string(),
+            delegatable,
+            available_bandwidth: resource.available_bandwidth(),
+        }
+    }
+
+    // energy in use can not be delegated, getcandelegatedmaxsize accounts for it
+    pub fn with_max_size(mut self, max_size: &CanDelegatedMaxSize) -> Self {
+        self.delegatable = self.delegatable.min(max_size.max_size);
+        self
+    }
+}
+
+/// Compares the ways to cover the energy of a contract transaction.
+pub struct EnergyPlanner {
+    pub sender: String,
+    pub consumer: ResourceConsumer,
+    // unit is sun
+    pub balance: i64,
+    pub total_energy_limit: i64,
+    pub total_energy_weight: i64,
+    pub delegator: Option<EnergyDelegator>,
+}
+
+impl EnergyPlanner {
+    // consumer comes from `contract_fee` of the transaction
+    pub fn new(
+        sender: &str,
+        consumer: ResourceConsumer,
+        account: &TronAccount,
+        resource: &AccountResourceDetail,
+    ) -> Self {
+        Self {
+            sender: sender.to_string(),
+            consumer,
+            balance: account.balance,
+            total_energy_limit: resource.total_energy_limit,
+            total_energy_weight: resource.total_energy_weight,
+            delegator: None,
+        }
+    }
+
+    pub fn with_delegator(mut self, delegator: EnergyDelegator) -> Self {
+        self.delegator = Some(delegator);
+        self
+    }
+
+    // trx to stake for the energy, the network shares total_energy_limit by the staked weight
+    pub fn trx_for_energy(&self, energy: i64) -> Option<i64> {
+        if energy <= 0 {
+            return Some(0);
+        }
+        if self.total_energy_limit <= 0 || self.total_energy_weight <= 0 {
+            return None;
+        }
+
+        let numerator = energy as i128 * self.total_energy_weight as i128;
+        let limit = self.total_energy_limit as i128;
+        Some((((numerator + limit - 1) / limit) as i64).max(1))
+    }
+
+    // fee of the transaction bandwidth once the energy is covered
+    fn transfer_fee(&self, available_bandwidth: i64) -> i64 {
+        let bandwidth = Resource::new(
+            available_bandwidth,
+            self.consumer.bandwidth.consumer,
+            self.consumer.bandwidth.price,
+            "bandwidth",
+        );
+        bandwidth.fee() + self.consumer.extra_fee
+    }
+
+    // fee of a staking transaction and the bandwidth left after it
+    fn stake_tx_fee(&self, available_bandwidth: i64) -> (i64, i64) {
+        let bandwidth = Resource::new(
+            available_bandwidth,
+            STAKE_TX_BANDWIDTH,
+            self.consumer.bandwidth.price,
+            "bandwidth",
+        );
+        let fee = bandwidth.fee();
+        let left = if fee == 0 {
+            available_bandwidth - STAKE_TX_BANDWIDTH
+        } else {
+            available_bandwidth
+        };
+        (fee, left)
+    }
+
+    fn burn(&self) -> EnergyPlanOption {
+        let fee = self.consumer.transaction_fee_i64();
+
+        EnergyPlanOption {
+            strategy: EnergyStrategy::Burn,
+            fee,
+            locked: 0,
+            available: self.balance >= fee,
+            steps: vec![EnergyPlanStep::Transfer {
+                from: self.sender.clone(),
+            }],
+        }
+    }
+
+    fn freeze(&self, amount: i64) -> EnergyPlanOption {
+        let (freeze_fee, left) = self.stake_tx_fee(self.consumer.bandwidth.limit);
+        let fee = freeze_fee + self.transfer_fee(left);
+        let locked = amount * consts::TRX_VALUE;
+
+        EnergyPlanOption {
+            strategy: EnergyStrategy::Freeze,
+            fee,
+            locked,
+            available: self.balance >= locked + fee,
+            steps: vec![
+                EnergyPlanStep::Freeze {
+                    owner: self.sender.clone(),
+                    amount,
+                },
+                EnergyPlanStep::Transfer {
+                    from: self.sender.clone(),
+                },
+            ],
+        }
+    }
+
+    // the energy used by the sender goes back to the delegator on undelegate
+    // and recovers there within 24 hours
+    fn delegate(&self, delegator: &EnergyDelegator, amount: i64) -> EnergyPlanOption {
+        let (delegate_fee, left) = self.stake_tx_fee(delegator.available_bandwidth);
+        let (undelegate_fee, _) = self.stake_tx_fee(left);
+        let transfer_fee = self.transfer_fee(self.consumer.bandwidth.limit);
+
+        EnergyPlanOption {
+            strategy: EnergyStrategy::Delegate,
+            fee: delegate_fee + undelegate_fee + transfer_fee,
+            locked: 0,
+            available: delegator.delegatable >= amount * consts::TRX_VALUE
+                && self.balance >= transfer_fee,
+            steps: vec![
+                EnergyPlanStep::Delegate {
+                    from: delegator.address.clone(),
+                    to: self.sender.clone(),
+                    amount,
+                },
+                EnergyPlanStep::Transfer {
+                    from: self.sender.clone(),
+                },
+                EnergyPlanStep::UnDelegate {
+                    from: delegator.address.clone(),
+                    to: self.sender.clone(),
+                    amount,
+                },
+            ],
+        }
+    }
+
+    pub fn plan(&self) -> EnergyPlan {
+        let missing_energy = self.consumer.need_extra_energy();
+
+        let mut options = vec![self.burn()];
+        if missing_energy > 0
+            && let Some(amount) = self.trx_for_energy(missing_energy)
+        {
+            options.push(self.freeze(amount));
+            if let Some(delegator) = self.delegator.as_ref() {
+                options.push(self.delegate(delegator, amount));
+            }
+        }
+
+        let recommended = options
+            .iter()
+            .filter(|o| o.available)
+            .min_by_key(|o| (o.fee, o.steps.len()))
+            .map(|o| o.strategy);
+
+        EnergyPlan {
+            missing_energy,
+            options,
+            recommended,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENDER: &str = "TXYZopYRdj2D9XRtbG411XZZ3kM5VkAeBf";
+    const DELEGATOR: &str = "TN3W4H6rK2ce4vX9YnFQHwKENnHjoxb3m9";
+
+    // trc20 transfer needing 65000 energy, 345 bytes, no free resources left
+    fn planner(balance: i64) -> EnergyPlanner {
+        let bandwidth = Resource::new(0, 345, 1000, "bandwidth");
+        let energy = Resource::new(0, 65_000, 210, "energy");
+        let consumer = ResourceConsumer::new(bandwidth, Some(energy));
+
+        EnergyPlanner {
+            sender: SENDER.to_string(),
+            consumer,
+            balance,
+            // about 10 energy per staked trx
+            total_energy_limit: 180_000_000_000,
+            total_energy_weight: 18_000_000_000,
+            delegator: None,
+        }
+    }
+
+    #[test]
+    fn test_trx_for_energy() {
+        let planner = planner(0);
+        assert_eq!(planner.trx_for_energy(0), Some(0));
+        assert_eq!(planner.trx_for_energy(65_000), Some(6_500));
+        assert_eq!(planner.trx_for_energy(65_001), Some(6_501));
+        assert_eq!(planner.trx_for_energy(1), Some(1));
+    }
+
+    #[test]
+    fn test_plan() {
+        // 13.65 trx burned for energy, 0.345 for bandwidth
+        let plan = planner(20 * consts::TRX_VALUE).plan();
+        assert_eq!(plan.missing_energy, 65_000);
+        assert_eq!(plan.options[0].fee, 13_995_000);
+        // not enough trx to stake 6500
+        assert!(!plan.options[1].available);
+        assert_eq!(plan.recommended, Some(EnergyStrategy::Burn));
+
+        let delegator = EnergyDelegator {
+            address: DELEGATOR.to_string(),
+            delegatable: 10_000 * consts::TRX_VALUE,
+            available_bandwidth: 600,
+        };
+        let plan = planner(20 * consts::TRX_VALUE)
+            .with_delegator(delegator)
+            .plan();
+        let option = plan.recommended_option().unwrap();
+        assert_eq!(option.strategy, EnergyStrategy::Delegate);
+        // delegate and undelegate use the free bandwidth of the delegator
+        assert_eq!(option.fee, 345_000);
+        assert!(matches!(
+            option.steps.as_slice(),
+            [
+                EnergyPlanStep::Delegate { amount: 6_500, .. },
+                EnergyPlanStep::Transfer { .. },
+                EnergyPlanStep::UnDelegate { .. }
+            ]
+        ));
+
+        let plan = planner(10_000 * consts::TRX_VALUE).plan();
+        assert_eq!(plan.recommended, Some(EnergyStrategy::Freeze));
+        assert_eq!(plan.options[1].locked, 6_500 * consts::TRX_VALUE);
+        assert_eq!(plan.options[1].fee, 280_000 + 345_000);
+    }
+}
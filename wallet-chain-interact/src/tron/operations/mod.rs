@@ -6,11 +6,15 @@ use std::fmt::Debug;
 use wallet_utils::serde_func;
 
 pub mod contract;
+pub mod energy;
 pub mod multisig;
+pub mod permission_editor;
 pub mod permissions;
 pub mod stake;
+pub mod tns;
 pub mod transfer;
 pub mod trc;
+pub mod trc10;
 
 #[async_trait]
 pub trait TronTxOperation<T> {
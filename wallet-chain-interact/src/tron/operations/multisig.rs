@@ -1,4 +1,4 @@
-use super::{RawTransactionParams, TronTransactionResponse, TronTxOperation};
+use super::{RawData, RawTransactionParams, TronTransactionResponse, TronTxOperation};
 use crate::{
     tron::{consts::PERMISSION, provider::Provider},
     types::{ChainPrivateKey, MultisigSignResp},
@@ -209,3 +209,226 @@ impl TransactionOpt {
         })
     }
 }
+
+// the chain rejects transactions expiring more than 24 hours after creation
+pub const MAX_EXPIRATION_MS: u64 = 24 * 3600 * 1000;
+// time left before the proposer should rebuild the transaction
+pub const EXPIRATION_WARNING_MS: u64 = 30 * 60 * 1000;
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerWeight {
+    // base58 address
+    pub address: String,
+    pub weight: i32,
+    pub signature: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigProgress {
+    pub tx_id: String,
+    pub threshold: i64,
+    pub weight: i64,
+    // weight still missing to reach the threshold
+    pub remaining: i64,
+    pub signers: Vec<SignerWeight>,
+    // unit is ms
+    pub expiration: u64,
+    pub expires_in: i64,
+    // close to expiration, the transaction should be rebuilt if not ready
+    pub expiring: bool,
+    pub expired: bool,
+}
+
+/// Collects the signatures of a multisig transaction against the permission it uses.
+pub struct MultisigCollector {
+    pub transaction: RawTransactionParams,
+    pub permission: Permission,
+    pub expiration: u64,
+    pub signers: Vec<SignerWeight>,
+}
+
+impl MultisigCollector {
+    // signatures already in the transaction are checked as well
+    pub fn new(transaction: RawTransactionParams, permission: Permission) -> crate::Result<Self> {
+        let raw_data = wallet_utils::serde_func::serde_from_str::<RawData<serde_json::Value>>(
+            &transaction.raw_data,
+        )?;
+
+        let mut collector = Self {
+            expiration: raw_data.expiration,
+            signers: vec![],
+            permission,
+            transaction,
+        };
+
+        let signatures = std::mem::take(&mut collector.transaction.signature);
+        for signature in signatures.iter() {
+            collector.add_signature(signature)?;
+        }
+        Ok(collector)
+    }
+
+    // Permission_id of the first contract, 0 is the owner permission
+    pub fn permission_id(raw_data: &str) -> crate::Result<(String, i8)> {
+        let raw_data =
+            wallet_utils::serde_func::serde_from_str::<RawData<serde_json::Value>>(raw_data)?;
+        let contract = raw_data.contract.first().ok_or(crate::Error::Other(
+            "transaction has no contract".to_string(),
+        ))?;
+
+        let owner = contract.parameter.value["owner_address"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        Ok((owner, contract.permission_id.unwrap_or_default() as i8))
+    }
+
+    fn key_weight(&self, address: &str) -> crate::Result<Option<i32>> {
+        let hex = address::bs58_addr_to_hex(address)?;
+        for key in self.permission.keys.iter() {
+            // account info returns base58 keys, update args use hex
+            let key_hex = if key.address.starts_with('T') {
+                address::bs58_addr_to_hex(&key.address)?
+            } else {
+                key.address.clone()
+            };
+            if key_hex == hex {
+                return Ok(Some(key.weight));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn add_signature(&mut self, signature: &str) -> crate::Result<&SignerWeight> {
+        let address = sign::recover_tron_address(&self.transaction.tx_id, signature)?;
+
+        if self.signers.iter().any(|s| s.address == address) {
+            return Err(crate::Error::Other(format!(
+                "{} has already signed",
+                address
+            )));
+        }
+        let weight = self
+            .key_weight(&address)?
+            .ok_or(crate::Error::Other(format!(
+                "{} is not a key of {}",
+                address, self.permission.permission_name
+            )))?;
+
+        self.signers.push(SignerWeight {
+            address,
+            weight,
+            signature: signature.to_string(),
+        });
+        Ok(self.signers.last().unwrap())
+    }
+
+    pub fn weight(&self) -> i64 {
+        self.signers.iter().map(|s| s.weight as i64).sum()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.weight() >= self.permission.threshold as i64
+    }
+
+    // now unit is ms
+    pub fn progress(&self, now: u64) -> MultisigProgress {
+        let threshold = self.permission.threshold as i64;
+        let weight = self.weight();
+        let expires_in = self.expiration as i64 - now as i64;
+
+        MultisigProgress {
+            tx_id: self.transaction.tx_id.clone(),
+            threshold,
+            weight,
+            remaining: (threshold - weight).max(0),
+            signers: self.signers.clone(),
+            expiration: self.expiration,
+            expires_in,
+            expiring: expires_in > 0 && expires_in <= EXPIRATION_WARNING_MS as i64,
+            expired: expires_in <= 0,
+        }
+    }
+
+    // the transaction with the collected signatures, once the threshold is reached
+    pub fn into_transaction(mut self, now: u64) -> crate::Result<RawTransactionParams> {
+        if self.expiration <= now {
+            return Err(crate::Error::Other(format!(
+                "transaction {} expired",
+                self.transaction.tx_id
+            )));
+        }
+        if !self.is_ready() {
+            return Err(crate::Error::Other(format!(
+                "signature weight {} is below the threshold {}",
+                self.weight(),
+                self.permission.threshold
+            )));
+        }
+
+        self.transaction.signature = self.signers.into_iter().map(|s| s.signature).collect();
+        Ok(self.transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY1: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const KEY2: &str = "0000000000000000000000000000000000000000000000000000000000000002";
+    const KEY3: &str = "0000000000000000000000000000000000000000000000000000000000000003";
+    const TX_ID: &str = "069cce46b57b652b1d04ca2d74abe86b605d9d737879b138b631c43e3cb54328";
+
+    fn collector() -> MultisigCollector {
+        let keys = [KEY1, KEY2]
+            .iter()
+            .map(|k| {
+                let sign = sign::sign_tron(TX_ID, k, None).unwrap();
+                let address = sign::recover_tron_address(TX_ID, &sign).unwrap();
+                Keys::new(&address, 1).unwrap()
+            })
+            .collect();
+
+        let raw_data = json!({
+            "contract": [],
+            "ref_block_bytes": "",
+            "ref_block_hash": "",
+            "expiration": 10_000_000u64,
+            "timestamp": 0,
+        });
+        let transaction = RawTransactionParams {
+            tx_id: TX_ID.to_string(),
+            raw_data: raw_data.to_string(),
+            raw_data_hex: String::new(),
+            signature: vec![],
+        };
+
+        MultisigCollector::new(transaction, Permission::new_owner(2, keys)).unwrap()
+    }
+
+    #[test]
+    fn test_collect_signatures() {
+        let mut collector = collector();
+        let sign1 = sign::sign_tron(TX_ID, KEY1, None).unwrap();
+
+        collector.add_signature(&sign1).unwrap();
+        assert!(collector.add_signature(&sign1).is_err());
+        // not a member of the permission
+        let sign3 = sign::sign_tron(TX_ID, KEY3, None).unwrap();
+        assert!(collector.add_signature(&sign3).is_err());
+
+        let progress = collector.progress(10_000_000 - 60_000);
+        assert_eq!(progress.remaining, 1);
+        assert!(progress.expiring);
+
+        let sign2 = sign::sign_tron(TX_ID, KEY2, Some(27)).unwrap();
+        collector.add_signature(&sign2).unwrap();
+        assert!(collector.is_ready());
+
+        let tx = collector.into_transaction(0).unwrap();
+        assert_eq!(tx.signature.len(), 2);
+    }
+}
@@ -0,0 +1,410 @@
+// 账户权限编辑: 基于链上权限做增量修改,构建交易前校验,避免错误的权限更新锁死账户
+use super::{
+    multisig::{Keys, Permission},
+    permissions::{ContractType, PermissionTypes, PermissionUpdateArgs},
+};
+use crate::tron::protocol::account::TronAccount;
+use std::collections::HashSet;
+use wallet_utils::address;
+
+// chain limits, see AccountPermissionUpdateActuator in java-tron
+pub const MAX_PERMISSION_KEYS: usize = 5;
+pub const MAX_ACTIVE_PERMISSIONS: usize = 8;
+pub const MAX_PERMISSION_NAME_LEN: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionTarget {
+    Owner,
+    // index in the active permissions
+    Active(usize),
+}
+
+/// One edit of the permissions, addresses are base58.
+#[derive(Debug, Clone)]
+pub enum PermissionChange {
+    AddKey {
+        target: PermissionTarget,
+        address: String,
+        weight: i32,
+    },
+    RemoveKey {
+        target: PermissionTarget,
+        address: String,
+    },
+    SetWeight {
+        target: PermissionTarget,
+        address: String,
+        weight: i32,
+    },
+    SetThreshold {
+        target: PermissionTarget,
+        threshold: u8,
+    },
+    SetOperation {
+        index: usize,
+        contract: ContractType,
+        enabled: bool,
+    },
+    AddActive {
+        name: String,
+        threshold: u8,
+        keys: Vec<(String, i32)>,
+        operations: Vec<ContractType>,
+    },
+    RemoveActive {
+        index: usize,
+    },
+}
+
+pub struct PermissionEditor {
+    pub owner_address: String,
+    pub owner: Permission,
+    pub actives: Vec<Permission>,
+    // applied changes in order
+    pub changes: Vec<PermissionChange>,
+}
+
+impl TryFrom<&TronAccount> for PermissionEditor {
+    type Error = crate::Error;
+
+    fn try_from(account: &TronAccount) -> Result<Self, Self::Error> {
+        let args = PermissionUpdateArgs::try_from(account)?;
+
+        Ok(Self {
+            owner_address: args.owner_address,
+            owner: args.owner,
+            actives: args.actives,
+            changes: vec![],
+        })
+    }
+}
+
+impl PermissionEditor {
+    fn permission_mut(&mut self, target: PermissionTarget) -> crate::Result<&mut Permission> {
+        match target {
+            PermissionTarget::Owner => Ok(&mut self.owner),
+            PermissionTarget::Active(index) => {
+                self.actives
+                    .get_mut(index)
+                    .ok_or(crate::Error::Other(format!(
+                        "active permission {} not found",
+                        index
+                    )))
+            }
+        }
+    }
+
+    fn key_mut<'a>(permission: &'a mut Permission, address: &str) -> crate::Result<&'a mut Keys> {
+        let hex = address::bs58_addr_to_hex(address)?;
+        permission
+            .keys
+            .iter_mut()
+            .find(|k| k.address == hex)
+            .ok_or(crate::Error::Other(format!(
+                "{} is not a key of {}",
+                address, permission.permission_name
+            )))
+    }
+
+    pub fn apply(&mut self, change: PermissionChange) -> crate::Result<()> {
+        match &change {
+            PermissionChange::AddKey {
+                target,
+                address,
+                weight,
+            } => {
+                let key = Keys::new(address, *weight)?;
+                let permission = self.permission_mut(*target)?;
+                if permission.keys.iter().any(|k| k.address == key.address) {
+                    return Err(crate::Error::Other(format!(
+                        "{} is already a key of {}",
+                        address, permission.permission_name
+                    )));
+                }
+                permission.keys.push(key);
+            }
+            PermissionChange::RemoveKey { target, address } => {
+                let hex = address::bs58_addr_to_hex(address)?;
+                let permission = self.permission_mut(*target)?;
+                let len = permission.keys.len();
+                permission.keys.retain(|k| k.address != hex);
+                if permission.keys.len() == len {
+                    return Err(crate::Error::Other(format!(
+                        "{} is not a key of {}",
+                        address, permission.permission_name
+                    )));
+                }
+            }
+            PermissionChange::SetWeight {
+                target,
+                address,
+                weight,
+            } => {
+                let permission = self.permission_mut(*target)?;
+                Self::key_mut(permission, address)?.weight = *weight;
+            }
+            PermissionChange::SetThreshold { target, threshold } => {
+                self.permission_mut(*target)?.threshold = *threshold;
+            }
+            PermissionChange::SetOperation {
+                index,
+                contract,
+                enabled,
+            } => {
+                let permission = self.permission_mut(PermissionTarget::Active(*index))?;
+                let operations = permission.operations.as_deref().unwrap_or_default();
+                let operations = toggle_operation(operations, *contract, *enabled)?;
+                permission.operations = Some(operations);
+            }
+            PermissionChange::AddActive {
+                name,
+                threshold,
+                keys,
+                operations,
+            } => {
+                let keys = keys
+                    .iter()
+                    .map(|(address, weight)| Keys::new(address, *weight))
+                    .collect::<crate::Result<Vec<_>>>()?;
+                let operations = PermissionTypes(operations.clone()).to_hex();
+
+                self.actives.push(Permission::new_actives(
+                    name.clone(),
+                    operations,
+                    *threshold,
+                    keys,
+                ));
+            }
+            PermissionChange::RemoveActive { index } => {
+                if *index >= self.actives.len() {
+                    return Err(crate::Error::Other(format!(
+                        "active permission {} not found",
+                        index
+                    )));
+                }
+                self.actives.remove(*index);
+            }
+        }
+
+        self.changes.push(change);
+        Ok(())
+    }
+
+    // contract types the active permission allows
+    pub fn operations(&self, index: usize) -> crate::Result<Vec<ContractType>> {
+        let permission = self.actives.get(index).ok_or(crate::Error::Other(format!(
+            "active permission {} not found",
+            index
+        )))?;
+        decode_operations(permission.operations.as_deref().unwrap_or_default())
+    }
+
+    // `signers` are the base58 addresses whose keys the user holds,
+    // the owner permission has to be reachable with them or the account is locked
+    pub fn validate(&self, signers: &[String]) -> crate::Result<()> {
+        validate_permission(&self.owner)?;
+        if self.owner.operations.is_some() {
+            return Err(crate::Error::Other(
+                "owner permission can not set operations".to_string(),
+            ));
+        }
+
+        if self.actives.is_empty() || self.actives.len() > MAX_ACTIVE_PERMISSIONS {
+            return Err(crate::Error::Other(format!(
+                "active permissions must be between 1 and {}",
+                MAX_ACTIVE_PERMISSIONS
+            )));
+        }
+        for active in self.actives.iter() {
+            validate_permission(active)?;
+
+            let operations = active.operations.as_deref().unwrap_or_default();
+            if decode_operations(operations)?.is_empty() {
+                return Err(crate::Error::Other(format!(
+                    "{} has no operations",
+                    active.permission_name
+                )));
+            }
+        }
+
+        let signers = signers
+            .iter()
+            .map(|s| address::bs58_addr_to_hex(s))
+            .collect::<Result<HashSet<_>, _>>()?;
+        let weight = self
+            .owner
+            .keys
+            .iter()
+            .filter(|k| signers.contains(&k.address))
+            .map(|k| k.weight as i64)
+            .sum::<i64>();
+        if weight < self.owner.threshold as i64 {
+            return Err(crate::Error::Other(format!(
+                "known keys reach weight {} of the owner threshold {}, the account would be locked",
+                weight, self.owner.threshold
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn build(&self, signers: &[String]) -> crate::Result<PermissionUpdateArgs> {
+        self.validate(signers)?;
+
+        Ok(PermissionUpdateArgs {
+            owner_address: self.owner_address.clone(),
+            owner: self.owner.clone(),
+            actives: self.actives.clone(),
+        })
+    }
+}
+
+fn validate_permission(permission: &Permission) -> crate::Result<()> {
+    let name = &permission.permission_name;
+    if name.len() > MAX_PERMISSION_NAME_LEN {
+        return Err(crate::Error::Other(format!(
+            "permission name {} is longer than {}",
+            name, MAX_PERMISSION_NAME_LEN
+        )));
+    }
+
+    if permission.keys.is_empty() || permission.keys.len() > MAX_PERMISSION_KEYS {
+        return Err(crate::Error::Other(format!(
+            "{} keys must be between 1 and {}",
+            name, MAX_PERMISSION_KEYS
+        )));
+    }
+
+    let mut addresses = HashSet::new();
+    for key in permission.keys.iter() {
+        if key.weight <= 0 {
+            return Err(crate::Error::Other(format!(
+                "{} key {} weight must be positive",
+                name, key.address
+            )));
+        }
+        if !addresses.insert(key.address.as_str()) {
+            return Err(crate::Error::Other(format!(
+                "{} has duplicate key {}",
+                name, key.address
+            )));
+        }
+    }
+
+    let total = permission.keys.iter().map(|k| k.weight as i64).sum::<i64>();
+    if permission.threshold == 0 || total < permission.threshold as i64 {
+        return Err(crate::Error::Other(format!(
+            "{} threshold {} is not reachable with total weight {}",
+            name, permission.threshold, total
+        )));
+    }
+
+    Ok(())
+}
+
+// bits of unknown contract types are rejected by the chain
+pub fn decode_operations(operations: &str) -> crate::Result<Vec<ContractType>> {
+    if operations.is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(PermissionTypes::from_i8(PermissionTypes::from_hex(operations)?)?.0)
+}
+
+pub fn toggle_operation(
+    operations: &str,
+    contract: ContractType,
+    enabled: bool,
+) -> crate::Result<String> {
+    let mut types = decode_operations(operations)?;
+    types.retain(|t| *t != contract);
+    if enabled {
+        types.push(contract);
+    }
+    Ok(PermissionTypes(types).to_hex())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tron::consts::PERMISSION;
+
+    const OWNER: &str = "TXYZopYRdj2D9XRtbG411XZZ3kM5VkAeBf";
+    const OTHER: &str = "TN3W4H6rK2ce4vX9YnFQHwKENnHjoxb3m9";
+
+    fn editor() -> PermissionEditor {
+        let key = Keys::new(OWNER, 1).unwrap();
+        PermissionEditor {
+            owner_address: address::bs58_addr_to_hex(OWNER).unwrap(),
+            owner: Permission::new_owner(1, vec![key.clone()]),
+            actives: vec![Permission::new_actives(
+                "active".to_string(),
+                PERMISSION.to_string(),
+                1,
+                vec![key],
+            )],
+            changes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_owner_satisfiable() {
+        let mut editor = editor();
+        let signers = vec![OWNER.to_string()];
+        assert!(editor.validate(&signers).is_ok());
+
+        editor
+            .apply(PermissionChange::AddKey {
+                target: PermissionTarget::Owner,
+                address: OTHER.to_string(),
+                weight: 1,
+            })
+            .unwrap();
+        editor
+            .apply(PermissionChange::SetThreshold {
+                target: PermissionTarget::Owner,
+                threshold: 2,
+            })
+            .unwrap();
+        // the other key is not held by the user
+        assert!(editor.validate(&signers).is_err());
+        assert!(
+            editor
+                .validate(&[OWNER.to_string(), OTHER.to_string()])
+                .is_ok()
+        );
+
+        editor
+            .apply(PermissionChange::RemoveKey {
+                target: PermissionTarget::Owner,
+                address: OWNER.to_string(),
+            })
+            .unwrap();
+        // threshold 2 with a single key of weight 1
+        assert!(editor.build(&[OTHER.to_string()]).is_err());
+        assert_eq!(editor.changes.len(), 3);
+    }
+
+    #[test]
+    fn test_toggle_operation() {
+        let mut editor = editor();
+        assert!(
+            editor
+                .operations(0)
+                .unwrap()
+                .contains(&ContractType::AccountPermissionUpdateContract)
+        );
+
+        editor
+            .apply(PermissionChange::SetOperation {
+                index: 0,
+                contract: ContractType::AccountPermissionUpdateContract,
+                enabled: false,
+            })
+            .unwrap();
+        let operations = editor.operations(0).unwrap();
+        assert!(!operations.contains(&ContractType::AccountPermissionUpdateContract));
+        assert!(operations.contains(&ContractType::TransferContract));
+
+        assert!(decode_operations("ff").is_err());
+    }
+}
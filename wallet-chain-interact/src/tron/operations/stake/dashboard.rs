@@ -0,0 +1,258 @@
+// 质押 2.0 总览: 汇总质押、解质押、代理、投票以及奖励
+use super::{DelegateResouce, ResourceType, Witness};
+use crate::tron::protocol::{
+    account::{AccountResourceDetail, TronAccount},
+    chain_parameter::ChainParameter,
+};
+
+// one block every 3 seconds
+pub const BLOCKS_PER_YEAR: i64 = 365 * 24 * 1200;
+// witnesses producing blocks
+pub const ACTIVE_WITNESSES: usize = 27;
+// witnesses sharing the vote reward
+pub const REWARDED_WITNESSES: usize = 127;
+
+// frozen bandwidth entries come without a type
+fn resource_of(types: &str) -> ResourceType {
+    if types == "ENERGY" {
+        ResourceType::ENERGY
+    } else {
+        ResourceType::BANDWIDTH
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrozenAmount {
+    // unit is sun
+    pub bandwidth: i64,
+    pub energy: i64,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingUnfreeze {
+    pub resource: ResourceType,
+    // unit is sun
+    pub amount: i64,
+    // unit is ms
+    pub unlock_time: i64,
+    pub withdrawable: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationPosition {
+    // the receiver for delegations out, the delegator for delegations in
+    pub address: String,
+    pub resource: ResourceType,
+    // unit is sun
+    pub amount: i64,
+    // unit is ms, 0 when the delegation is not locked
+    pub expire_time: i64,
+    pub locked: bool,
+}
+
+impl DelegationPosition {
+    fn from_resource(
+        resource: &DelegateResouce,
+        address: &str,
+        now: i64,
+    ) -> impl Iterator<Item = DelegationPosition> {
+        let positions = [
+            (
+                ResourceType::BANDWIDTH,
+                resource.frozen_balance_for_bandwidth,
+                resource.expire_time_for_bandwidth,
+            ),
+            (
+                ResourceType::ENERGY,
+                resource.frozen_balance_for_energy,
+                resource.expire_time_for_energy,
+            ),
+        ];
+
+        let address = address.to_string();
+        positions
+            .into_iter()
+            .filter(|(_, amount, _)| *amount > 0)
+            .map(move |(resource, amount, expire_time)| DelegationPosition {
+                address: address.clone(),
+                resource,
+                amount,
+                expire_time,
+                locked: expire_time > now,
+            })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VotePosition {
+    pub witness: String,
+    pub url: String,
+    pub votes: i64,
+    // percent kept by the witness
+    pub brokerage: i64,
+    // estimated yearly reward per vote, 0.05 is 5%
+    pub apr: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeDashboard {
+    pub address: String,
+    // unit is sun
+    pub balance: i64,
+    pub frozen: FrozenAmount,
+    pub unfreezing: Vec<PendingUnfreeze>,
+    // unit is sun
+    pub withdrawable: i64,
+    pub delegated_out: Vec<DelegationPosition>,
+    pub delegated_in: Vec<DelegationPosition>,
+    pub votes: Vec<VotePosition>,
+    pub tron_power_used: i64,
+    pub tron_power_limit: i64,
+    // unit is sun
+    pub reward: i64,
+}
+
+impl StakeDashboard {
+    pub fn new(address: &str, account: &TronAccount, resource: &AccountResourceDetail) -> Self {
+        let now = wallet_utils::time::now().timestamp_millis();
+
+        let mut frozen = FrozenAmount::default();
+        for item in account.frozen_v2.iter() {
+            match resource_of(&item.types) {
+                ResourceType::ENERGY => frozen.energy += item.amount,
+                ResourceType::BANDWIDTH => frozen.bandwidth += item.amount,
+            }
+        }
+
+        let unfreezing = account
+            .unfreeze_v2
+            .iter()
+            .map(|item| PendingUnfreeze {
+                resource: resource_of(&item.types),
+                amount: item.unfreeze_amount,
+                unlock_time: item.unfreeze_expire_time,
+                withdrawable: item.unfreeze_expire_time <= now,
+            })
+            .collect();
+
+        Self {
+            address: address.to_string(),
+            balance: account.balance,
+            frozen,
+            unfreezing,
+            withdrawable: 0,
+            delegated_out: vec![],
+            delegated_in: vec![],
+            votes: vec![],
+            tron_power_used: resource.tron_power_used,
+            tron_power_limit: resource.tron_power_limit,
+            reward: 0,
+        }
+    }
+
+    pub fn add_delegated_out(&mut self, resources: &[DelegateResouce]) {
+        let now = wallet_utils::time::now().timestamp_millis();
+        for item in resources {
+            self.delegated_out
+                .extend(DelegationPosition::from_resource(item, &item.to, now));
+        }
+    }
+
+    pub fn add_delegated_in(&mut self, resources: &[DelegateResouce]) {
+        let now = wallet_utils::time::now().timestamp_millis();
+        for item in resources {
+            self.delegated_in
+                .extend(DelegationPosition::from_resource(item, &item.from, now));
+        }
+    }
+}
+
+/// Shares of the block and vote rewards between witnesses.
+pub struct VoteRewardEstimator {
+    // unit is sun
+    pub block_reward: i64,
+    pub vote_reward: i64,
+    pub active_witnesses: Vec<String>,
+    // votes of the 127 rewarded witnesses
+    pub total_votes: i64,
+}
+
+impl VoteRewardEstimator {
+    pub fn new(witnesses: &[Witness], chain_params: &ChainParameter) -> Self {
+        let mut sorted = witnesses.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| b.vote_count.cmp(&a.vote_count));
+
+        Self {
+            block_reward: chain_params.witness_pay_per_block(),
+            vote_reward: chain_params.witness_127_pay_per_block(),
+            active_witnesses: sorted
+                .iter()
+                .take(ACTIVE_WITNESSES)
+                .map(|w| w.address.clone())
+                .collect(),
+            total_votes: sorted
+                .iter()
+                .take(REWARDED_WITNESSES)
+                .map(|w| w.vote_count)
+                .sum(),
+        }
+    }
+
+    // yearly reward of one vote after the brokerage of the witness
+    pub fn apr(&self, witness: &Witness, brokerage: i64) -> f64 {
+        if witness.vote_count <= 0 || self.total_votes <= 0 {
+            return 0.0;
+        }
+
+        let votes = witness.vote_count as f64;
+        let years = BLOCKS_PER_YEAR as f64;
+
+        let mut reward = self.vote_reward as f64 * years * votes / self.total_votes as f64;
+        if self.active_witnesses.contains(&witness.address) {
+            reward += self.block_reward as f64 * years / ACTIVE_WITNESSES as f64;
+        }
+
+        // votes are in trx, rewards in sun
+        let keep = (100 - brokerage.clamp(0, 100)) as f64 / 100.0;
+        reward * keep / (votes * crate::tron::consts::TRX_VALUE as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn witness(address: &str, vote_count: i64) -> Witness {
+        serde_json::from_value(serde_json::json!({
+            "address": address,
+            "voteCount": vote_count,
+            "url": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apr() {
+        let estimator = VoteRewardEstimator {
+            block_reward: 16_000_000,
+            vote_reward: 160_000_000,
+            active_witnesses: vec!["a".to_string()],
+            total_votes: 40_000_000_000,
+        };
+
+        // 160 trx per block shared by 40 billion votes
+        let apr = estimator.apr(&witness("b", 1_000_000_000), 0);
+        assert!((apr - 0.042048).abs() < 1e-6);
+
+        // an active witness also shares the block reward, 20% goes to the witness
+        let apr = estimator.apr(&witness("a", 1_000_000_000), 20);
+        assert!((apr - (0.042048 + 0.00622933) * 0.8).abs() < 1e-6);
+
+        assert_eq!(estimator.apr(&witness("c", 0), 0), 0.0);
+    }
+}
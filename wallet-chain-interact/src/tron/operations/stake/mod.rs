@@ -1,3 +1,6 @@
+pub mod dashboard;
+pub use dashboard::*;
+
 pub mod delegate;
 pub use delegate::*;
 
@@ -6,7 +6,8 @@ use crate::tron::{
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct Reward {
     #[serde(default)]
-    reward: i64,
+    // unit is sun
+    pub reward: i64,
 }
 impl Reward {
     pub fn to_sun(&self) -> f64 {
@@ -0,0 +1,118 @@
+// tron name service(`.trx`), an ens style registry deployed on tron:
+// registry.resolver(namehash) then resolver.addr(namehash) / resolver.name(namehash).
+// the registry has no canonical deployment known to the wallet, so it is passed by the caller
+use super::contract::TriggerContractParameter;
+use crate::eth::operations::ens;
+use alloy::{primitives::Address, sol_types::SolValue as _};
+
+// the node of a name as the abi encoded bytes32 parameter
+fn node_parameter(name: &str) -> String {
+    hex::encode(ens::namehash(name))
+}
+
+// registry and resolver are base58 tron addresses
+fn trigger(contract: &str, function: &str, name: &str) -> crate::Result<TriggerContractParameter> {
+    let contract = wallet_utils::address::bs58_addr_to_hex(contract)?;
+
+    Ok(TriggerContractParameter::new(
+        &contract,
+        &contract,
+        function,
+        node_parameter(name),
+    ))
+}
+
+pub fn resolver_trigger(registry: &str, name: &str) -> crate::Result<TriggerContractParameter> {
+    trigger(registry, "resolver(bytes32)", name)
+}
+
+pub fn addr_trigger(resolver: &str, name: &str) -> crate::Result<TriggerContractParameter> {
+    trigger(resolver, "addr(bytes32)", name)
+}
+
+pub fn name_trigger(resolver: &str, name: &str) -> crate::Result<TriggerContractParameter> {
+    trigger(resolver, "name(bytes32)", name)
+}
+
+// reverse record of a tron address, `<hex address without 41>.addr.reverse`
+pub fn reverse_name(address: &str) -> crate::Result<String> {
+    let hex = wallet_utils::address::bs58_addr_to_hex(address)?;
+    let address = wallet_utils::address::parse_eth_address(&hex[2..])?;
+    Ok(ens::reverse_name(address))
+}
+
+// address returned by the registry or resolver, the zero address means no record
+pub fn parse_address(constant_result: &[String]) -> crate::Result<Option<String>> {
+    let Some(word) = constant_result.first().filter(|r| r.len() == 64) else {
+        return Ok(None);
+    };
+    let bytes = wallet_utils::hex_func::hex_decode(word)?;
+    let address = Address::from_slice(&bytes[12..]);
+    if address == Address::ZERO {
+        return Ok(None);
+    }
+
+    Ok(Some(wallet_utils::address::hex_to_bs58_addr(&format!(
+        "41{}",
+        hex::encode(address)
+    ))?))
+}
+
+// name of the reverse record, empty when not set
+pub fn parse_name(constant_result: &[String]) -> crate::Result<Option<String>> {
+    let Some(data) = constant_result.first().filter(|r| !r.is_empty()) else {
+        return Ok(None);
+    };
+    let bytes = wallet_utils::hex_func::hex_decode(data)?;
+    let name =
+        String::abi_decode(&bytes, true).map_err(|e| crate::Error::AbiParseError(e.to_string()))?;
+
+    Ok((!name.is_empty()).then_some(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+
+    #[test]
+    fn test_triggers() {
+        let trigger = resolver_trigger(ADDRESS, "justin.trx").unwrap();
+        assert_eq!(trigger.function_selector, "resolver(bytes32)");
+        assert_eq!(trigger.parameter, hex::encode(ens::namehash("justin.trx")));
+        assert!(trigger.contract_address.starts_with("41"));
+
+        let reverse = reverse_name(ADDRESS).unwrap();
+        assert!(reverse.ends_with(".addr.reverse"));
+        assert_eq!(reverse.len(), 40 + ".addr.reverse".len());
+    }
+
+    #[test]
+    fn test_parse_address() {
+        let hex = wallet_utils::address::bs58_addr_to_hex(ADDRESS).unwrap();
+        let word = format!("{:0>64}", &hex[2..]);
+        assert_eq!(parse_address(&[word]).unwrap().as_deref(), Some(ADDRESS));
+
+        assert_eq!(parse_address(&["0".repeat(64)]).unwrap(), None);
+        assert_eq!(parse_address(&[]).unwrap(), None);
+    }
+
+    // abi encoded string return: offset, length, then the padded bytes
+    fn string_return(value: &str) -> String {
+        let mut data = format!("{:064x}{:064x}", 32, value.len());
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.resize(value.len().div_ceil(32) * 32, 0);
+        data.push_str(&hex::encode(bytes));
+        data
+    }
+
+    #[test]
+    fn test_parse_name() {
+        let data = string_return("justin.trx");
+        assert_eq!(parse_name(&[data]).unwrap().as_deref(), Some("justin.trx"));
+
+        assert_eq!(parse_name(&[string_return("")]).unwrap(), None);
+        assert_eq!(parse_name(&[]).unwrap(), None);
+    }
+}
@@ -0,0 +1,180 @@
+// trc10 资产: 元数据查询以及 TransferAssetContract 转账
+use super::{RawTransactionParams, TronTxOperation};
+use crate::tron::provider::Provider;
+use wallet_utils::{address, hex_func};
+
+// trc10 ids are numbers, trc20 tokens are contract addresses
+pub fn is_trc10_id(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Response of `getassetissuebyid`, text fields are hex encoded.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default)]
+#[serde(default)]
+pub struct AssetIssue {
+    pub id: String,
+    pub owner_address: String,
+    pub name: String,
+    pub abbr: String,
+    pub total_supply: i64,
+    pub precision: u8,
+    pub description: String,
+    pub url: String,
+}
+
+impl AssetIssue {
+    pub fn name(&self) -> crate::Result<String> {
+        Ok(hex_func::hex_to_utf8(&self.name)?)
+    }
+
+    // abbr is optional, fall back to the name
+    pub fn symbol(&self) -> crate::Result<String> {
+        if self.abbr.is_empty() {
+            return self.name();
+        }
+        Ok(hex_func::hex_to_utf8(&self.abbr)?)
+    }
+
+    pub fn description(&self) -> crate::Result<String> {
+        Ok(hex_func::hex_to_utf8(&self.description)?)
+    }
+
+    pub fn url(&self) -> crate::Result<String> {
+        Ok(hex_func::hex_to_utf8(&self.url)?)
+    }
+}
+
+/// trc10 transfer parameter
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+pub struct TransferAssetOpt {
+    pub from: String,
+    pub to: String,
+    pub asset_id: String,
+    // unit is the smallest unit of the asset
+    pub value: i64,
+    pub memo: Option<String>,
+    #[serde(rename = "Permission_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_id: Option<i64>,
+}
+
+impl TransferAssetOpt {
+    pub fn new(
+        from: &str,
+        to: &str,
+        asset_id: &str,
+        value: i64,
+        memo: Option<String>,
+    ) -> crate::Result<Self> {
+        if !is_trc10_id(asset_id) {
+            return Err(crate::Error::Other(format!(
+                "invalid trc10 asset id {}",
+                asset_id
+            )));
+        }
+
+        Ok(Self {
+            from: address::bs58_addr_to_hex(from)?,
+            to: address::bs58_addr_to_hex(to)?,
+            asset_id: asset_id.to_string(),
+            value,
+            memo: memo.map(|m| hex_func::utf8_to_hex(&m)),
+            permission_id: None,
+        })
+    }
+
+    pub fn with_permission(mut self, permission: i64) -> Self {
+        self.permission_id = Some(permission);
+        self
+    }
+
+    // the amount of the asset in its smallest unit, get_value only reports trx
+    pub fn asset_amount(&self) -> i64 {
+        self.value
+    }
+}
+
+/// TransferAssetContract in the tron transaction
+#[derive(serde::Deserialize, serde::Serialize, Debug)]
+pub struct TransferAssetResp {
+    pub amount: i64,
+    // hex of the asset id
+    pub asset_name: String,
+    pub owner_address: String,
+    pub to_address: String,
+    #[serde(rename = "Permission_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra_data: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl TronTxOperation<TransferAssetResp> for TransferAssetOpt {
+    async fn build_raw_transaction(
+        &self,
+        provider: &Provider,
+    ) -> crate::Result<RawTransactionParams> {
+        let params = TransferAssetResp {
+            amount: self.value,
+            asset_name: hex_func::utf8_to_hex(&self.asset_id),
+            owner_address: self.from.clone(),
+            to_address: self.to.clone(),
+            permission_id: self.permission_id,
+            extra_data: self.memo.clone(),
+        };
+
+        let res = provider.transfer_asset(params).await?;
+        Ok(RawTransactionParams::from(res))
+    }
+
+    // transfer_fee checks the receiver, a new account costs the activation fee
+    fn get_to(&self) -> String {
+        self.to.clone()
+    }
+
+    // no trx is transferred, the asset amount is asset_amount
+    fn get_value(&self) -> f64 {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_issue() {
+        assert!(is_trc10_id("1002000"));
+        assert!(!is_trc10_id("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"));
+
+        let json = r#"{"owner_address":"4137fa1a56eb8c503624701d776d95f6dae1e1f4c1","name":"426974546f7272656e74","abbr":"425454","total_supply":990000000000000000,"precision":6,"id":"1002000","url":"7777772e626974746f7272656e742e636f6d"}"#;
+        let asset: AssetIssue = serde_json::from_str(json).unwrap();
+        assert_eq!(asset.name().unwrap(), "BitTorrent");
+        assert_eq!(asset.symbol().unwrap(), "BTT");
+        assert_eq!(asset.url().unwrap(), "www.bittorrent.com");
+        assert_eq!(asset.precision, 6);
+
+        let opt = TransferAssetOpt::new(
+            "TXYZopYRdj2D9XRtbG411XZZ3kM5VkAeBf",
+            "TN3W4H6rK2ce4vX9YnFQHwKENnHjoxb3m9",
+            "1002000",
+            1_000_000,
+            None,
+        )
+        .unwrap();
+        assert!(opt.to.starts_with("41"));
+        assert_eq!(opt.get_value(), 0.0);
+        assert_eq!(opt.asset_amount(), 1_000_000);
+        assert!(
+            TransferAssetOpt::new(
+                &opt.from,
+                &opt.to,
+                "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t",
+                1,
+                None
+            )
+            .is_err()
+        );
+    }
+}
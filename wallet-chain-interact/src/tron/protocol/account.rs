@@ -24,6 +24,9 @@ pub struct TronAccount {
     pub owner_permission: Permission,
     pub active_permission: Vec<Permission>,
     pub votes: Vec<Vote>,
+    // trc10 balances
+    #[serde(rename = "assetV2")]
+    pub asset_v2: Vec<AssetV2>,
     #[serde(flatten)]
     #[serde(default)]
     extra_fields: std::collections::HashMap<String, serde_json::Value>,
@@ -127,6 +130,23 @@ impl TronAccount {
             .into_iter()
             .collect()
     }
+
+    // trc10 balance, unit is the smallest unit of the asset
+    pub fn asset_balance(&self, asset_id: &str) -> i64 {
+        self.asset_v2
+            .iter()
+            .find(|item| item.key == asset_id)
+            .map(|item| item.value)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+#[serde(default)]
+pub struct AssetV2 {
+    // asset id
+    pub key: String,
+    pub value: i64,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
@@ -54,6 +54,18 @@ impl ChainParameter {
             .unwrap_or(100_000_000)
     }
 
+    // block reward of the producing witness, unit is sun
+    pub fn witness_pay_per_block(&self) -> i64 {
+        self.get_value("getWitnessPayPerBlock")
+            .unwrap_or(16_000_000)
+    }
+
+    // vote reward shared by the top 127 witnesses every block, unit is sun
+    pub fn witness_127_pay_per_block(&self) -> i64 {
+        self.get_value("getWitness127PayPerBlock")
+            .unwrap_or(160_000_000)
+    }
+
     // multisig sign fee ,the unit is sun
     pub fn get_multi_sign_fee(&self) -> i64 {
         self.get_value("getMultiSignFee")
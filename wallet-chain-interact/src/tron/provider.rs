@@ -4,6 +4,7 @@ use super::{
         contract::{ConstantContract, TriggerContractParameter, TriggerContractResult},
         stake::{self, CanDelegatedMaxSize},
         transfer::{ContractTransferResp, TronTransferResp},
+        trc10::{AssetIssue, TransferAssetResp},
     },
     params::ResourceConsumer,
     protocol::{
@@ -147,6 +148,31 @@ impl Provider {
         Ok(res)
     }
 
+    pub async fn transfer_asset(
+        &self,
+        params: TransferAssetResp,
+    ) -> crate::Result<TronTransactionResponse<TransferAssetResp>> {
+        self.do_request::<_, _>("wallet/transferasset", Some(params))
+            .await
+    }
+
+    // trc10 metadata
+    pub async fn asset_issue_by_id(&self, asset_id: &str) -> crate::Result<AssetIssue> {
+        let params = json!({"value": asset_id});
+        let res = self
+            .do_request::<_, AssetIssue>("wallet/getassetissuebyid", Some(params))
+            .await?;
+
+        // unknown ids return an empty object
+        if res.id.is_empty() {
+            return Err(crate::Error::Other(format!(
+                "trc10 asset {} not found",
+                asset_id
+            )));
+        }
+        Ok(res)
+    }
+
     // get account info
     pub async fn account_info(&self, account: &str) -> crate::Result<TronAccount> {
         let mut params = HashMap::from([("address", account)]);
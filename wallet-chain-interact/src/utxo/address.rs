@@ -0,0 +1,300 @@
+use super::network::NetworkParams;
+use bitcoin::{
+    PrivateKey, PubkeyHash, ScriptBuf, ScriptHash, WitnessProgram, WitnessVersion, base58,
+    bech32::{self, Fe32},
+    hashes::Hash as _,
+    secp256k1::SecretKey,
+};
+use wallet_utils::cashaddr::{self, CashAddrType};
+
+/// Address of a utxo chain, decoded with the network parameters of the chain
+/// instead of the `bitcoin` address type which only knows the bitcoin prefixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoAddress {
+    address: String,
+    script_pubkey: ScriptBuf,
+}
+
+impl UtxoAddress {
+    pub fn parse(params: &NetworkParams, address: &str) -> crate::Result<Self> {
+        let lower = address.to_lowercase();
+        let script_pubkey = match (params.bech32_hrp, params.cashaddr_prefix) {
+            (Some(hrp), _) if lower.starts_with(&format!("{}1", hrp)) => {
+                Self::decode_segwit(params, hrp, address)?
+            }
+            // cashaddr with or without the prefix, the payload starts with q(p2pkh) or p(p2sh)
+            (_, Some(prefix)) if lower.contains(':') || lower.starts_with(['q', 'p']) => {
+                Self::decode_cashaddr(prefix, address)?
+            }
+            _ => Self::decode_base58(params, address)?,
+        };
+
+        Ok(Self {
+            address: address.to_string(),
+            script_pubkey,
+        })
+    }
+
+    pub fn script_pubkey(&self) -> ScriptBuf {
+        self.script_pubkey.clone()
+    }
+
+    // chains with cashaddr prefer the cashaddr format
+    pub fn from_p2pkh(params: &NetworkParams, hash: &PubkeyHash) -> Self {
+        let address = match params.cashaddr_prefix {
+            Some(prefix) => cashaddr::encode(prefix, CashAddrType::P2pkh, hash.as_byte_array())
+                .expect("hash160 is a valid cashaddr payload"),
+            None => {
+                let mut data = vec![params.pubkey_hash_prefix];
+                data.extend_from_slice(hash.as_byte_array());
+                base58::encode_check(&data)
+            }
+        };
+
+        Self {
+            address,
+            script_pubkey: ScriptBuf::new_p2pkh(hash),
+        }
+    }
+
+    pub fn from_p2sh(params: &NetworkParams, hash: &ScriptHash) -> Self {
+        let address = match params.cashaddr_prefix {
+            Some(prefix) => cashaddr::encode(prefix, CashAddrType::P2sh, hash.as_byte_array())
+                .expect("hash160 is a valid cashaddr payload"),
+            None => {
+                let mut data = vec![params.script_hash_prefix];
+                data.extend_from_slice(hash.as_byte_array());
+                base58::encode_check(&data)
+            }
+        };
+
+        Self {
+            address,
+            script_pubkey: ScriptBuf::new_p2sh(hash),
+        }
+    }
+
+    // base58 format of the address, cashaddr is converted with the legacy version bytes
+    pub fn to_legacy(&self, params: &NetworkParams) -> crate::Result<String> {
+        match params.cashaddr_prefix {
+            Some(prefix) if !self.is_base58() => Ok(cashaddr::to_legacy(
+                prefix,
+                &self.address,
+                params.pubkey_hash_prefix,
+                params.script_hash_prefix,
+            )?),
+            _ => Ok(self.address.clone()),
+        }
+    }
+
+    // cashaddr format of the address, legacy addresses are converted
+    pub fn to_cashaddr(&self, params: &NetworkParams) -> crate::Result<String> {
+        let prefix = params
+            .cashaddr_prefix
+            .ok_or(crate::ParseErr::AddressPraseErr(format!(
+                "{} not support cashaddr",
+                params.name
+            )))?;
+
+        if self.is_base58() {
+            Ok(cashaddr::from_legacy(
+                prefix,
+                &self.address,
+                params.pubkey_hash_prefix,
+                params.script_hash_prefix,
+            )?)
+        } else {
+            let (addr_type, hash) = cashaddr::decode(prefix, &self.address)?;
+            Ok(cashaddr::encode(prefix, addr_type, &hash)?)
+        }
+    }
+
+    fn is_base58(&self) -> bool {
+        base58::decode_check(&self.address).is_ok()
+    }
+
+    fn decode_cashaddr(prefix: &str, address: &str) -> crate::Result<ScriptBuf> {
+        let (addr_type, hash) = cashaddr::decode(prefix, address).map_err(|e| err(address, e))?;
+
+        match addr_type {
+            CashAddrType::P2pkh => {
+                let hash = PubkeyHash::from_slice(&hash).map_err(|e| err(address, e))?;
+                Ok(ScriptBuf::new_p2pkh(&hash))
+            }
+            CashAddrType::P2sh => {
+                let hash = ScriptHash::from_slice(&hash).map_err(|e| err(address, e))?;
+                Ok(ScriptBuf::new_p2sh(&hash))
+            }
+        }
+    }
+
+    fn decode_base58(params: &NetworkParams, address: &str) -> crate::Result<ScriptBuf> {
+        let data = base58::decode_check(address).map_err(|e| err(address, e))?;
+        if data.len() != 21 {
+            return Err(err(address, "invalid length"));
+        }
+
+        let prefix = data[0];
+        if prefix == params.pubkey_hash_prefix {
+            let hash = PubkeyHash::from_slice(&data[1..]).map_err(|e| err(address, e))?;
+            Ok(ScriptBuf::new_p2pkh(&hash))
+        } else if prefix == params.script_hash_prefix {
+            let hash = ScriptHash::from_slice(&data[1..]).map_err(|e| err(address, e))?;
+            Ok(ScriptBuf::new_p2sh(&hash))
+        } else {
+            Err(err(
+                address,
+                format!("address prefix {} not match {}", prefix, params.name),
+            ))
+        }
+    }
+
+    fn decode_segwit(params: &NetworkParams, hrp: &str, address: &str) -> crate::Result<ScriptBuf> {
+        if !params.segwit {
+            return Err(err(address, format!("{} not support segwit", params.name)));
+        }
+
+        let (decode_hrp, version, program) =
+            bech32::segwit::decode(address).map_err(|e| err(address, e))?;
+        if decode_hrp.to_lowercase() != hrp {
+            return Err(err(
+                address,
+                format!("hrp {} not match {}", decode_hrp, params.name),
+            ));
+        }
+
+        let version = WitnessVersion::try_from(version).map_err(|e| err(address, e))?;
+        if version != WitnessVersion::V0 && !params.taproot {
+            return Err(err(address, format!("{} not support taproot", params.name)));
+        }
+
+        let program = WitnessProgram::new(version, &program).map_err(|e| err(address, e))?;
+        Ok(ScriptBuf::new_witness_program(&program))
+    }
+}
+
+impl std::fmt::Display for UtxoAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+// encode segwit address with the hrp of the chain
+pub fn encode_segwit(
+    params: &NetworkParams,
+    version: WitnessVersion,
+    program: &[u8],
+) -> crate::Result<String> {
+    let hrp = params
+        .bech32_hrp
+        .ok_or(crate::ParseErr::AddressPraseErr(format!(
+            "{} not support segwit",
+            params.name
+        )))?;
+    let hrp = bech32::Hrp::parse(hrp).map_err(|e| err(hrp, e))?;
+
+    bech32::segwit::encode(hrp, Fe32::from(version), program)
+        .map_err(|e| crate::ParseErr::AddressPraseErr(e.to_string()).into())
+}
+
+// wif private key with the version byte of the chain
+pub fn private_key_from_wif(params: &NetworkParams, wif: &str) -> crate::Result<PrivateKey> {
+    let data = base58::decode_check(wif).map_err(|e| crate::Error::SignError(e.to_string()))?;
+
+    let compressed = match data.len() {
+        33 => false,
+        34 if data[33] == 1 => true,
+        _ => return Err(crate::Error::SignError("invalid wif length".to_string())),
+    };
+    if data[0] != params.wif_prefix {
+        return Err(crate::Error::SignError(format!(
+            "wif prefix {} not match {}",
+            data[0], params.name
+        )));
+    }
+
+    let inner =
+        SecretKey::from_slice(&data[1..33]).map_err(|e| crate::Error::SignError(e.to_string()))?;
+
+    Ok(PrivateKey {
+        compressed,
+        network: bitcoin::NetworkKind::Main,
+        inner,
+    })
+}
+
+pub fn private_key_to_wif(params: &NetworkParams, key: &PrivateKey) -> String {
+    let mut data = vec![params.wif_prefix];
+    data.extend_from_slice(&key.inner.secret_bytes());
+    if key.compressed {
+        data.push(1);
+    }
+    base58::encode_check(&data)
+}
+
+fn err(address: &str, e: impl std::fmt::Display) -> crate::Error {
+    crate::ParseErr::AddressPraseErr(format!("err:{} address:{}", e, address)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utxo::network::{BCH_MAINNET, BTC_MAINNET, DOGE_MAINNET, LTC_MAINNET};
+
+    #[test]
+    fn test_parse_with_params() {
+        let hash = PubkeyHash::from_byte_array([7; 20]);
+
+        for params in [&BTC_MAINNET, &LTC_MAINNET, &DOGE_MAINNET] {
+            let address = UtxoAddress::from_p2pkh(params, &hash);
+            let parsed = UtxoAddress::parse(params, &address.to_string()).unwrap();
+            assert_eq!(parsed.script_pubkey(), ScriptBuf::new_p2pkh(&hash));
+        }
+
+        // bitcoin address is rejected by litecoin
+        let btc = UtxoAddress::from_p2pkh(&BTC_MAINNET, &hash).to_string();
+        assert!(UtxoAddress::parse(&LTC_MAINNET, &btc).is_err());
+    }
+
+    #[test]
+    fn test_segwit_address() {
+        let ltc = encode_segwit(&LTC_MAINNET, WitnessVersion::V0, &[7; 20]).unwrap();
+        assert!(ltc.starts_with("ltc1"));
+        assert!(UtxoAddress::parse(&LTC_MAINNET, &ltc).is_ok());
+
+        // dogecoin has no segwit
+        assert!(encode_segwit(&DOGE_MAINNET, WitnessVersion::V0, &[7; 20]).is_err());
+    }
+
+    #[test]
+    fn test_cashaddr() {
+        let cash = "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a";
+        let legacy = "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu";
+
+        let from_cash = UtxoAddress::parse(&BCH_MAINNET, cash).unwrap();
+        let from_legacy = UtxoAddress::parse(&BCH_MAINNET, legacy).unwrap();
+        assert_eq!(from_cash.script_pubkey(), from_legacy.script_pubkey());
+
+        // the prefix can be omitted
+        let no_prefix = UtxoAddress::parse(&BCH_MAINNET, &cash[12..]).unwrap();
+        assert_eq!(no_prefix.script_pubkey(), from_cash.script_pubkey());
+
+        assert_eq!(from_cash.to_legacy(&BCH_MAINNET).unwrap(), legacy);
+        assert_eq!(from_legacy.to_cashaddr(&BCH_MAINNET).unwrap(), cash);
+        assert!(UtxoAddress::parse(&BTC_MAINNET, cash).is_err());
+    }
+
+    #[test]
+    fn test_wif() {
+        let key = PrivateKey {
+            compressed: true,
+            network: bitcoin::NetworkKind::Main,
+            inner: SecretKey::from_slice(&[1; 32]).unwrap(),
+        };
+
+        let wif = private_key_to_wif(&LTC_MAINNET, &key);
+        let decoded = private_key_from_wif(&LTC_MAINNET, &wif).unwrap();
+        assert_eq!(decoded.inner, key.inner);
+        assert!(private_key_from_wif(&DOGE_MAINNET, &wif).is_err());
+    }
+}
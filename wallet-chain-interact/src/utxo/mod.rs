@@ -0,0 +1,13 @@
+//! Engine shared by the bitcoin-like utxo chains.
+//! A chain is added by describing it with a set of `NetworkParams`.
+pub mod address;
+pub mod chain;
+pub use chain::*;
+pub mod network;
+pub use network::{FeeUnit, NetworkParams};
+pub mod params;
+pub mod protocol;
+pub mod provider;
+pub mod signature;
+pub mod transfer;
+pub mod utxos;
@@ -0,0 +1,231 @@
+use wallet_types::chain::network::NetworkKind;
+
+/// How the fee rate of a chain is expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeUnit {
+    // segwit chains, fee is charged on the virtual size
+    SatPerVbyte,
+    // legacy only chains, fee is charged on the serialized size
+    SatPerByte,
+}
+
+/// Network parameters of a bitcoin-like utxo chain.
+/// Every chain powered by the utxo engine is described by one of these.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkParams {
+    pub name: &'static str,
+    pub symbol: &'static str,
+    pub decimals: u8,
+    // base58 version byte of p2pkh addresses
+    pub pubkey_hash_prefix: u8,
+    // base58 version byte of p2sh addresses
+    pub script_hash_prefix: u8,
+    // version byte of wif private keys
+    pub wif_prefix: u8,
+    // human readable part of segwit addresses, none when segwit is not supported
+    pub bech32_hrp: Option<&'static str>,
+    // prefix of cashaddr addresses, only bitcoin cash uses it
+    pub cashaddr_prefix: Option<&'static str>,
+    pub segwit: bool,
+    pub taproot: bool,
+    // outputs below this value are not relayed, unit is sat
+    pub dust_limit: u64,
+    pub fee_unit: FeeUnit,
+    // sign with SIGHASH_FORKID and the bip143 digest for every input (bitcoin cash replay protection)
+    pub sighash_forkid: bool,
+    // 节点是否支持 estimatesmartfee,不支持时从区块浏览器获取
+    pub smart_fee: bool,
+    // 手续费最小费率,0 表示使用节点的预估值
+    pub min_fee_rate: u64,
+    // 手续费最大费率
+    pub max_fee_rate: u64,
+    // 手续费放大的值
+    pub expend_fee_rate: u64,
+    // 预估手续费的区块数
+    pub fee_blocks: u32,
+    // 固定的费率(coin/kB),节点没有手续费历史时使用,例如 regtest
+    pub fixed_fee_rate: Option<f64>,
+}
+
+impl NetworkParams {
+    pub fn unit(&self) -> f64 {
+        10_u64.pow(self.decimals as u32) as f64
+    }
+
+    pub fn bitcoin(network: NetworkKind) -> &'static NetworkParams {
+        match network {
+            NetworkKind::Mainnet => &BTC_MAINNET,
+            NetworkKind::Testnet => &BTC_TESTNET,
+            NetworkKind::Regtest => &BTC_REGTEST,
+        }
+    }
+
+    pub fn litecoin(network: NetworkKind) -> &'static NetworkParams {
+        match network {
+            NetworkKind::Mainnet => &LTC_MAINNET,
+            NetworkKind::Testnet => &LTC_TESTNET,
+            NetworkKind::Regtest => &LTC_REGTEST,
+        }
+    }
+
+    pub fn bitcoin_cash(network: NetworkKind) -> &'static NetworkParams {
+        match network {
+            NetworkKind::Mainnet => &BCH_MAINNET,
+            NetworkKind::Testnet => &BCH_TESTNET,
+            NetworkKind::Regtest => &BCH_REGTEST,
+        }
+    }
+
+    pub fn dogecoin(network: NetworkKind) -> &'static NetworkParams {
+        match network {
+            NetworkKind::Mainnet => &DOGE_MAINNET,
+            NetworkKind::Testnet => &DOGE_TESTNET,
+            NetworkKind::Regtest => &DOGE_REGTEST,
+        }
+    }
+}
+
+pub const BTC_MAINNET: NetworkParams = NetworkParams {
+    name: "bitcoin",
+    symbol: "BTC",
+    decimals: 8,
+    pubkey_hash_prefix: 0x00,
+    script_hash_prefix: 0x05,
+    wif_prefix: 0x80,
+    bech32_hrp: Some("bc"),
+    cashaddr_prefix: None,
+    segwit: true,
+    taproot: true,
+    dust_limit: 546,
+    fee_unit: FeeUnit::SatPerVbyte,
+    sighash_forkid: false,
+    smart_fee: true,
+    min_fee_rate: 0,
+    max_fee_rate: 200,
+    expend_fee_rate: 2,
+    fee_blocks: 1,
+    fixed_fee_rate: None,
+};
+
+pub const BTC_TESTNET: NetworkParams = NetworkParams {
+    pubkey_hash_prefix: 0x6f,
+    script_hash_prefix: 0xc4,
+    wif_prefix: 0xef,
+    bech32_hrp: Some("tb"),
+    ..BTC_MAINNET
+};
+
+pub const BTC_REGTEST: NetworkParams = NetworkParams {
+    bech32_hrp: Some("bcrt"),
+    // 本地回归测试网络写死
+    fixed_fee_rate: Some(0.000048779),
+    ..BTC_TESTNET
+};
+
+pub const LTC_MAINNET: NetworkParams = NetworkParams {
+    name: "litecoin",
+    symbol: "LTC",
+    decimals: 8,
+    pubkey_hash_prefix: 0x30,
+    script_hash_prefix: 0x32,
+    wif_prefix: 0xb0,
+    bech32_hrp: Some("ltc"),
+    cashaddr_prefix: None,
+    segwit: true,
+    taproot: true,
+    dust_limit: 5460,
+    fee_unit: FeeUnit::SatPerVbyte,
+    sighash_forkid: false,
+    smart_fee: true,
+    min_fee_rate: 0,
+    max_fee_rate: 200,
+    expend_fee_rate: 2,
+    fee_blocks: 1,
+    fixed_fee_rate: None,
+};
+
+pub const LTC_TESTNET: NetworkParams = NetworkParams {
+    pubkey_hash_prefix: 0x6f,
+    script_hash_prefix: 0x3a,
+    wif_prefix: 0xef,
+    bech32_hrp: Some("tltc"),
+    ..LTC_MAINNET
+};
+
+pub const LTC_REGTEST: NetworkParams = NetworkParams {
+    bech32_hrp: Some("rltc"),
+    ..LTC_TESTNET
+};
+
+pub const DOGE_MAINNET: NetworkParams = NetworkParams {
+    name: "dogecoin",
+    symbol: "DOGE",
+    decimals: 8,
+    pubkey_hash_prefix: 0x1e,
+    script_hash_prefix: 0x16,
+    wif_prefix: 0x9e,
+    bech32_hrp: None,
+    cashaddr_prefix: None,
+    segwit: false,
+    taproot: false,
+    // 0.01 doge
+    dust_limit: 1_000_000,
+    fee_unit: FeeUnit::SatPerByte,
+    sighash_forkid: false,
+    smart_fee: true,
+    min_fee_rate: 0,
+    max_fee_rate: 200_000_000,
+    expend_fee_rate: 2,
+    fee_blocks: 1,
+    fixed_fee_rate: None,
+};
+
+pub const DOGE_TESTNET: NetworkParams = NetworkParams {
+    pubkey_hash_prefix: 0x71,
+    script_hash_prefix: 0xc4,
+    wif_prefix: 0xf1,
+    ..DOGE_MAINNET
+};
+
+pub const DOGE_REGTEST: NetworkParams = NetworkParams {
+    pubkey_hash_prefix: 0x6f,
+    script_hash_prefix: 0xc4,
+    wif_prefix: 0xef,
+    ..DOGE_MAINNET
+};
+
+pub const BCH_MAINNET: NetworkParams = NetworkParams {
+    name: "bitcoincash",
+    symbol: "BCH",
+    decimals: 8,
+    pubkey_hash_prefix: 0x00,
+    script_hash_prefix: 0x05,
+    wif_prefix: 0x80,
+    bech32_hrp: None,
+    cashaddr_prefix: Some("bitcoincash"),
+    segwit: false,
+    taproot: false,
+    dust_limit: 546,
+    fee_unit: FeeUnit::SatPerByte,
+    sighash_forkid: true,
+    smart_fee: false,
+    // 节点默认的最小转发费率 1 sat/byte
+    min_fee_rate: 1,
+    max_fee_rate: 100,
+    expend_fee_rate: 1,
+    fee_blocks: 2,
+    fixed_fee_rate: None,
+};
+
+pub const BCH_TESTNET: NetworkParams = NetworkParams {
+    pubkey_hash_prefix: 0x6f,
+    script_hash_prefix: 0xc4,
+    wif_prefix: 0xef,
+    cashaddr_prefix: Some("bchtest"),
+    ..BCH_MAINNET
+};
+
+pub const BCH_REGTEST: NetworkParams = NetworkParams {
+    cashaddr_prefix: Some("bchreg"),
+    ..BCH_TESTNET
+};
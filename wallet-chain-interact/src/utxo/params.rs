@@ -4,14 +4,14 @@ pub struct FeeSetting {
     pub size: usize,
 }
 impl FeeSetting {
-    // unit is btc
+    // unit is the coin
     pub fn transaction_fee(&self) -> String {
         let res = self.fee_rate * self.size as u64;
         let rs = res.to_float_in(bitcoin::Denomination::Bitcoin);
         rs.to_string()
     }
 
-    // unit is btc f64
+    // unit is the coin f64
     pub fn transaction_fee_f64(&self) -> f64 {
         let res = self.fee_rate * self.size as u64;
         res.to_float_in(bitcoin::Denomination::Bitcoin)
@@ -1,4 +1,4 @@
-use crate::btc::utxos::Utxo;
+use crate::utxo::utxos::Utxo;
 use bitcoin::{Amount, ScriptBuf, TxOut};
 use serde::Deserialize;
 
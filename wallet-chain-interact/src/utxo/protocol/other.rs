@@ -4,3 +4,9 @@ pub struct FeeRate {
     pub fee_rate: f64,
     pub blocks: u64,
 }
+
+// blockbook estimatefee, coin per kB
+#[derive(serde::Deserialize, Debug)]
+pub struct ApiFeeRate {
+    pub result: String,
+}
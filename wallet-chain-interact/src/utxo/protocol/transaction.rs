@@ -1,7 +1,7 @@
-use crate::dog::{consts::DOG_DECIMAL, utxos::Utxo};
+use crate::utxo::{network::NetworkParams, utxos::Utxo};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use wallet_types::chain::address::r#type::DogAddressType;
+use wallet_types::chain::address::r#type::BtcAddressType;
 use wallet_utils::unit;
 
 #[derive(Deserialize, Debug)]
@@ -218,7 +218,7 @@ pub struct Mweb {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DogJsonRpcReq {
+pub struct UtxoJsonRpcReq {
     pub jsonrpc: String,
     pub id: String,
     pub method: String,
@@ -227,7 +227,7 @@ pub struct DogJsonRpcReq {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DogJsonRpcRes {
+pub struct UtxoJsonRpcRes {
     pub result: serde_json::Value,
     pub error: Option<ApiError>,
     pub id: String,
@@ -293,14 +293,14 @@ impl ApiTransaction {
             .collect()
     }
 
-    pub fn get_fees(&self) -> crate::Result<f64> {
+    pub fn get_fees(&self, decimals: u8) -> crate::Result<f64> {
         let res = unit::u256_from_str(&self.fees)?;
-        Ok(unit::format_to_f64(res, DOG_DECIMAL)?)
+        Ok(unit::format_to_f64(res, decimals)?)
     }
 
-    pub fn get_value(&self) -> crate::Result<f64> {
+    pub fn get_value(&self, decimals: u8) -> crate::Result<f64> {
         let res = unit::u256_from_str(&self.value)?;
-        Ok(unit::format_to_f64(res, DOG_DECIMAL)?)
+        Ok(unit::format_to_f64(res, decimals)?)
     }
 }
 
@@ -325,23 +325,25 @@ pub struct ApiVout {
 }
 
 impl ValidateAddress {
-    pub fn address_type(&self) -> Option<DogAddressType> {
+    pub fn address_type(&self, params: &NetworkParams) -> Option<BtcAddressType> {
         if self.isscript.is_some() && !self.isscript.unwrap() {
-            return Some(DogAddressType::P2pkh);
-        } else if self.iswitness.is_some()
-            && self.witness_version.is_some()
-            && self.iswitness.unwrap()
-            && self.witness_version.unwrap() == 1
-        {
+            return Some(BtcAddressType::P2pkh);
+        } else if !params.segwit {
             return None;
         } else if self.isscript.is_some() && self.isscript.unwrap() {
-            return None;
+            return Some(BtcAddressType::P2shWpkh);
         } else if self.iswitness.is_some()
             && self.iswitness.unwrap()
             && self.witness_version.is_some()
             && self.witness_version.unwrap() == 0
         {
-            return None;
+            return Some(BtcAddressType::P2wpkh);
+        } else if self.iswitness.is_some()
+            && self.witness_version.is_some()
+            && self.iswitness.unwrap()
+            && self.witness_version.unwrap() == 1
+        {
+            return Some(BtcAddressType::P2tr);
         } else {
             return None;
         }
@@ -1,8 +1,8 @@
 use super::{
-    consts::{EXPEND_FEE_RATE, MAX_FEE_RATE},
+    network::NetworkParams,
     protocol::{
-        BlockHeader, OutInfo,
-        other::FeeRate,
+        BlockHeader, OutInfo, ScanOut,
+        other::{ApiFeeRate, FeeRate},
         transaction::{ApiBlock, ApiTransaction, JsonRpcTx, TransactionUtxo, ValidateAddress},
     },
     utxos::{Utxo, UtxoList},
@@ -30,12 +30,14 @@ pub struct RpcAuth {
 pub struct Provider {
     client: RpcClient,
     http_client: HttpClient,
+    params: &'static NetworkParams,
 }
 
 pub const API_ENPOINT: &'static str = "book/api/v2";
 
 impl Provider {
     pub fn new(
+        params: &'static NetworkParams,
         config: ProviderConfig,
         header_opt: Option<HashMap<String, String>>,
         timeout: Option<std::time::Duration>,
@@ -49,7 +51,7 @@ impl Provider {
         let mut header_map_api = header_opt.unwrap_or_else(HashMap::new);
         if let Some(api_key) = config.http_api_key {
             header_map_api.insert("api-key".to_owned(), api_key);
-        };
+        }
 
         let header_map_api = (!header_map_api.is_empty()).then_some(header_map_api);
         let http_client = HttpClient::new(&config.http_url, header_map_api, timeout)?;
@@ -57,29 +59,53 @@ impl Provider {
         Ok(Self {
             client,
             http_client,
+            params,
         })
     }
 
     pub async fn utxos(
         &self,
         address: &str,
-        _network: wallet_types::chain::network::NetworkKind,
+        network: wallet_types::chain::network::NetworkKind,
     ) -> crate::Result<UtxoList> {
-        let mut utxo = self.get_uxto_from_api(address).await?;
+        let mut utxo = match network {
+            // 本地回归测试网络没有区块浏览器,从节点扫描
+            wallet_types::chain::network::NetworkKind::Regtest => {
+                self.scan_utxo_from_rpc(address).await?
+            }
+            _ => self.get_uxto_from_api(address).await?,
+        };
 
         utxo.sort_by(|a, b| a.value.cmp(&b.value));
 
         Ok(UtxoList(utxo))
     }
 
-    pub async fn fetch_fee_rate(&self, blocks: u32) -> crate::Result<dogcoin::Amount> {
+    pub async fn scan_utxo_from_rpc(&self, address: &str) -> crate::Result<Vec<Utxo>> {
+        let params = JsonRpcParams::default().method("scantxoutset").params(vec![
+            json!("start"),
+            json!([{ "desc": format!("addr({address})") }]),
+        ]);
+
+        let result = self.client.invoke_request::<_, ScanOut>(params).await?;
+        Ok(result.unspents.iter().map(Utxo::from).collect())
+    }
+
+    pub fn params(&self) -> &'static NetworkParams {
+        self.params
+    }
+
+    // the node returns coin/kB, convert to sat per byte
+    pub async fn fetch_fee_rate(&self, blocks: u32) -> crate::Result<bitcoin::Amount> {
         let res = self.estimate_fee(blocks as u64).await?;
 
-        let fee_rate = bitcoin::Amount::from_sat((res.fee_rate * 100_000.0).round() as u64);
+        let sat_per_byte = res.fee_rate * self.params.unit() / 1000.0;
+        let fee_rate =
+            bitcoin::Amount::from_sat((sat_per_byte.round() as u64).max(self.params.min_fee_rate));
 
         // 扩大推荐费用,加快打包
-        let fee_rate = fee_rate * EXPEND_FEE_RATE;
-        let max_fee_rate = dogcoin::Amount::from_sat(MAX_FEE_RATE);
+        let fee_rate = fee_rate * self.params.expend_fee_rate;
+        let max_fee_rate = bitcoin::Amount::from_sat(self.params.max_fee_rate);
         if fee_rate > max_fee_rate {
             return Err(crate::UtxoError::ExceedsMaxFeeRate.into());
         }
@@ -148,10 +174,19 @@ impl Provider {
         let res = self.http_client.get_request::<ApiBlock>(&url).await?;
         Ok(res)
     }
+    // only confirmed utxos, unconfirmed outputs may never confirm or be replaced
     pub async fn get_uxto_from_api(&self, addr: &str) -> crate::Result<Vec<Utxo>> {
         let url = format!("{}/utxo/{}", API_ENPOINT, addr);
 
-        Ok(self.http_client.get_request::<Vec<Utxo>>(&url).await?)
+        let mut params = HashMap::new();
+        params.insert("confirmed", "true");
+
+        Ok(self
+            .http_client
+            .get(&url)
+            .query(params)
+            .send::<Vec<Utxo>>()
+            .await?)
     }
 
     pub async fn get_transaction_from_json_rpc(&self, hash: &str) -> crate::Result<JsonRpcTx> {
@@ -164,6 +199,13 @@ impl Provider {
 
     // 获取原始的费率
     pub async fn estimate_fee(&self, blocks: u64) -> crate::Result<FeeRate> {
+        if let Some(fee_rate) = self.params.fixed_fee_rate {
+            return Ok(FeeRate { fee_rate, blocks });
+        }
+        if !self.params.smart_fee {
+            return self.estimate_fee_from_api(blocks).await;
+        }
+
         let params = JsonRpcParams::default()
             .method("estimatesmartfee")
             .params(vec![blocks]);
@@ -171,6 +213,17 @@ impl Provider {
         Ok(self.client.invoke_request::<_, FeeRate>(params).await?)
     }
 
+    // blockbook estimatefee, used by the nodes without estimatesmartfee
+    pub async fn estimate_fee_from_api(&self, blocks: u64) -> crate::Result<FeeRate> {
+        let url = format!("{}/estimatefee/{}", API_ENPOINT, blocks);
+        let res = self.http_client.get_request::<ApiFeeRate>(&url).await?;
+
+        Ok(FeeRate {
+            fee_rate: wallet_utils::unit::string_to_f64(&res.result)?,
+            blocks,
+        })
+    }
+
     pub async fn validate_address_from_json_rpc(
         &self,
         addr: &str,
@@ -1,8 +1,15 @@
-use super::{provider::Provider, utxos::Usedutxo};
-use crate::{Error, script::BtcScript};
+use super::{
+    address,
+    network::{FeeUnit, NetworkParams},
+    provider::Provider,
+    utxos::Usedutxo,
+};
 use bitcoin::{
     Amount, CompressedPublicKey, EcdsaSighashType, PrivateKey, ScriptBuf, TapSighashType,
-    Transaction, TxOut, Witness, ecdsa,
+    Transaction, TxOut, Witness,
+    consensus::Encodable as _,
+    ecdsa,
+    hashes::{Hash as _, sha256d},
     key::{Keypair, Secp256k1, TapTweak, TweakedKeypair},
     opcodes::OP_0,
     script::{self, PushBytes},
@@ -14,18 +21,50 @@ use std::str::FromStr as _;
 use wallet_types::chain::address::r#type::BtcAddressType;
 use wallet_utils::hex_func;
 
-pub struct BtcSignature {
+// 用于模拟多签交易签名的参数
+#[derive(Debug)]
+pub struct MultisigSignParams {
+    pub threlod: i8,
+    pub memember: i8,
+    // 脚本的16进制字符串
+    pub redeem_script: String,
+    pub inner_key: String,
+}
+
+impl MultisigSignParams {
+    pub fn new(threlod: i8, memember: i8, redeem_script: String) -> Self {
+        Self {
+            threlod,
+            memember,
+            redeem_script,
+            inner_key: String::new(),
+        }
+    }
+
+    pub fn with_inner_key(mut self, inner_key: String) -> Self {
+        self.inner_key = inner_key;
+        self
+    }
+
+    pub fn to_script(&self) -> crate::Result<ScriptBuf> {
+        let redeem_script = ScriptBuf::from_hex(&self.redeem_script)
+            .map_err(|e| crate::Error::BtcScript(e.to_string()))?;
+
+        Ok(redeem_script)
+    }
+}
+
+pub struct UtxoSignature {
     secp: Secp256k1<All>,
     used_utxo: Usedutxo,
     private_key: PrivateKey,
 }
 
-impl BtcSignature {
-    pub fn new(key_str: &str, used_utxo: Usedutxo) -> crate::Result<Self> {
+impl UtxoSignature {
+    pub fn new(params: &NetworkParams, key_str: &str, used_utxo: Usedutxo) -> crate::Result<Self> {
         let secp = Secp256k1::new();
 
-        let private_key = bitcoin::PrivateKey::from_wif(key_str)
-            .map_err(|e| crate::Error::SignError(e.to_string()))?;
+        let private_key = address::private_key_from_wif(params, key_str)?;
 
         Ok(Self {
             secp,
@@ -40,7 +79,11 @@ impl BtcSignature {
         provider: &Provider,
         transaction: &mut Transaction,
     ) -> crate::Result<()> {
+        let params = provider.params();
+        check_address_type(params, address_type)?;
+
         match address_type {
+            BtcAddressType::P2pkh if params.sighash_forkid => self.p2pkh_forkid(transaction)?,
             BtcAddressType::P2pkh => self.p2pkh(transaction)?,
             BtcAddressType::P2wpkh => self.p2wpkh(transaction)?,
             BtcAddressType::P2shWpkh => self.p2sh_wpkh(transaction)?,
@@ -73,7 +116,27 @@ impl BtcSignature {
                 sighash_type: EcdsaSighashType::All,
             };
 
-            tx.input[i].script_sig = BtcScript::sign_script_sig(signature, pk);
+            tx.input[i].script_sig = sign_script_sig(signature, pk);
+        }
+        Ok(())
+    }
+
+    // bitcoin cash p2pkh, every input commits to its amount with the bip143 digest
+    pub fn p2pkh_forkid(&self, tx: &mut Transaction) -> crate::Result<()> {
+        let sk = self.private_key.inner;
+
+        let pk = self.private_key.public_key(&self.secp);
+        let script = ScriptBuf::new_p2pkh(&pk.pubkey_hash());
+
+        for i in 0..tx.input.len() {
+            let previous = &tx.input[i].previous_output;
+            let amount = self.get_amount(previous.txid, previous.vout)?;
+
+            let sighash = forkid_signature_hash(tx, i, &script, amount, SIGHASH_ALL_FORKID)?;
+            let msg = Message::from_digest(sighash);
+            let signature = self.secp.sign_ecdsa(&msg, &sk);
+
+            tx.input[i].script_sig = forkid_script_sig(&signature, SIGHASH_ALL_FORKID, &pk);
         }
         Ok(())
     }
@@ -155,18 +218,30 @@ impl BtcSignature {
         Ok(())
     }
 
-    pub async fn multisig_sign_v1(
+    // 多签的签名,每个输入一个签名,由 SignatureCombiner 组装
+    pub async fn multisig_sign(
         &self,
         address_type: BtcAddressType,
         script: ScriptBuf,
         tx: Transaction,
         provider: &Provider,
     ) -> crate::Result<Vec<Vec<u8>>> {
+        let params = provider.params();
+        check_address_type(params, address_type)?;
+        if params.sighash_forkid {
+            return Err(crate::Error::SignError(format!(
+                "{} not support multisig sign",
+                params.name
+            )));
+        }
+
         match address_type {
             BtcAddressType::P2sh => self.p2sh(&tx, script),
             BtcAddressType::P2wsh | BtcAddressType::P2shWsh => self.p2wsh(&tx, script),
             BtcAddressType::P2trSh => self.p2tr_sh(&tx, script, provider).await,
-            _ => panic!("sign not support multisig address"),
+            _ => Err(crate::Error::SignError(format!(
+                "multisig sign not support address type {address_type:?}",
+            ))),
         }
     }
 
@@ -203,12 +278,12 @@ impl BtcSignature {
         let mut sig = vec![];
         for i in 0..tx.input.len() {
             let previous = &tx.input[i].previous_output;
-            let amount = self.get_amount(previous.txid, previous.vout).unwrap();
+            let amount = self.get_amount(previous.txid, previous.vout)?;
 
             let sighash = SighashCache::new(tx)
                 .p2wsh_signature_hash(i, &script, amount, sighash_type)
                 .map_err(|e| {
-                    crate::Error::SignError(format!("p2sh failed to compute sighash{e:}"))
+                    crate::Error::SignError(format!("p2wsh failed to compute sighash{e:}"))
                 })?;
 
             let msg = secp256k1::Message::from(sighash);
@@ -286,7 +361,7 @@ impl BtcSignature {
             let tx_id = tx.input[i].previous_output.txid;
             let index = tx.input[i].previous_output.vout;
             let out = provider.utxo_out(&tx_id.to_string(), index).await?;
-            let tx_out = TxOut::try_from(out).unwrap();
+            let tx_out = TxOut::try_from(out)?;
             prevouts.push(tx_out);
         }
         let prevouts = Prevouts::All(&prevouts);
@@ -319,39 +394,6 @@ impl BtcSignature {
     }
 }
 
-// 用于模拟多签交易签名的参数
-#[derive(Debug)]
-pub struct MultisigSignParams {
-    pub threlod: i8,
-    pub memember: i8,
-    // 脚本的16进制字符串
-    pub redeem_script: String,
-    pub inner_key: String,
-}
-
-impl MultisigSignParams {
-    pub fn new(threlod: i8, memember: i8, redeem_script: String) -> Self {
-        Self {
-            threlod,
-            memember,
-            redeem_script,
-            inner_key: String::new(),
-        }
-    }
-
-    pub fn with_inner_key(mut self, inner_key: String) -> Self {
-        self.inner_key = inner_key;
-        self
-    }
-
-    pub fn to_script(&self) -> crate::Result<ScriptBuf> {
-        let redeem_script = ScriptBuf::from_hex(&self.redeem_script)
-            .map_err(|e| crate::Error::BtcScript(e.to_string()))?;
-
-        Ok(redeem_script)
-    }
-}
-
 pub struct SignatureCombiner {
     pub signatures: Vec<String>,
     pub redeem_script: ScriptBuf,
@@ -376,15 +418,14 @@ impl SignatureCombiner {
 
                 let sign_bytes = res[i].as_slice();
                 let push_bytes: &PushBytes = sign_bytes.try_into().map_err(|e| {
-                    Error::SignError(format!("p2sh sign bytes to push_bytes err: {e}"))
+                    crate::Error::SignError(format!("p2sh sign bytes to push_bytes err: {e}"))
                 })?;
                 buf.push_slice(push_bytes);
             }
 
-            let a: &PushBytes =
-                self.redeem_script.as_bytes().try_into().map_err(|e| {
-                    Error::SignError(format!("p2sh sign bytes to push_bytes err: {e}"))
-                })?;
+            let a: &PushBytes = self.redeem_script.as_bytes().try_into().map_err(|e| {
+                crate::Error::SignError(format!("p2sh sign bytes to push_bytes err: {e}"))
+            })?;
             buf.push_slice(a);
             transaction.input[i].script_sig = buf;
         }
@@ -400,10 +441,9 @@ impl SignatureCombiner {
                 .push_slice(self.redeem_script.wscript_hash())
                 .into_script();
             let mut script_sig = ScriptBuf::new();
-            let push_bytes: &PushBytes = builder
-                .as_bytes()
-                .try_into()
-                .map_err(|e| Error::SignError(format!("p2sh sign bytes to push_bytes err: {e}")))?;
+            let push_bytes: &PushBytes = builder.as_bytes().try_into().map_err(|e| {
+                crate::Error::SignError(format!("p2sh sign bytes to push_bytes err: {e}"))
+            })?;
             script_sig.push_slice(push_bytes);
 
             let mut witness = Witness::new();
@@ -448,14 +488,20 @@ impl SignatureCombiner {
 
         for i in 0..len {
             let secp = Secp256k1::new();
-            let internal_key = bitcoin::XOnlyPublicKey::from_str(inner_key).unwrap();
+            let internal_key = bitcoin::XOnlyPublicKey::from_str(inner_key)
+                .map_err(|e| crate::Error::SignError(format!("p2tr-sh inner key err: {e}")))?;
 
             let taproot_builder =
-                TaprootBuilder::with_huffman_tree(vec![(1, self.redeem_script.clone())]).unwrap();
-            let taproot_data = taproot_builder.finalize(&secp, internal_key).unwrap();
+                TaprootBuilder::with_huffman_tree(vec![(1, self.redeem_script.clone())])
+                    .map_err(|e| crate::Error::SignError(e.to_string()))?;
+            let taproot_data = taproot_builder
+                .finalize(&secp, internal_key)
+                .map_err(|e| crate::Error::SignError(format!("{e:?}")))?;
             let control_block = taproot_data
                 .control_block(&(self.redeem_script.clone(), LeafVersion::TapScript))
-                .unwrap();
+                .ok_or(crate::Error::SignError(
+                    "p2tr-sh control block not found".to_string(),
+                ))?;
 
             let mut witness = Witness::new();
             for sign in self.signatures.iter() {
@@ -475,19 +521,107 @@ impl SignatureCombiner {
     }
 }
 
+fn check_address_type(params: &NetworkParams, address_type: BtcAddressType) -> crate::Result<()> {
+    let segwit = !matches!(address_type, BtcAddressType::P2pkh | BtcAddressType::P2sh);
+    let taproot = matches!(address_type, BtcAddressType::P2tr | BtcAddressType::P2trSh);
+    if (segwit && !params.segwit) || (taproot && !params.taproot) {
+        return Err(crate::Error::SignError(format!(
+            "{} not support address type {address_type:?}",
+            params.name
+        )));
+    }
+    Ok(())
+}
+
+pub fn sign_script_sig(signature: ecdsa::Signature, pk: bitcoin::PublicKey) -> ScriptBuf {
+    let signature_bytes = signature.to_vec();
+
+    let bytes: &PushBytes = signature_bytes.as_slice().try_into().unwrap();
+    script::Builder::new()
+        .push_slice(bytes)
+        .push_key(&pk)
+        .into_script()
+}
+
+// SIGHASH_ALL | SIGHASH_FORKID with fork id 0
+pub const SIGHASH_ALL_FORKID: u32 = 0x41;
+
+/// Bip143 signature digest used by SIGHASH_FORKID,
+/// the same serialization as segwit v0 but applied to legacy scripts.
+pub fn forkid_signature_hash(
+    tx: &Transaction,
+    input_index: usize,
+    script_code: &bitcoin::Script,
+    value: Amount,
+    sighash_type: u32,
+) -> crate::Result<[u8; 32]> {
+    let input = tx
+        .input
+        .get(input_index)
+        .ok_or(crate::Error::SignError(format!(
+            "input index {input_index} out of range"
+        )))?;
+
+    let encode = || -> Result<Vec<u8>, bitcoin::io::Error> {
+        let mut prevouts = vec![];
+        let mut sequences = vec![];
+        for txin in tx.input.iter() {
+            txin.previous_output.consensus_encode(&mut prevouts)?;
+            txin.sequence.consensus_encode(&mut sequences)?;
+        }
+        let mut outputs = vec![];
+        for out in tx.output.iter() {
+            out.consensus_encode(&mut outputs)?;
+        }
+
+        let mut preimage = vec![];
+        tx.version.consensus_encode(&mut preimage)?;
+        preimage.extend_from_slice(sha256d::Hash::hash(&prevouts).as_byte_array());
+        preimage.extend_from_slice(sha256d::Hash::hash(&sequences).as_byte_array());
+        input.previous_output.consensus_encode(&mut preimage)?;
+        script_code.to_owned().consensus_encode(&mut preimage)?;
+        value.consensus_encode(&mut preimage)?;
+        input.sequence.consensus_encode(&mut preimage)?;
+        preimage.extend_from_slice(sha256d::Hash::hash(&outputs).as_byte_array());
+        tx.lock_time.consensus_encode(&mut preimage)?;
+        sighash_type.consensus_encode(&mut preimage)?;
+        Ok(preimage)
+    };
+    let preimage =
+        encode().map_err(|e| crate::Error::SignError(format!("forkid build sign hash err{e:}")))?;
+
+    Ok(sha256d::Hash::hash(&preimage).to_byte_array())
+}
+
+// the sighash byte of forkid signatures is not a valid EcdsaSighashType, push the raw bytes
+pub fn forkid_script_sig(
+    signature: &secp256k1::ecdsa::Signature,
+    sighash_type: u32,
+    pk: &bitcoin::PublicKey,
+) -> ScriptBuf {
+    let mut signature_bytes = signature.serialize_der().to_vec();
+    signature_bytes.push(sighash_type as u8);
+
+    let bytes: &PushBytes = signature_bytes.as_slice().try_into().unwrap();
+    script::Builder::new()
+        .push_slice(bytes)
+        .push_key(pk)
+        .into_script()
+}
+
 /// This method is used to estimate the size of a transaction.
 /// The signature data and witness data used in the calculation are dummy data,
 /// and do not represent actual transaction content.
 /// It is mainly intended for estimating the transaction size and does not involve
 /// actual transaction validation or signing.
 pub fn predict_transaction_size(
+    params: &NetworkParams,
     mut tx: bitcoin::Transaction,
-    change_address: Option<bitcoin::Address>,
+    change_script: Option<ScriptBuf>,
     address_type: BtcAddressType,
     mutlsig_sign_params: &Option<MultisigSignParams>,
 ) -> crate::Result<usize> {
     match address_type {
-        // 普通
         BtcAddressType::P2pkh => {
             let bytes = [
                 72, 48, 69, 2, 33, 0, 199, 18, 48, 98, 71, 105, 115, 75, 245, 25, 245, 245, 235,
@@ -503,7 +637,6 @@ pub fn predict_transaction_size(
                 input.script_sig = script.clone();
             }
         }
-        // 多签
         BtcAddressType::P2sh => {
             let multisig_sign = mutlsig_sign_params
                 .as_ref()
@@ -515,7 +648,6 @@ pub fn predict_transaction_size(
                 input.script_sig = buf.clone();
             }
         }
-        // 普通
         BtcAddressType::P2wpkh => {
             let witness_bytes = [
                 &[
@@ -537,7 +669,6 @@ pub fn predict_transaction_size(
                 input.witness = witness.clone();
             }
         }
-        // 多签
         BtcAddressType::P2wsh => {
             let multisig_sign = mutlsig_sign_params
                 .as_ref()
@@ -548,7 +679,6 @@ pub fn predict_transaction_size(
                 input.witness = witness.clone();
             }
         }
-        // 普通
         BtcAddressType::P2tr => {
             let witness_bytes = [[
                 0x0e, 0x30, 0xa4, 0x02, 0xce, 0x97, 0x5a, 0x9e, 0x97, 0xb7, 0x82, 0x2e, 0x0a, 0xff,
@@ -562,7 +692,6 @@ pub fn predict_transaction_size(
                 input.witness = witness.clone();
             }
         }
-        // 普通
         BtcAddressType::P2shWpkh => {
             let bytes = [
                 22, 0, 20, 235, 55, 162, 228, 166, 224, 55, 151, 185, 230, 245, 21, 15, 171, 242,
@@ -592,7 +721,6 @@ pub fn predict_transaction_size(
                 input.witness = witness.clone();
             }
         }
-        // 多签
         BtcAddressType::P2shWsh => {
             let multisig_sign = mutlsig_sign_params
                 .as_ref()
@@ -605,7 +733,6 @@ pub fn predict_transaction_size(
                 input.witness = witness.clone();
             }
         }
-        // 多签
         BtcAddressType::P2trSh => {
             let multisig_sign = mutlsig_sign_params
                 .as_ref()
@@ -618,157 +745,21 @@ pub fn predict_transaction_size(
         }
     }
 
-    if let Some(change_address) = change_address {
+    // 默认给到一个找零的地址输出大小
+    if let Some(change_script) = change_script {
         tx.output.push(TxOut {
             value: Amount::from_sat(1000),
-            script_pubkey: change_address.script_pubkey(),
+            script_pubkey: change_script,
         });
     }
 
-    let size = tx.vsize();
+    let size = match params.fee_unit {
+        FeeUnit::SatPerVbyte => tx.vsize(),
+        FeeUnit::SatPerByte => tx.total_size(),
+    };
     Ok(size)
 }
 
-// pub fn predict_transaction_size_v1(
-//     mut tx: bitcoin::Transaction,
-//     address_type: BtcAddressType,
-//     mutlsig_sign_params: &Option<MultisigSignParams>,
-// ) -> crate::Result<usize> {
-//     match address_type {
-//         // 普通
-//         BtcAddressType::P2pkh => {
-//             let bytes = [
-//                 72, 48, 69, 2, 33, 0, 199, 18, 48, 98, 71, 105, 115, 75, 245, 25, 245, 245, 235,
-//                 127, 226, 94, 203, 186, 149, 42, 87, 185, 68, 252, 65, 245, 220, 187, 178, 212, 30,
-//                 122, 2, 32, 55, 187, 187, 179, 154, 112, 87, 248, 204, 12, 230, 75, 34, 115, 214,
-//                 124, 255, 7, 175, 152, 231, 35, 89, 201, 191, 229, 104, 155, 124, 20, 167, 68, 1,
-//                 33, 2, 43, 28, 139, 236, 245, 140, 224, 167, 219, 46, 175, 86, 102, 242, 149, 199,
-//                 200, 52, 48, 119, 224, 154, 11, 38, 102, 235, 81, 241, 203, 192, 132, 70,
-//             ]
-//             .to_vec();
-//             let script = ScriptBuf::from_bytes(bytes);
-//             for input in tx.input.iter_mut() {
-//                 input.script_sig = script.clone();
-//             }
-//         }
-//         // 多签
-//         BtcAddressType::P2sh => {
-//             let multisig_sign = mutlsig_sign_params
-//                 .as_ref()
-//                 .ok_or_else(|| crate::Error::Other("Multisig parameters missing".to_string()))?;
-
-//             let buf = estimate_p2sh(multisig_sign)?;
-
-//             for input in tx.input.iter_mut() {
-//                 input.script_sig = buf.clone();
-//             }
-//         }
-//         // 普通
-//         BtcAddressType::P2wpkh => {
-//             let witness_bytes = [
-//                 &[
-//                     0x30, 0x45, 0x02, 0x21, 0x00, 0xc4, 0xfa, 0x6a, 0x60, 0x86, 0x92, 0xa7, 0x25,
-//                     0x76, 0xe2, 0xa3, 0xcd, 0x6f, 0x16, 0x45, 0x2b, 0x9a, 0x92, 0x38, 0x28, 0x1b,
-//                     0x3a, 0x4d, 0x77, 0x99, 0x37, 0xcc, 0xcf, 0x54, 0x23, 0x9b, 0x88, 0x02, 0x20,
-//                     0x48, 0x15, 0x86, 0x40, 0x6d, 0xcf, 0xe7, 0xf9, 0xbb, 0x5f, 0x19, 0x59, 0x37,
-//                     0x25, 0x9d, 0x74, 0x69, 0x5e, 0x2e, 0xce, 0x66, 0x82, 0x84, 0xd8, 0x6b, 0x3b,
-//                     0xcf, 0xf4, 0x58, 0xd4, 0xbd, 0xa5, 0x01,
-//                 ][..],
-//                 &[
-//                     0x02, 0x2b, 0x1c, 0x8b, 0xec, 0xf5, 0x8c, 0xe0, 0xa7, 0xdb, 0x2e, 0xaf, 0x56,
-//                     0x66, 0xf2, 0x95, 0xc7, 0xc8, 0x34, 0x30, 0x77, 0xe0, 0x9a, 0x0b, 0x26, 0x66,
-//                     0xeb, 0x51, 0xf1, 0xcb, 0xc0, 0x84, 0x46,
-//                 ][..],
-//             ];
-//             let witness = Witness::from_slice(witness_bytes.as_slice());
-//             for input in tx.input.iter_mut() {
-//                 input.witness = witness.clone();
-//             }
-//         }
-//         // 多签
-//         BtcAddressType::P2wsh => {
-//             let multisig_sign = mutlsig_sign_params
-//                 .as_ref()
-//                 .ok_or_else(|| crate::Error::Other("Multisig parameters missing".to_string()))?;
-
-//             let witness = estimate_p2wsh(multisig_sign)?;
-//             for input in tx.input.iter_mut() {
-//                 input.witness = witness.clone();
-//             }
-//         }
-//         // 普通
-//         BtcAddressType::P2tr => {
-//             let witness_bytes = [[
-//                 0x0e, 0x30, 0xa4, 0x02, 0xce, 0x97, 0x5a, 0x9e, 0x97, 0xb7, 0x82, 0x2e, 0x0a, 0xff,
-//                 0xcf, 0x0e, 0x1a, 0xde, 0xef, 0x2c, 0x10, 0x78, 0x9b, 0xa7, 0xa7, 0x5d, 0xd7, 0xd0,
-//                 0x32, 0x3d, 0x21, 0x21, 0x91, 0x00, 0xc0, 0x32, 0x85, 0x41, 0xdb, 0x64, 0x52, 0xe8,
-//                 0xbe, 0xf9, 0x70, 0xf3, 0x02, 0x24, 0x7f, 0x67, 0x33, 0x58, 0x15, 0xa2, 0x15, 0xbe,
-//                 0x14, 0xf1, 0x26, 0x1f, 0x54, 0x56, 0x8c, 0x8e,
-//             ]];
-//             let witness = Witness::from_slice(witness_bytes.as_slice());
-//             for input in tx.input.iter_mut() {
-//                 input.witness = witness.clone();
-//             }
-//         }
-//         // 普通
-//         BtcAddressType::P2shWpkh => {
-//             let bytes = [
-//                 22, 0, 20, 235, 55, 162, 228, 166, 224, 55, 151, 185, 230, 245, 21, 15, 171, 242,
-//                 160, 164, 229, 103, 81,
-//             ]
-//             .to_vec();
-//             let script = ScriptBuf::from_bytes(bytes);
-
-//             let witness_bytes = [
-//                 &[
-//                     0x30, 0x45, 0x02, 0x21, 0x00, 0xf5, 0x5b, 0x7c, 0x11, 0xc9, 0x06, 0x87, 0x2c,
-//                     0xe6, 0x7b, 0xcc, 0xff, 0xc9, 0xac, 0xe1, 0xc2, 0x19, 0xcf, 0xc6, 0x53, 0xbc,
-//                     0x6f, 0x86, 0xee, 0x72, 0x17, 0x5d, 0x31, 0x56, 0x81, 0x51, 0x38, 0x02, 0x20,
-//                     0x10, 0xc4, 0x81, 0xdb, 0x3c, 0xbf, 0x56, 0x21, 0x78, 0x0d, 0x39, 0x57, 0xf2,
-//                     0xba, 0xb5, 0x69, 0xc6, 0x97, 0x5d, 0x76, 0xe3, 0x51, 0x7e, 0xb0, 0x9c, 0xc7,
-//                     0x71, 0xac, 0xfe, 0x2a, 0x1d, 0x81, 0x01,
-//                 ][..],
-//                 &[
-//                     0x02, 0x2b, 0x1c, 0x8b, 0xec, 0xf5, 0x8c, 0xe0, 0xa7, 0xdb, 0x2e, 0xaf, 0x56,
-//                     0x66, 0xf2, 0x95, 0xc7, 0xc8, 0x34, 0x30, 0x77, 0xe0, 0x9a, 0x0b, 0x26, 0x66,
-//                     0xeb, 0x51, 0xf1, 0xcb, 0xc0, 0x84, 0x46,
-//                 ][..],
-//             ];
-//             let witness = Witness::from_slice(witness_bytes.as_slice());
-//             for input in tx.input.iter_mut() {
-//                 input.script_sig = script.clone();
-//                 input.witness = witness.clone();
-//             }
-//         }
-//         // 多签
-//         BtcAddressType::P2shWsh => {
-//             let multisig_sign = mutlsig_sign_params
-//                 .as_ref()
-//                 .ok_or_else(|| crate::Error::Other("Multisig parameters missing".to_string()))?;
-
-//             let (witness, script) = estimate_p2sh_wsh(multisig_sign)?;
-
-//             for input in tx.input.iter_mut() {
-//                 input.script_sig = script.clone();
-//                 input.witness = witness.clone();
-//             }
-//         }
-//         // 多签
-//         BtcAddressType::P2trSh => {
-//             let multisig_sign = mutlsig_sign_params
-//                 .as_ref()
-//                 .ok_or_else(|| crate::Error::Other("Multisig parameters missing".to_string()))?;
-//             let witness = estimate_p2tr_sh(multisig_sign)?;
-
-//             for input in tx.input.iter_mut() {
-//                 input.witness = witness.clone();
-//             }
-//         }
-//     }
-
-//     Ok(tx.vsize())
-// }
-
 // 默认使用签名的格式
 pub const ESTIMATE_SIGN_BYTES: [u8; 72] = [
     48, 69, 2, 33, 0, 167, 108, 196, 128, 152, 212, 181, 18, 178, 16, 251, 53, 222, 24, 65, 210,
@@ -0,0 +1,320 @@
+use super::{
+    address::UtxoAddress,
+    network::NetworkParams,
+    provider::Provider,
+    signature::{self, MultisigSignParams},
+    utxos::UtxoList,
+};
+use bitcoin::{Amount, TxIn, consensus, transaction::Version};
+use wallet_types::chain::address::r#type::BtcAddressType;
+use wallet_utils::unit;
+
+#[derive(Debug)]
+pub struct TransferArg {
+    pub params: &'static NetworkParams,
+    pub from: UtxoAddress,
+    pub to: UtxoAddress,
+    pub value: bitcoin::Amount,
+    pub change_address: UtxoAddress,
+    pub address_type: BtcAddressType,
+    pub fee_rate: Option<u64>,
+    pub spend_all: bool,
+}
+
+impl TransferArg {
+    pub fn new(
+        params: &'static NetworkParams,
+        from: &str,
+        to: &str,
+        value: &str,
+        address_type: BtcAddressType,
+    ) -> crate::Result<Self> {
+        let value = unit::convert_to_u256(value, params.decimals)?;
+        let value = bitcoin::Amount::from_sat(value.to::<u64>());
+
+        Ok(Self {
+            params,
+            from: UtxoAddress::parse(params, from)?,
+            to: UtxoAddress::parse(params, to)?,
+            change_address: UtxoAddress::parse(params, from)?,
+            value,
+            address_type,
+            fee_rate: None,
+            spend_all: false,
+        })
+    }
+
+    pub fn with_spend_all(mut self, spend_all: bool) -> Self {
+        self.spend_all = spend_all;
+        self
+    }
+
+    /// unit is sat/vb
+    pub async fn get_fee_rate(&self, provider: &Provider) -> crate::Result<bitcoin::Amount> {
+        if let Some(fee_rate) = self.fee_rate {
+            Ok(bitcoin::Amount::from_sat(fee_rate))
+        } else {
+            let fetched_fee_rate = provider.fetch_fee_rate(self.params.fee_blocks).await?;
+
+            Ok(fetched_fee_rate)
+        }
+    }
+}
+
+impl TransferArg {
+    pub fn build_transaction(&self, mut utxo: UtxoList) -> crate::Result<TransferBuilder> {
+        let (input, output) = if self.spend_all {
+            (utxo.selected_all()?, vec![])
+        } else {
+            (
+                utxo.inputs_from_utxo(self.value)?,
+                vec![bitcoin::TxOut {
+                    value: self.value,
+                    script_pubkey: self.to.script_pubkey(),
+                }],
+            )
+        };
+
+        let transaction = bitcoin::Transaction {
+            version: Version(2),
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input,
+            output,
+        };
+
+        Ok(TransferBuilder {
+            params: self.params,
+            transaction,
+            utxo,
+            multisig_sign_params: None,
+        })
+    }
+
+    // build transaction with fee  fee unit is Btc
+    // include change
+    pub fn build_with_fee(
+        &self,
+        mut utxo: UtxoList,
+        fee: Amount,
+    ) -> crate::Result<TransferBuilder> {
+        let amount = self.value + fee;
+        let input = utxo.inputs_from_utxo(amount)?;
+
+        let mut output = vec![];
+        let spend = bitcoin::TxOut {
+            value: self.value,
+            script_pubkey: self.to.script_pubkey(),
+        };
+        output.push(spend);
+
+        // select utxo amount
+        let total_input = utxo.total_input_amount();
+
+        if total_input >= amount + Amount::from_sat(self.params.dust_limit) {
+            let change = total_input - amount;
+            let change_output = bitcoin::TxOut {
+                value: change,
+                script_pubkey: self.change_address.script_pubkey(),
+            };
+            output.push(change_output);
+        }
+
+        let transaction = bitcoin::Transaction {
+            version: Version(2),
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input,
+            output,
+        };
+
+        Ok(TransferBuilder {
+            params: self.params,
+            transaction,
+            utxo,
+            multisig_sign_params: None,
+        })
+    }
+}
+
+pub struct TransferBuilder {
+    pub params: &'static NetworkParams,
+    pub transaction: bitcoin::Transaction,
+    pub utxo: UtxoList,
+    // 多签地址预估交易大小时使用
+    pub multisig_sign_params: Option<MultisigSignParams>,
+}
+
+// build
+impl TransferBuilder {
+    pub fn set_multisig_params(&mut self, multisig_sign_params: MultisigSignParams) {
+        self.multisig_sign_params = Some(multisig_sign_params);
+    }
+
+    pub fn transactin_size(
+        &mut self,
+        fee_rate: Amount,
+        params: &TransferArg,
+    ) -> crate::Result<usize> {
+        if params.spend_all {
+            self.spent_all_set_fee(fee_rate, params.to.clone(), params.address_type)
+        } else {
+            // 找零和手续费配置
+            self.change_and_fee(
+                fee_rate,
+                params.change_address.clone(),
+                params.address_type,
+                params.value,
+            )
+        }
+    }
+
+    pub fn change_and_fee(
+        &mut self,
+        fee_rate: bitcoin::Amount,
+        change_address: UtxoAddress,
+        address_type: BtcAddressType,
+        value: bitcoin::Amount,
+    ) -> crate::Result<usize> {
+        loop {
+            // 在预估交易大小是使用交易的副本
+            let size = signature::predict_transaction_size(
+                self.params,
+                self.transaction.clone(),
+                Some(change_address.script_pubkey()),
+                address_type,
+                &self.multisig_sign_params,
+            )?;
+
+            let res = self.set_transaction_fee(fee_rate, size, value)?;
+
+            // 没有额外的输入进行找零
+            if !res.0 {
+                self.change(res.1, change_address);
+                return Ok(size);
+            }
+        }
+    }
+
+    pub fn spent_all_set_fee(
+        &mut self,
+        fee_rate: bitcoin::Amount,
+        spend_address: UtxoAddress,
+        address_type: BtcAddressType,
+    ) -> crate::Result<usize> {
+        // 模拟交易的大小
+        let size = signature::predict_transaction_size(
+            self.params,
+            self.transaction.clone(),
+            Some(spend_address.script_pubkey()),
+            address_type,
+            &self.multisig_sign_params,
+        )?;
+
+        let total_input = self.utxo.total_input_amount();
+        let transaction_fee = fee_rate * size as u64;
+
+        if total_input < transaction_fee {
+            return Err(crate::UtxoError::InsufficientFee(transaction_fee.to_btc()).into());
+        }
+
+        // add spend
+        let spend = bitcoin::TxOut {
+            value: total_input - transaction_fee,
+            script_pubkey: spend_address.script_pubkey(),
+        };
+        self.transaction.output.push(spend);
+
+        Ok(size)
+    }
+
+    fn set_transaction_fee(
+        &mut self,
+        fee_rate: bitcoin::Amount,
+        size: usize,
+        value: bitcoin::Amount,
+    ) -> crate::Result<(bool, bitcoin::Amount)> {
+        // The total amount of selected UTXOs
+        let total_input = self.utxo.total_input_amount();
+
+        // transaction fee
+        let transaction_fee = fee_rate * size as u64;
+
+        // Whether there is a new input; if there is, the size of the transaction changes,
+        // and the fee needs to be recalculated
+        let mut has_new_input = false;
+        let required_amount = value + transaction_fee;
+
+        // In the case where the current input amount is insufficient
+        if total_input < required_amount {
+            // How much additional is required
+            let additional_required = required_amount - total_input;
+
+            // The total additional input
+            let mut additional_input = bitcoin::Amount::from_sat(0);
+
+            // UTXOs that have not been selected
+            let available = self.utxo.available_utxo();
+
+            for utxo in available {
+                additional_input += bitcoin::Amount::from_sat(utxo.value);
+
+                self.transaction.input.push(TxIn::from(utxo.clone()));
+                has_new_input = true;
+
+                // Mark this UTXO as used
+                self.utxo.tag_select(&utxo.txid, utxo.vout);
+
+                // If the additional input is sufficient
+                if additional_input >= additional_required {
+                    break;
+                }
+            }
+
+            // If all UTXOs have been iterated and there is still not enough money
+            if additional_input < additional_required {
+                return Err(crate::UtxoError::InsufficientFee(additional_required.to_btc()).into());
+            }
+        }
+        Ok((has_new_input, required_amount))
+    }
+
+    // change below the dust limit is left to the miner
+    fn change(&mut self, required_amount: Amount, change_address: UtxoAddress) {
+        let total_input = self.utxo.total_input_amount();
+        let change = total_input - required_amount;
+        if change >= Amount::from_sat(self.params.dust_limit) {
+            self.transaction.output.push(bitcoin::TxOut {
+                value: change,
+                script_pubkey: change_address.script_pubkey(),
+            });
+        }
+    }
+
+    pub fn act_transfer_fee(&self) -> Amount {
+        let input_total = self.utxo.total_input_amount();
+
+        let out_total = self
+            .transaction
+            .output
+            .iter()
+            .map(|item| item.value)
+            .sum::<Amount>();
+
+        input_total - out_total
+    }
+
+    // 如果实际的手续费大于 给定的手续费 5倍，那么报错
+    pub fn exceeds_max_fee(&self, fee: Amount) -> bool {
+        let act_fee = self.act_transfer_fee();
+
+        act_fee > (fee * 5)
+    }
+
+    // 转账金额小于手续费
+    pub fn is_dust_tx(&self, amount: Amount, fee: Amount) -> bool {
+        amount <= (fee / self.params.expend_fee_rate)
+    }
+
+    pub fn get_raw_transaction(&self) -> String {
+        consensus::encode::serialize_hex(&self.transaction)
+    }
+}
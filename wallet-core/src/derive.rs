@@ -35,3 +35,11 @@ pub trait GenDerivationDog {
         input_index: i32,
     ) -> Result<String, Self::Error>;
 }
+
+pub trait GenDerivationBch {
+    type Error;
+    fn generate(
+        address_type: &Option<wallet_types::chain::address::r#type::BchAddressType>,
+        input_index: i32,
+    ) -> Result<String, Self::Error>;
+}
@@ -114,10 +114,9 @@ impl RpcClient {
         let rpc_result = wallet_utils::serde_func::serde_from_str::<JsonRpcResult<R>>(&response)?;
 
         if let Some(err) = rpc_result.error {
-            return Err(TransportError::NodeResponseError(NodeResponseError::new(
-                err.code,
-                Some(err.message),
-            )));
+            return Err(TransportError::NodeResponseError(
+                NodeResponseError::new(err.code, Some(err.message)).with_data(err.data),
+            ));
         }
 
         match rpc_result.result {
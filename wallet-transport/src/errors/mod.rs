@@ -34,10 +34,21 @@ impl TransportError {
 pub struct NodeResponseError {
     pub code: i64,
     pub message: Option<String>,
+    // json rpc error data, evm nodes put the revert data here
+    pub data: Option<serde_json::Value>,
 }
 
 impl NodeResponseError {
     pub fn new(code: i64, message: Option<String>) -> Self {
-        Self { code, message }
+        Self {
+            code,
+            message,
+            data: None,
+        }
+    }
+
+    pub fn with_data(mut self, data: Option<serde_json::Value>) -> Self {
+        self.data = data;
+        self
     }
 }
@@ -1,4 +1,6 @@
-use super::r#type::{AddressType, BtcAddressType, DogAddressType, LtcAddressType, TonAddressType};
+use super::r#type::{
+    AddressType, BchAddressType, BtcAddressType, DogAddressType, LtcAddressType, TonAddressType,
+};
 use crate::constant::btc_address_catecory::*;
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Copy)]
@@ -7,6 +9,7 @@ pub enum AddressCategory {
     Btc(BtcAddressCategory),
     Ltc(LtcAddressCategory),
     Dog(DogAddressCategory),
+    Bch(BchAddressCategory),
     Ton(TonAddressType),
     Other,
 }
@@ -17,6 +20,7 @@ impl AddressCategory {
             AddressCategory::Btc(addr_type) => addr_type.as_ref(),
             AddressCategory::Ltc(addr_type) => addr_type.as_ref(),
             AddressCategory::Dog(addr_type) => addr_type.as_ref(),
+            AddressCategory::Bch(addr_type) => addr_type.as_ref(),
             AddressCategory::Ton(addr_type) => addr_type.as_ref(),
             AddressCategory::Other => "",
         }
@@ -191,12 +195,50 @@ impl From<DogAddressType> for DogAddressCategory {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Copy)]
+pub enum BchAddressCategory {
+    Legacy,
+}
+
+impl AsRef<str> for BchAddressCategory {
+    fn as_ref(&self) -> &str {
+        match self {
+            BchAddressCategory::Legacy => LEGACY,
+        }
+    }
+}
+impl TryFrom<String> for BchAddressCategory {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            LEGACY => Ok(BchAddressCategory::Legacy),
+            other => Err(crate::Error::BchAddressCategoryInvalid(other.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for BchAddressCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl From<BchAddressType> for BchAddressCategory {
+    fn from(addr_type: BchAddressType) -> Self {
+        match addr_type {
+            BchAddressType::P2pkh | BchAddressType::P2sh => BchAddressCategory::Legacy,
+        }
+    }
+}
+
 impl From<AddressType> for AddressCategory {
     fn from(address: AddressType) -> Self {
         match address {
             AddressType::Btc(addr_type) => AddressCategory::Btc(addr_type.into()),
             AddressType::Ltc(addr_type) => AddressCategory::Ltc(addr_type.into()),
             AddressType::Dog(addr_type) => AddressCategory::Dog(addr_type.into()),
+            AddressType::Bch(addr_type) => AddressCategory::Bch(addr_type.into()),
             AddressType::Ton(addr_type) => AddressCategory::Ton(addr_type),
             AddressType::Other => AddressCategory::Other,
         }
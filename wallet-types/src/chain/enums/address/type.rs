@@ -1,4 +1,6 @@
-use super::category::{BtcAddressCategory, DogAddressCategory, LtcAddressCategory};
+use super::category::{
+    BchAddressCategory, BtcAddressCategory, DogAddressCategory, LtcAddressCategory,
+};
 use crate::constant::btc_address_type::*;
 use once_cell::sync::Lazy;
 use tonlib_core::wallet::wallet_version::WalletVersion;
@@ -24,6 +26,9 @@ pub static LTC_ADDRESS_TYPES: Lazy<Vec<AddressType>> = Lazy::new(|| {
 pub static DOG_ADDRESS_TYPES: Lazy<Vec<AddressType>> =
     Lazy::new(|| vec![AddressType::Dog(DogAddressType::P2pkh)]);
 
+pub static BCH_ADDRESS_TYPES: Lazy<Vec<AddressType>> =
+    Lazy::new(|| vec![AddressType::Bch(BchAddressType::P2pkh)]);
+
 pub static TON_ADDRESS_TYPES: Lazy<Vec<AddressType>> =
     Lazy::new(|| vec![AddressType::Ton(TonAddressType::V4R2)]);
 
@@ -33,6 +38,7 @@ pub enum AddressType {
     Btc(BtcAddressType),
     Ltc(LtcAddressType),
     Dog(DogAddressType),
+    Bch(BchAddressType),
     Ton(TonAddressType),
     Other,
 }
@@ -120,6 +126,15 @@ pub enum DogAddressType {
     P2trSh,
 }
 
+/// bitcoin cash has no segwit, the address is shown in cashaddr format
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Copy)]
+pub enum BchAddressType {
+    /// Pay to public hash (legacy)
+    P2pkh,
+    /// Pay to script hash
+    P2sh,
+}
+
 impl AsRef<str> for BtcAddressType {
     fn as_ref(&self) -> &str {
         match self {
@@ -165,6 +180,15 @@ impl AsRef<str> for DogAddressType {
     }
 }
 
+impl AsRef<str> for BchAddressType {
+    fn as_ref(&self) -> &str {
+        match self {
+            BchAddressType::P2pkh => P2PKH,
+            BchAddressType::P2sh => P2SH,
+        }
+    }
+}
+
 impl AsRef<str> for TonAddressType {
     fn as_ref(&self) -> &str {
         match self {
@@ -207,6 +231,20 @@ impl From<DogAddressCategory> for DogAddressType {
     }
 }
 
+impl From<BchAddressCategory> for BchAddressType {
+    fn from(addr_scheme: BchAddressCategory) -> Self {
+        match addr_scheme {
+            BchAddressCategory::Legacy => BchAddressType::P2sh,
+        }
+    }
+}
+
+impl std::fmt::Display for BchAddressType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
 impl std::fmt::Display for DogAddressType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_ref())
@@ -237,6 +275,7 @@ impl std::fmt::Display for AddressType {
             AddressType::Btc(btc_address_type) => write!(f, "{}", btc_address_type),
             AddressType::Ltc(ltc_address_type) => write!(f, "{}", ltc_address_type),
             AddressType::Dog(dog_address_type) => write!(f, "{}", dog_address_type),
+            AddressType::Bch(bch_address_type) => write!(f, "{}", bch_address_type),
             AddressType::Ton(ton) => write!(f, "{}", ton),
             AddressType::Other => write!(f, ""),
         }
@@ -259,6 +298,7 @@ impl AsRef<str> for AddressType {
             AddressType::Btc(btc_address_type) => btc_address_type.as_ref(),
             AddressType::Ltc(ltc_address_type) => ltc_address_type.as_ref(),
             AddressType::Dog(dog_address_type) => dog_address_type.as_ref(),
+            AddressType::Bch(bch_address_type) => bch_address_type.as_ref(),
             AddressType::Ton(ton) => ton.as_ref(),
             AddressType::Other => "",
         }
@@ -348,3 +388,25 @@ impl TryFrom<&str> for DogAddressType {
         })
     }
 }
+
+impl<T: AsRef<str>> TryFrom<Option<T>> for BchAddressType {
+    type Error = crate::Error;
+    fn try_from(value: Option<T>) -> Result<Self, Self::Error> {
+        match value {
+            Some(v) => BchAddressType::try_from(v.as_ref()),
+            None => Err(crate::Error::BchNeedAddressType),
+        }
+    }
+}
+
+impl TryFrom<&str> for BchAddressType {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value.to_lowercase().as_ref() {
+            P2PKH => BchAddressType::P2pkh,
+            P2SH => BchAddressType::P2sh,
+            other => return Err(crate::Error::BchAddressTypeInvalid(other.to_string())),
+        })
+    }
+}
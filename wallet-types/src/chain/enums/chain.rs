@@ -1,4 +1,5 @@
-#[derive(Debug, PartialEq, Clone, serde::Serialize, Copy)]
+// serialized as the chain code string, the same as Display and TryFrom<&str>
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum ChainCode {
     Tron,
     Bitcoin,
@@ -7,8 +8,11 @@ pub enum ChainCode {
     BnbSmartChain,
     Litecoin,
     Dogcoin,
+    BitcoinCash,
     Sui,
     Ton,
+    // evm compatible chain keyed by the chain id, described by the evm registry
+    Evm(u64),
 }
 
 impl TryFrom<&str> for ChainCode {
@@ -23,9 +27,19 @@ impl TryFrom<&str> for ChainCode {
             crate::constant::chain_code::BNB => ChainCode::BnbSmartChain,
             crate::constant::chain_code::LTC => ChainCode::Litecoin,
             crate::constant::chain_code::DOG => ChainCode::Dogcoin,
+            crate::constant::chain_code::BCH => ChainCode::BitcoinCash,
             crate::constant::chain_code::TON => ChainCode::Ton,
             crate::constant::chain_code::SUI => ChainCode::Sui,
-            _ => return Err(crate::Error::UnknownChainCode),
+            other => {
+                if let Some(info) = super::evm::EvmChainInfo::by_code(other) {
+                    return Ok(info.chain_code());
+                }
+                let chain_id = other
+                    .strip_prefix(crate::constant::chain_code::EVM_PREFIX)
+                    .and_then(|id| id.parse::<u64>().ok())
+                    .ok_or(crate::Error::UnknownChainCode)?;
+                ChainCode::Evm(chain_id)
+            }
         };
         Ok(res)
     }
@@ -41,12 +55,40 @@ impl std::fmt::Display for ChainCode {
             ChainCode::Bitcoin => write!(f, "{}", crate::constant::chain_code::BTC),
             ChainCode::Litecoin => write!(f, "{}", crate::constant::chain_code::LTC),
             ChainCode::Dogcoin => write!(f, "{}", crate::constant::chain_code::DOG),
+            ChainCode::BitcoinCash => write!(f, "{}", crate::constant::chain_code::BCH),
             ChainCode::Sui => write!(f, "{}", crate::constant::chain_code::SUI),
             ChainCode::Ton => write!(f, "{}", crate::constant::chain_code::TON),
+            ChainCode::Evm(chain_id) => match super::evm::EvmChainInfo::by_chain_id(*chain_id) {
+                Some(info) if !info.code.is_empty() => write!(f, "{}", info.code),
+                _ => write!(f, "{}{}", crate::constant::chain_code::EVM_PREFIX, chain_id),
+            },
         }
     }
 }
 
+impl serde::Serialize for ChainCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ChainCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        ChainCode::try_from(code.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+impl ChainCode {
+    // chains served by the evm implementation
+    pub fn is_evm(&self) -> bool {
+        matches!(
+            self,
+            ChainCode::Ethereum | ChainCode::BnbSmartChain | ChainCode::Evm(_)
+        )
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, serde::Serialize)]
 pub struct ChainCodes(pub Vec<ChainCode>);
 
@@ -55,14 +97,18 @@ impl TryFrom<u32> for ChainCodes {
 
     fn try_from(value: u32) -> Result<Self, Self::Error> {
         let chain_code = match value {
-            crate::constant::chain_type::ETH_TYPE => {
-                vec![ChainCode::Ethereum, ChainCode::BnbSmartChain]
-            }
+            // every mainnet chain of the evm registry shares the ethereum coin type
+            crate::constant::chain_type::ETH_TYPE => super::evm::EVM_CHAINS
+                .iter()
+                .filter(|info| !info.testnet)
+                .map(|info| info.chain_code())
+                .collect(),
             crate::constant::chain_type::TRON_TYPE => vec![ChainCode::Tron],
             crate::constant::chain_type::SOLANA_TYPE => vec![ChainCode::Solana],
             crate::constant::chain_type::BTC_TYPE => vec![ChainCode::Bitcoin],
             crate::constant::chain_type::LTC_TYPE => vec![ChainCode::Litecoin],
             crate::constant::chain_type::DOG_TYPE => vec![ChainCode::Dogcoin],
+            crate::constant::chain_type::BCH_TYPE => vec![ChainCode::BitcoinCash],
             crate::constant::chain_type::SUI_TYPE => vec![ChainCode::Sui],
             _ => return Err(crate::Error::UnknownChainCode),
         };
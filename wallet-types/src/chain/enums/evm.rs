@@ -0,0 +1,331 @@
+use super::{chain::ChainCode, network::NetworkKind};
+
+/// How the transaction fee of an evm chain is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmFeeModel {
+    // type 0 transaction, only gas price
+    Legacy,
+    // type 2 transaction, max fee = base fee + priority fee
+    Eip1559,
+    // type 2 transaction on chains whose base fee is always 0 (bnb smart chain),
+    // max fee equals the priority fee
+    Eip1559ZeroBaseFee,
+}
+
+/// Rollup stack of a layer 2 chain, the l1 data fee is charged on top of the l2 execution fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmRollup {
+    // l1 fee from the GasPriceOracle predeploy, paid outside the gas limit
+    OpStack,
+    // l1 fee charged as extra gas units, broken down by NodeInterface
+    Arbitrum,
+}
+
+/// Safe{Wallet} proxy factory and singleton deployed on the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafeDeployment {
+    pub proxy_factory: &'static str,
+    pub singleton: &'static str,
+    // MultiSendCallOnly library, batches are delegatecalled to it
+    pub multi_send: &'static str,
+}
+
+// safe v1.3.0 canonical deployment
+pub const SAFE_CANONICAL: SafeDeployment = SafeDeployment {
+    proxy_factory: "0xa6B71E26C5e0845f74c812102Ca7114b6a896AB2",
+    singleton: "0x3E5c63644E683549055b9Be8653de26E0B4CD36E",
+    multi_send: "0x40A2aCCbd92BCA938b02010E17A5b8929b49130D",
+};
+
+// safe v1.3.0 eip155 deployment, used where the canonical one is missing
+pub const SAFE_EIP155: SafeDeployment = SafeDeployment {
+    proxy_factory: "0xC22834581EbC8527d974F8a1c97E1bEA4EF910BC",
+    singleton: "0xfb1bffC9d739B8D520DaF37dF666da4C687191EA",
+    multi_send: "0xA1dabEF33b3B82c7814B6D82A79e50F4AC44102B",
+};
+
+/// Static description of an evm compatible chain,
+/// every chain in the registry is served by the same `EthChain` implementation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EvmChainInfo {
+    pub chain_id: u64,
+    // chain code used by the wallet, empty for the chains with their own `ChainCode` variant
+    pub code: &'static str,
+    pub name: &'static str,
+    pub native_symbol: &'static str,
+    pub native_decimals: u8,
+    pub fee_model: EvmFeeModel,
+    pub safe: Option<SafeDeployment>,
+    pub rollup: Option<EvmRollup>,
+    pub explorer: &'static str,
+    pub testnet: bool,
+}
+
+impl EvmChainInfo {
+    pub fn supports_eip1559(&self) -> bool {
+        self.fee_model != EvmFeeModel::Legacy
+    }
+
+    pub fn chain_code(&self) -> ChainCode {
+        match self.chain_id {
+            1 | 11155111 => ChainCode::Ethereum,
+            56 | 97 => ChainCode::BnbSmartChain,
+            chain_id => ChainCode::Evm(chain_id),
+        }
+    }
+
+    pub fn tx_url(&self, hash: &str) -> String {
+        format!("{}/tx/{}", self.explorer.trim_end_matches('/'), hash)
+    }
+
+    pub fn address_url(&self, address: &str) -> String {
+        format!(
+            "{}/address/{}",
+            self.explorer.trim_end_matches('/'),
+            address
+        )
+    }
+
+    pub fn unit(&self) -> f64 {
+        10_f64.powi(self.native_decimals as i32)
+    }
+
+    pub fn by_chain_id(chain_id: u64) -> Option<&'static EvmChainInfo> {
+        EVM_CHAINS.iter().find(|c| c.chain_id == chain_id)
+    }
+
+    pub fn by_code(code: &str) -> Option<&'static EvmChainInfo> {
+        EVM_CHAINS
+            .iter()
+            .find(|c| !c.code.is_empty() && c.code.eq_ignore_ascii_case(code))
+    }
+
+    // ethereum and bnb smart chain share the chain code between mainnet and testnet,
+    // the network picks the chain id
+    pub fn lookup(chain_code: ChainCode, network: NetworkKind) -> Option<&'static EvmChainInfo> {
+        let testnet = network != NetworkKind::Mainnet;
+        let chain_id = match chain_code {
+            ChainCode::Ethereum if testnet => 11155111,
+            ChainCode::Ethereum => 1,
+            ChainCode::BnbSmartChain if testnet => 97,
+            ChainCode::BnbSmartChain => 56,
+            ChainCode::Evm(chain_id) => chain_id,
+            _ => return None,
+        };
+        Self::by_chain_id(chain_id)
+    }
+
+    // same as lookup, but an evm chain id outside the registry is described with
+    // conservative defaults: legacy fee, 18 decimals and no safe deployment
+    pub fn resolve(chain_code: ChainCode, network: NetworkKind) -> Option<EvmChainInfo> {
+        match (Self::lookup(chain_code, network), chain_code) {
+            (Some(info), _) => Some(*info),
+            (None, ChainCode::Evm(chain_id)) => Some(Self::unregistered(chain_id, network)),
+            _ => None,
+        }
+    }
+
+    pub fn unregistered(chain_id: u64, network: NetworkKind) -> EvmChainInfo {
+        EvmChainInfo {
+            chain_id,
+            code: "",
+            name: "evm",
+            native_symbol: "",
+            native_decimals: 18,
+            fee_model: EvmFeeModel::Legacy,
+            safe: None,
+            rollup: None,
+            explorer: "",
+            testnet: network != NetworkKind::Mainnet,
+        }
+    }
+}
+
+pub static EVM_CHAINS: &[EvmChainInfo] = &[
+    EvmChainInfo {
+        chain_id: 1,
+        code: "",
+        name: "Ethereum",
+        native_symbol: "ETH",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_CANONICAL),
+        rollup: None,
+        explorer: "https://etherscan.io",
+        testnet: false,
+    },
+    EvmChainInfo {
+        chain_id: 11155111,
+        code: "",
+        name: "Sepolia",
+        native_symbol: "ETH",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_CANONICAL),
+        rollup: None,
+        explorer: "https://sepolia.etherscan.io",
+        testnet: true,
+    },
+    EvmChainInfo {
+        chain_id: 56,
+        code: "",
+        name: "BNB Smart Chain",
+        native_symbol: "BNB",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559ZeroBaseFee,
+        safe: Some(SAFE_CANONICAL),
+        rollup: None,
+        explorer: "https://bscscan.com",
+        testnet: false,
+    },
+    EvmChainInfo {
+        chain_id: 97,
+        code: "",
+        name: "BNB Smart Chain Testnet",
+        native_symbol: "tBNB",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559ZeroBaseFee,
+        safe: Some(SAFE_CANONICAL),
+        rollup: None,
+        explorer: "https://testnet.bscscan.com",
+        testnet: true,
+    },
+    EvmChainInfo {
+        chain_id: 137,
+        code: "pol",
+        name: "Polygon",
+        native_symbol: "POL",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_CANONICAL),
+        rollup: None,
+        explorer: "https://polygonscan.com",
+        testnet: false,
+    },
+    EvmChainInfo {
+        chain_id: 42161,
+        code: "arb",
+        name: "Arbitrum One",
+        native_symbol: "ETH",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_CANONICAL),
+        rollup: Some(EvmRollup::Arbitrum),
+        explorer: "https://arbiscan.io",
+        testnet: false,
+    },
+    EvmChainInfo {
+        chain_id: 10,
+        code: "op",
+        name: "OP Mainnet",
+        native_symbol: "ETH",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_EIP155),
+        rollup: Some(EvmRollup::OpStack),
+        explorer: "https://optimistic.etherscan.io",
+        testnet: false,
+    },
+    EvmChainInfo {
+        chain_id: 8453,
+        code: "base",
+        name: "Base",
+        native_symbol: "ETH",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_CANONICAL),
+        rollup: Some(EvmRollup::OpStack),
+        explorer: "https://basescan.org",
+        testnet: false,
+    },
+    EvmChainInfo {
+        chain_id: 43114,
+        code: "avax",
+        name: "Avalanche C-Chain",
+        native_symbol: "AVAX",
+        native_decimals: 18,
+        fee_model: EvmFeeModel::Eip1559,
+        safe: Some(SAFE_CANONICAL),
+        rollup: None,
+        explorer: "https://snowtrace.io",
+        testnet: false,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::chain::ChainCodes;
+
+    #[test]
+    fn test_lookup() {
+        let bsc = EvmChainInfo::lookup(ChainCode::BnbSmartChain, NetworkKind::Mainnet).unwrap();
+        assert_eq!(bsc.chain_id, 56);
+        assert_eq!(bsc.fee_model, EvmFeeModel::Eip1559ZeroBaseFee);
+
+        let sepolia = EvmChainInfo::lookup(ChainCode::Ethereum, NetworkKind::Testnet).unwrap();
+        assert_eq!(sepolia.chain_id, 11155111);
+
+        let polygon = EvmChainInfo::by_code("pol").unwrap();
+        assert_eq!(polygon.chain_code(), ChainCode::Evm(137));
+        assert_eq!(
+            EvmChainInfo::lookup(ChainCode::Evm(137), NetworkKind::Mainnet),
+            Some(polygon)
+        );
+        assert_eq!(polygon.rollup, None);
+        assert_eq!(
+            EvmChainInfo::by_code("base").unwrap().rollup,
+            Some(EvmRollup::OpStack)
+        );
+        assert!(EvmChainInfo::lookup(ChainCode::Tron, NetworkKind::Mainnet).is_none());
+    }
+
+    #[test]
+    fn test_chain_code_string() {
+        let code = ChainCode::try_from("arb").unwrap();
+        assert_eq!(code, ChainCode::Evm(42161));
+        assert_eq!(code.to_string(), "arb");
+
+        // chains outside the registry keep the chain id
+        let code = ChainCode::try_from("evm:324").unwrap();
+        assert_eq!(code, ChainCode::Evm(324));
+        assert_eq!(code.to_string(), "evm:324");
+
+        let info = EvmChainInfo::resolve(code, NetworkKind::Mainnet).unwrap();
+        assert_eq!(info.chain_id, 324);
+        assert_eq!(info.fee_model, EvmFeeModel::Legacy);
+        assert_eq!(info.safe, None);
+        assert!(EvmChainInfo::resolve(ChainCode::Tron, NetworkKind::Mainnet).is_none());
+    }
+
+    #[test]
+    fn test_chain_code_serde() {
+        let codes = [
+            (ChainCode::Ethereum, "\"eth\""),
+            (ChainCode::Evm(137), "\"pol\""),
+            (ChainCode::Evm(324), "\"evm:324\""),
+        ];
+        for (code, json) in codes {
+            assert_eq!(serde_json::to_string(&code).unwrap(), json);
+            assert_eq!(serde_json::from_str::<ChainCode>(json).unwrap(), code);
+        }
+        assert!(serde_json::from_str::<ChainCode>("\"evm:abc\"").is_err());
+    }
+
+    #[test]
+    fn test_eth_type_chain_codes() {
+        let codes = ChainCodes::try_from(crate::constant::chain_type::ETH_TYPE).unwrap();
+        assert_eq!(
+            codes.0,
+            vec![
+                ChainCode::Ethereum,
+                ChainCode::BnbSmartChain,
+                ChainCode::Evm(137),
+                ChainCode::Evm(42161),
+                ChainCode::Evm(10),
+                ChainCode::Evm(8453),
+                ChainCode::Evm(43114),
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
 pub mod address;
 pub mod chain;
+pub mod evm;
 pub mod network;
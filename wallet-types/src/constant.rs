@@ -55,6 +55,10 @@ pub const DOG_TAPROOT_HARD_DERIVATION_PATH: &str = "m/86'/3'/0'/0/0";
 pub const DOG_SEG_WIT_DERIVATION_PATH: &str = "m/49'/3'/0'/0/0";
 pub const DOG_SEG_WIT_HARD_DERIVATION_PATH: &str = "m/49'/3/0'/0/0";
 
+/// legacy, cashaddr shares the same derivation
+pub const BCH_DERIVATION_PATH: &str = "m/44'/145'/0'/0/0";
+pub const BCH_HARD_DERIVATION_PATH: &str = "m/44'/145'/0'/0/0";
+
 pub const ETH_DERIVATION_PATH_START: &str = "m/44'/60'/";
 pub const TRON_DERIVATION_PATH_START: &str = "m/44'/195'/";
 pub const SOLANA_DERIVATION_PATH_START: &str = "m/44'/501'/";
@@ -68,6 +72,7 @@ pub mod chain_type {
     pub const BTC_86_TYPE: u32 = 86;
     pub const LTC_TYPE: u32 = 2;
     pub const DOG_TYPE: u32 = 3;
+    pub const BCH_TYPE: u32 = 145;
     pub const SUI_TYPE: u32 = 784;
 }
 
@@ -79,8 +84,11 @@ pub mod chain_code {
     pub const BTC: &str = "btc";
     pub const LTC: &str = "ltc";
     pub const DOG: &str = "doge";
+    pub const BCH: &str = "bch";
     pub const TON: &str = "ton";
     pub const SUI: &str = "sui";
+    // evm chains outside the registry, followed by the chain id
+    pub const EVM_PREFIX: &str = "evm:";
 }
 
 pub mod coin {
@@ -133,4 +141,5 @@ pub mod check_black {
     pub const BTC: &[&str] = &[];
     pub const LTC: &[&str] = &[];
     pub const DOG: &[&str] = &[];
+    pub const BCH: &[&str] = &[];
 }
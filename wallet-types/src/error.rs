@@ -12,18 +12,24 @@ pub enum Error {
     LtcNeedAddressType,
     #[error("Dog need address type")]
     DogNeedAddressType,
+    #[error("Bch need address type")]
+    BchNeedAddressType,
     #[error("Invalid BtcAddressType: {0}")]
     BtcAddressTypeInvalid(String),
     #[error("Invalid LtcAddressType: {0}")]
     LtcAddressTypeInvalid(String),
     #[error("Invalid DogAddressType: {0}")]
     DogAddressTypeInvalid(String),
+    #[error("Invalid BchAddressType: {0}")]
+    BchAddressTypeInvalid(String),
     #[error("Invalid BtcAddressCategory: {0}")]
     BtcAddressCategoryInvalid(String),
     #[error("Invalid LtcAddressCategory: {0}")]
     LtcAddressCategoryInvalid(String),
     #[error("Invalid DogAddressCategory: {0}")]
     DogAddressCategoryInvalid(String),
+    #[error("Invalid BchAddressCategory: {0}")]
+    BchAddressCategoryInvalid(String),
     #[error("Miss address type")]
     MissAddressType,
     #[error("ton address type  invalid: {0}")]
@@ -0,0 +1,293 @@
+use crate::error::parse::ParseError;
+use sha2::{Digest as _, Sha256};
+
+// bitcoin cash address format, https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/cashaddr.md
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LEN: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CashAddrType {
+    P2pkh,
+    P2sh,
+}
+
+impl CashAddrType {
+    fn version_type(&self) -> u8 {
+        match self {
+            CashAddrType::P2pkh => 0,
+            CashAddrType::P2sh => 1,
+        }
+    }
+}
+
+pub fn encode(prefix: &str, addr_type: CashAddrType, hash: &[u8]) -> Result<String, crate::Error> {
+    let size_code = match hash.len() {
+        20 => 0,
+        24 => 1,
+        28 => 2,
+        32 => 3,
+        40 => 4,
+        48 => 5,
+        56 => 6,
+        64 => 7,
+        _ => return Err(convert_err(hex::encode(hash))),
+    };
+
+    let mut payload = Vec::with_capacity(hash.len() + 1);
+    payload.push((addr_type.version_type() << 3) | size_code);
+    payload.extend_from_slice(hash);
+
+    let mut data = convert_bits(&payload, 8, 5, true).ok_or(convert_err(hex::encode(hash)))?;
+    let checksum = checksum(prefix, &data);
+    data.extend_from_slice(&checksum);
+
+    let body = data
+        .iter()
+        .map(|d| CHARSET[*d as usize] as char)
+        .collect::<String>();
+    Ok(format!("{}:{}", prefix, body))
+}
+
+// the prefix of the address is optional, when absent the expected prefix is used to verify the checksum
+pub fn decode(
+    expected_prefix: &str,
+    address: &str,
+) -> Result<(CashAddrType, Vec<u8>), crate::Error> {
+    let has_lower = address.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = address.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(convert_err(address));
+    }
+
+    let lower = address.to_lowercase();
+    let (prefix, body) = match lower.split_once(':') {
+        Some((prefix, body)) => (prefix, body),
+        None => (expected_prefix, lower.as_str()),
+    };
+    if prefix != expected_prefix || body.len() <= CHECKSUM_LEN {
+        return Err(convert_err(address));
+    }
+
+    let mut data = Vec::with_capacity(body.len());
+    for c in body.bytes() {
+        let value = CHARSET
+            .iter()
+            .position(|v| *v == c)
+            .ok_or(convert_err(address))?;
+        data.push(value as u8);
+    }
+    if polymod(&prefix_data(prefix, &data)) != 0 {
+        return Err(convert_err(address));
+    }
+
+    let payload = convert_bits(&data[..data.len() - CHECKSUM_LEN], 5, 8, false)
+        .ok_or(convert_err(address))?;
+    let (version, hash) = payload.split_first().ok_or(convert_err(address))?;
+
+    let addr_type = match version >> 3 {
+        0 => CashAddrType::P2pkh,
+        1 => CashAddrType::P2sh,
+        _ => return Err(convert_err(address)),
+    };
+    let size = match version & 0x07 {
+        0 => 20,
+        1 => 24,
+        2 => 28,
+        3 => 32,
+        4 => 40,
+        5 => 48,
+        6 => 56,
+        _ => 64,
+    };
+    if hash.len() != size {
+        return Err(convert_err(address));
+    }
+
+    Ok((addr_type, hash.to_vec()))
+}
+
+// convert cashaddr to the base58 legacy format with the version bytes of the network
+pub fn to_legacy(
+    expected_prefix: &str,
+    address: &str,
+    pubkey_hash_prefix: u8,
+    script_hash_prefix: u8,
+) -> Result<String, crate::Error> {
+    let (addr_type, hash) = decode(expected_prefix, address)?;
+    let version = match addr_type {
+        CashAddrType::P2pkh => pubkey_hash_prefix,
+        CashAddrType::P2sh => script_hash_prefix,
+    };
+
+    let mut data = Vec::with_capacity(hash.len() + 5);
+    data.push(version);
+    data.extend_from_slice(&hash);
+    let checksum = Sha256::digest(Sha256::digest(&data));
+    data.extend_from_slice(&checksum[..4]);
+
+    Ok(bs58::encode(data).into_string())
+}
+
+// convert base58 legacy address to cashaddr
+pub fn from_legacy(
+    prefix: &str,
+    address: &str,
+    pubkey_hash_prefix: u8,
+    script_hash_prefix: u8,
+) -> Result<String, crate::Error> {
+    let data = bs58::decode(address)
+        .into_vec()
+        .map_err(|_| convert_err(address))?;
+    if data.len() != 25 {
+        return Err(convert_err(address));
+    }
+
+    let (payload, checksum) = data.split_at(21);
+    if Sha256::digest(Sha256::digest(payload))[..4] != *checksum {
+        return Err(convert_err(address));
+    }
+
+    let addr_type = if payload[0] == pubkey_hash_prefix {
+        CashAddrType::P2pkh
+    } else if payload[0] == script_hash_prefix {
+        CashAddrType::P2sh
+    } else {
+        return Err(convert_err(address));
+    };
+
+    encode(prefix, addr_type, &payload[1..])
+}
+
+fn convert_err(address: impl Into<String>) -> crate::Error {
+    crate::Error::Parse(ParseError::AddressConvertFailed(address.into()))
+}
+
+fn prefix_data(prefix: &str, data: &[u8]) -> Vec<u8> {
+    let mut values = prefix.bytes().map(|b| b & 0x1f).collect::<Vec<_>>();
+    values.push(0);
+    values.extend_from_slice(data);
+    values
+}
+
+fn checksum(prefix: &str, data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let mut values = prefix_data(prefix, data);
+    values.extend_from_slice(&[0; CHECKSUM_LEN]);
+    let poly = polymod(&values);
+
+    let mut res = [0; CHECKSUM_LEN];
+    for (i, v) in res.iter_mut().enumerate() {
+        *v = ((poly >> (5 * (CHECKSUM_LEN - 1 - i))) & 0x1f) as u8;
+    }
+    res
+}
+
+fn polymod(values: &[u8]) -> u64 {
+    const GENERATOR: [u64; 5] = [
+        0x98f2bc8e61,
+        0x79b76d99e2,
+        0xf33e5fb3c4,
+        0xae2eabe2a8,
+        0x1e4f43e470,
+    ];
+
+    let mut c = 1_u64;
+    for d in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07ffffffff) << 5) ^ (*d as u64);
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if c0 & (1 << i) != 0 {
+                c ^= g;
+            }
+        }
+    }
+    c ^ 1
+}
+
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc = 0_u32;
+    let mut bits = 0_u32;
+    let max = (1 << to) - 1;
+    let mut res = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+    for value in data {
+        let value = *value as u32;
+        if value >> from != 0 {
+            return None;
+        }
+        acc = (acc << from) | value;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            res.push(((acc >> bits) & max) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            res.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return None;
+    }
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "76a04053bda0a88bda5177b86a15c3b29f559873";
+
+    #[test]
+    fn test_encode_decode() {
+        let hash = hex::decode(HASH).unwrap();
+
+        let p2pkh = encode("bitcoincash", CashAddrType::P2pkh, &hash).unwrap();
+        assert_eq!(
+            p2pkh,
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+        );
+        let p2sh = encode("bitcoincash", CashAddrType::P2sh, &hash).unwrap();
+        assert_eq!(
+            p2sh,
+            "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq"
+        );
+
+        let (addr_type, decoded) =
+            decode("bitcoincash", "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a").unwrap();
+        assert_eq!(addr_type, CashAddrType::P2pkh);
+        assert_eq!(decoded, hash);
+
+        // wrong prefix and broken checksum
+        assert!(decode("bchtest", &p2pkh).is_err());
+        assert!(
+            decode(
+                "bitcoincash",
+                "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_legacy_convert() {
+        let legacy = "1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu";
+        let cash = from_legacy("bitcoincash", legacy, 0x00, 0x05).unwrap();
+        assert_eq!(
+            cash,
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"
+        );
+
+        let back = to_legacy("bitcoincash", &cash, 0x00, 0x05).unwrap();
+        assert_eq!(back, legacy);
+
+        let p2sh = to_legacy(
+            "bitcoincash",
+            "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq",
+            0x00,
+            0x05,
+        )
+        .unwrap();
+        assert_eq!(p2sh, "3CWFddi6m4ndiGyKqzYvsFYagqDLPVMTzC");
+    }
+}
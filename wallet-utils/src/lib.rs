@@ -12,6 +12,7 @@ pub mod system_info;
 pub mod time;
 pub use crypto::*;
 pub mod address;
+pub mod cashaddr;
 pub use error::{Error, http::HttpError, parse::ParseError, serde::SerdeError};
 pub use log::{init_log, init_test_log};
 mod global_value;
@@ -1,6 +1,6 @@
 use crate::error::sign_err::SignError;
 use hex::decode;
-use libsecp256k1::{Message, SecretKey};
+use libsecp256k1::{Message, RecoveryId, SecretKey, Signature};
 
 pub fn sign_tron(s: &str, private_key: &str, recover: Option<u8>) -> Result<String, crate::Error> {
     let input = s.strip_prefix("0x").unwrap_or(s);
@@ -27,6 +27,40 @@ pub fn sign_tron(s: &str, private_key: &str, recover: Option<u8>) -> Result<Stri
     Ok(hex::encode(full_signature))
 }
 
+// recover the base58 address that signed the tx_id, v is 0/1 or 27/28
+pub fn recover_tron_address(s: &str, signature: &str) -> Result<String, crate::Error> {
+    use sha3::Digest as _;
+
+    let input = s.strip_prefix("0x").unwrap_or(s);
+    let input_bytes = decode(input).map_err(|e| SignError::Message(e.to_string()))?;
+    let message =
+        Message::parse_slice(&input_bytes).map_err(|e| SignError::Message(e.to_string()))?;
+
+    let signature_bytes = decode(signature).map_err(|e| SignError::Message(e.to_string()))?;
+    if signature_bytes.len() != 65 {
+        return Err(SignError::Message(format!(
+            "invalid signature length {}",
+            signature_bytes.len()
+        ))
+        .into());
+    }
+
+    let v = signature_bytes[64];
+    let v = if v >= 27 { v - 27 } else { v };
+    let recovery_id = RecoveryId::parse(v).map_err(|e| SignError::Message(e.to_string()))?;
+    let signature = Signature::parse_standard_slice(&signature_bytes[..64])
+        .map_err(|e| SignError::Message(e.to_string()))?;
+
+    let public_key = libsecp256k1::recover(&message, &signature, &recovery_id)
+        .map_err(|e| SignError::Message(e.to_string()))?;
+
+    let hash = sha3::Keccak256::digest(&public_key.serialize()[1..]);
+    let mut address = vec![0x41];
+    address.extend_from_slice(&hash[12..]);
+
+    crate::address::hex_to_bs58_addr(&hex::encode(address))
+}
+
 #[cfg(test)]
 mod tests {
     use alloy::primitives::Keccak256;
@@ -69,6 +103,22 @@ mod tests {
         Ok(true)
     }
 
+    #[test]
+    fn test_recover_tron_address() {
+        let key = "0000000000000000000000000000000000000000000000000000000000000001";
+        let tx_id = "069cce46b57b652b1d04ca2d74abe86b605d9d737879b138b631c43e3cb54328";
+
+        let signature = super::sign_tron(tx_id, key, None).unwrap();
+        let address = super::recover_tron_address(tx_id, &signature).unwrap();
+        assert_eq!(address, "TMVQGm1qAQYVdetCeGRRkTWYYrLXuHK2HC");
+
+        let signature = super::sign_tron(tx_id, key, Some(27)).unwrap();
+        assert_eq!(
+            super::recover_tron_address(tx_id, &signature).unwrap(),
+            address
+        );
+    }
+
     #[test]
     fn test_verify() {
         let message = "069cce46b57b652b1d04ca2d74abe86b605d9d737879b138b631c43e3cb54328";